@@ -0,0 +1,182 @@
+## Menus
+
+main-menu = 🤖 ماذا تريد أن تفعل؟
+help-footer = جميع ميزات المزاد متاحة من أزرار القائمة. أرسل /start لفتح القائمة مرة أخرى.
+menu-catalogue = 🗂️ الكتالوج
+menu-my-bids = 🪙 مزايداتي
+menu-favorites = ⭐ المفضلة
+menu-my-wins = 🧾 مشترياتي الفائزة
+menu-settings = ⚙️ الإعدادات
+menu-admin = 🛡️ لوحة الإدارة
+menu-main = ⬅️ القائمة الرئيسية
+menu-categories = ⬅️ الفئات
+menu-favorites-sent = ⭐ تم إرسال المفضلة.
+menu-bids-sent = 🪙 تم إرسال مزايداتك.
+menu-wins-sent = 🧾 تم إرسال مشترياتك الفائزة.
+admins-only = 🛡️ للمشرفين فقط.
+callback-stale = ⌛ هذا الزر لم يعد متاحًا. استخدم /start لفتح القائمة.
+error-reply = 😕 حدث خطأ من جهتنا. حاول مرة أخرى لاحقًا. إذا تكرر ذلك، تواصل مع الدعم واذكر المرجع { $reference }.
+idle-unknown = لم أفهم ذلك. استخدم أزرار القائمة أو /help.
+
+## Settings
+
+settings-title = ⚙️ الإعدادات
+settings-notifications-on = 🔔 الإشعارات مفعّلة
+settings-notifications-off = 🔕 الإشعارات متوقفة
+settings-hint = استخدم الزر أدناه للتحكم في تحديثات المزاد.
+settings-language = 🌐 اللغة: { $language }
+settings-enable-updates = 🔔 تفعيل التحديثات
+settings-mute-updates = 🔕 إيقاف التحديثات
+settings-notifications-muted = 🔕 تم إيقاف الإشعارات.
+settings-notifications-enabled = 🔔 تم تفعيل الإشعارات.
+settings-language-prompt = 🌐 اختر لغتك:
+settings-language-auto = 🔄 مثل لغة Telegram
+settings-language-updated = 🌐 تم تغيير اللغة.
+
+## Catalogue
+
+catalogue-empty = 🗂️ لا توجد فئات بعد. عد لاحقًا.
+catalogue-choose = 🗂️ اختر فئة:
+category-header = 🗂️ الفئة: { $name }
+category-empty = 📭 لا توجد سلع في هذه الفئة بعد.
+category-select = 🛍️ اختر سلعة:
+category-not-found = ❓ الفئة غير موجودة
+favorites-empty = ⭐ لا توجد عناصر في المفضلة بعد.
+favorites-header = ⭐ المفضلة ({ $count }):
+favorite-added = ⭐ أضيفت إلى المفضلة
+favorite-removed = ❌ أزيلت من المفضلة
+bids-empty = 🪙 لم تقدّم أي مزايدة بعد.
+bids-header = 🪙 المزايدات النشطة (عدد السلع: { $count }):
+
+## Item cards
+
+item-not-found = ❓ السلعة غير موجودة
+item-closed = 🔒 المزاد مغلق
+item-start = 💰 سعر البداية: { $price }
+item-relisted-from = ♻️ أعيد عرضها من #{ $item_id }
+item-best = 🏆 أعلى مزايدة: { $price }
+item-your-bid = 🎯 أعلى مزايدة لك: { $price }
+item-favorited = ⭐ محفوظة في المفضلة
+item-new = 🆕 معروضة حديثًا
+item-status-open = 📦 الحالة: مفتوح
+item-status-closed = 📦 الحالة: مغلق
+item-archived = 🗃 مؤرشفة
+item-scheduled = 🗓 تُعرض في { $time } UTC
+item-closes = ⏰ يُغلق في { $time } UTC
+item-place-bid = 💸 قدّم مزايدة
+item-add-favorite = ⭐ أضف إلى المفضلة
+item-remove-favorite = ❌ أزل من المفضلة
+images-all-shown = 📷 تم عرض جميع الصور.
+images-all-already-shown = 📷 تم عرض جميع الصور مسبقًا.
+images-sent-more = 📷 تم إرسال المزيد من الصور.
+images-more = 📷 صور إضافية متاحة: { $count }.
+images-show-more = عرض المزيد من الصور ({ $count })
+
+## Bidding
+
+bid-prompt = أدخل مزايدتك على السلعة #{ $item_id }، مثل 50 أو 1,250:
+bid-in-progress = هناك مزايدة أخرى قيد الإدخال.
+bid-amount-missing = أدخل مبلغ المزايدة، مثل 50 أو 1,250.
+bid-placed = تم تسجيل مزايدة بقيمة { $amount } على السلعة #{ $item_id }.
+bid-placed-quick = ✅ تم تسجيل مزايدة بقيمة { $amount } على السلعة #{ $item_id }.
+bid-highest = 🎉 أنت الآن صاحب أعلى مزايدة!
+bid-failed = تعذّر تسجيل المزايدة، حاول مرة أخرى لاحقًا.
+bid-confirm-below = أكّد مزايدتك أدناه.
+bid-confirm-warning = ⚠️ المبلغ { $amount } أعلى بكثير من السعر الحالي { $reference }.
+bid-confirm-question = هل تؤكد مزايدتك بقيمة { $amount } على السلعة #{ $item_id } ({ $title })؟
+bid-confirm-question-group = { $name }، هل تؤكد مزايدتك بقيمة { $amount } على السلعة #{ $item_id }؟
+bid-confirm-button = ✅ تأكيد { $amount }
+bid-confirm-cancel = ❌ إلغاء
+bid-placed-group = ✅ { $name } زايد بمبلغ { $amount } على #{ $item_id }.
+bid-new-leader = 🏆 صاحب أعلى مزايدة جديد!
+bid-confirmation-foreign = هذا التأكيد يخص مستخدمًا آخر.
+bid-cancelled = ❌ تم إلغاء المزايدة.
+bid-error-invalid-amount = مبلغ غير صالح: { $reason }.
+bid-error-not-found = السلعة غير موجودة.
+bid-error-closed = المزاد مغلق.
+bid-error-too-low = يجب أن تتجاوز مزايدتك { $amount }.
+bid-error-below-start = يجب ألا تقل مزايدتك عن { $amount }.
+money-invalid-format = أدخل مبلغًا مثل 50 أو 1,250.50 أو 1.2k
+money-ambiguous = يمكن قراءة '{ $input }' بأكثر من طريقة، استخدم النقطة للكسور والفواصل بين الآلاف
+money-too-precise = المبلغ يحتوي على منازل عشرية أكثر مما تسمح به { $currency }
+money-wrong-currency = الأسعار هنا بعملة { $currency }
+money-out-of-range = المبلغ يتجاوز الحد المسموح
+
+## Notifications
+
+notify-outbid = ⚠️ تجاوز { $bidder } مزايدتك البالغة { $previous } على السلعة #{ $item_id } ({ $title }). أعلى مزايدة الآن: { $amount }.
+notify-seller-bid = مزايدة جديدة على السلعة #{ $item_id } ({ $title }): @{ $bidder } عرض { $amount }
+notify-closed-won =
+    🏁 أُغلق المزاد على السلعة #{ $item_id } ({ $title }).
+
+    🎉 تهانينا! لقد فزت بمزايدة قدرها { $amount }.
+    تابع طلبك من 🧾 مشترياتي الفائزة.
+notify-closed-final =
+    🏁 أُغلق المزاد على السلعة #{ $item_id } ({ $title }).
+    السعر النهائي: { $amount }. شكرًا لمشاركتك!
+notify-closed-no-bids =
+    🏁 أُغلق المزاد على السلعة #{ $item_id } ({ $title }).
+    أُغلقت السلعة دون أي مزايدات.
+notify-new-lots = 🆕 سلع جديدة متاحة!
+notify-new-lot = • #{ $item_id } { $title } — سعر البداية { $price }
+notify-bid-retracted =
+    ↩️ ألغى أحد المشرفين مزايدتك البالغة { $amount } على السلعة #{ $item_id } ({ $title }).
+    السبب: { $reason }
+notify-new-leader = 🏆 أُلغيت مزايدة أعلى على السلعة #{ $item_id } ({ $title }). أنت الآن صاحب أعلى مزايدة بقيمة { $amount }.
+notify-order-status = 🧾 حالة طلبك #{ $order_id } للسلعة #{ $item_id } ({ $title }) الآن: { $status }
+
+## Orders
+
+wins-empty = 🧾 لم تفز بأي مزاد بعد.
+wins-header = 🧾 مشترياتي الفائزة ({ $count }):
+wins-order =
+    #{ $item_id } { $title } — { $amount }
+    الطلب #{ $order_id } · { $status }
+order-status-awaiting-payment = ⏳ بانتظار الدفع
+order-status-paid = 💳 مدفوع
+order-status-shipped = 🚚 تم الشحن
+order-status-completed = ✅ مكتمل
+order-status-cancelled = ❌ ملغى
+
+## Second chance
+
+second-chance-offer =
+    🥈 أخبار سارة! السلعة #{ $item_id } ({ $title }) متاحة مجددًا، ويمكنك الحصول عليها بأعلى مزايدة قدمتها وهي { $amount }.
+
+    ينتهي هذا العرض في { $expires } UTC.
+second-chance-buy = ✅ اشترِها
+second-chance-no-thanks = ❌ لا، شكرًا
+second-chance-unavailable = ⌛ هذا العرض لم يعد متاحًا.
+second-chance-sold-meanwhile = ⚠️ بيعت هذه السلعة في الأثناء. سيتواصل معك أحد المشرفين.
+second-chance-bought = ✅ اشتريت السلعة #{ $item_id } ({ $title }) مقابل { $amount }. تابع طلبك من 🧾 مشترياتي الفائزة.
+second-chance-declined = 👌 تم رفض العرض. شكرًا لإبلاغنا.
+second-chance-expired = ⌛ انتهت صلاحية العرض على السلعة #{ $item_id }.
+
+## Payments
+
+invoice-description = المزايدة الفائزة على السلعة #{ $item_id } ({ $title }).
+invoice-label = المزايدة الفائزة #{ $item_id }
+checkout-order-missing = هذا الطلب لم يعد موجودًا.
+checkout-wrong-payer = هذه الفاتورة تخص فائزًا آخر.
+checkout-order-status = حالة هذا الطلب بالفعل: { $status }.
+checkout-amount-changed = تغيّر المبلغ المستحق إلى { $amount }. يرجى انتظار فاتورة جديدة.
+checkout-unavailable = تعذّر التحقق من هذا الطلب الآن. يرجى المحاولة مرة أخرى بعد قليل.
+payment-under-review = 💳 تم استلام الدفعة. سيراجع أحد المشرفين الطلب #{ $order_id } قريبًا.
+payment-received = ✅ تم استلام دفعة بقيمة { $amount } للسلعة #{ $item_id } ({ $title }). تابع طلبك من 🧾 مشترياتي الفائزة.
+payment-removed-item = سلعة محذوفة
+
+## Bid history
+
+history-open = 📜 سجل المزايدات
+history-title = 📜 سجل المزايدات — #{ $item_id } { $title }
+history-unique-bidders = 👥 عدد المزايدين: { $count }
+history-bids = 🔢 المزايدات: { $count }
+history-retracted-count = (الملغاة: { $count })
+history-first-bid = ⏱ أول مزايدة بعد: { $delay }
+history-uplift = 📈 الزيادة: { $amount } ({ $percent }) فوق سعر البداية { $start }
+history-no-bids = 📈 لا توجد مزايدات فوق سعر البداية { $start } بعد
+history-page = الصفحة { $page }/{ $pages }
+history-retracted = ↩️ ملغاة
+history-retracted-by = بواسطة { $admin }
+history-newer = ◀️ الأحدث
+history-older = الأقدم ▶️
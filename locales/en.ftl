@@ -0,0 +1,188 @@
+## Menus
+
+main-menu = 🤖 What would you like to do?
+help-footer = All auction features are available from the on-screen menu buttons. Use /start to open the menu again.
+menu-catalogue = 🗂️ Catalogue
+menu-my-bids = 🪙 My bids
+menu-favorites = ⭐ My favorites
+menu-my-wins = 🧾 My wins
+menu-settings = ⚙️ My settings
+menu-admin = 🛡️ Admin panel
+menu-main = ⬅️ Main menu
+menu-categories = ⬅️ Categories
+menu-favorites-sent = ⭐ Sent your favorites.
+menu-bids-sent = 🪙 Sent your bids.
+menu-wins-sent = 🧾 Sent your wins.
+admins-only = 🛡️ Admins only.
+callback-stale = ⌛ This button is no longer available. Use /start to open the menu.
+error-reply = 😕 Something went wrong on our side. Please try again later. If it keeps happening, contact support with reference { $reference }.
+idle-unknown = I did not understand that. Use the menu buttons or /help.
+
+## Settings
+
+settings-title = ⚙️ Settings
+settings-notifications-on = 🔔 Notifications are ON
+settings-notifications-off = 🔕 Notifications are OFF
+settings-hint = Toggle below to control auction updates.
+settings-language = 🌐 Language: { $language }
+settings-enable-updates = 🔔 Enable updates
+settings-mute-updates = 🔕 Mute updates
+settings-notifications-muted = 🔕 Notifications muted.
+settings-notifications-enabled = 🔔 Notifications enabled.
+settings-language-prompt = 🌐 Choose your language:
+settings-language-auto = 🔄 Same as Telegram
+settings-language-updated = 🌐 Language updated.
+
+## Catalogue
+
+catalogue-empty = 🗂️ No categories yet. Check back soon.
+catalogue-choose = 🗂️ Choose a category:
+category-header = 🗂️ Category: { $name }
+category-empty = 📭 No items in this category yet.
+category-select = 🛍️ Select an item:
+category-not-found = ❓ Category not found
+favorites-empty = ⭐ No favorites yet.
+favorites-header = ⭐ Favorites ({ $count }):
+favorite-added = ⭐ Added to favorites
+favorite-removed = ❌ Removed from favorites
+bids-empty = 🪙 You have not placed any bids yet.
+bids-header = 🪙 Active bids ({ $count ->
+    [one] { $count } item
+   *[other] { $count } items
+  }):
+
+## Item cards
+
+item-not-found = ❓ Item not found
+item-closed = 🔒 Auction is closed
+item-start = 💰 Start: { $price }
+item-relisted-from = ♻️ Relisted from #{ $item_id }
+item-best = 🏆 Current best: { $price }
+item-your-bid = 🎯 Your top bid: { $price }
+item-favorited = ⭐ Saved to favorites
+item-new = 🆕 Newly listed
+item-status-open = 📦 Status: OPEN
+item-status-closed = 📦 Status: CLOSED
+item-archived = 🗃 Archived
+item-scheduled = 🗓 Goes live { $time } UTC
+item-closes = ⏰ Closes { $time } UTC
+item-place-bid = 💸 Place bid
+item-add-favorite = ⭐ Add favorite
+item-remove-favorite = ❌ Remove favorite
+images-all-shown = 📷 All images shown.
+images-all-already-shown = 📷 All images already shown.
+images-sent-more = 📷 Sent more photos.
+images-more = 📷 { $count ->
+    [one] { $count } more photo available.
+   *[other] { $count } more photos available.
+  }
+images-show-more = Show more images ({ $count })
+
+## Bidding
+
+bid-prompt = Enter your bid for item #{ $item_id }, e.g. 50 or 1,250:
+bid-in-progress = Another bid is already in progress.
+bid-amount-missing = Provide your bid amount, e.g. 50 or 1,250.
+bid-placed = Bid placed at { $amount } for item #{ $item_id }.
+bid-placed-quick = ✅ Bid placed at { $amount } for item #{ $item_id }.
+bid-highest = 🎉 You're now the highest bidder!
+bid-failed = Failed to place bid, try again later.
+bid-confirm-below = Confirm your bid below.
+bid-confirm-warning = ⚠️ { $amount } is well above the current price of { $reference }.
+bid-confirm-question = Confirm your bid of { $amount } on item #{ $item_id } ({ $title })?
+bid-confirm-question-group = { $name }, confirm your bid of { $amount } on item #{ $item_id }?
+bid-confirm-button = ✅ Confirm { $amount }
+bid-confirm-cancel = ❌ Cancel
+bid-placed-group = ✅ { $name } bid { $amount } on #{ $item_id }.
+bid-new-leader = 🏆 New leader!
+bid-confirmation-foreign = This confirmation belongs to someone else.
+bid-cancelled = ❌ Bid cancelled.
+bid-error-invalid-amount = Invalid amount: { $reason }.
+bid-error-not-found = Item not found.
+bid-error-closed = Auction is closed.
+bid-error-too-low = Your bid must exceed { $amount }.
+bid-error-below-start = Your bid must be at least { $amount }.
+money-invalid-format = use an amount like 50, 1,250.50 or 1.2k
+money-ambiguous = '{ $input }' could be read more than one way, use a dot for decimals and commas between thousands
+money-too-precise = amount has more decimals than { $currency } allows
+money-wrong-currency = prices here are in { $currency }
+money-out-of-range = amount exceeds supported range
+
+## Notifications
+
+notify-outbid = ⚠️ Your bid of { $previous } on item #{ $item_id } ({ $title }) was beaten by { $bidder }. New highest bid: { $amount }.
+notify-seller-bid = New bid on item #{ $item_id } ({ $title }): @{ $bidder } offered { $amount }
+notify-closed-won =
+    🏁 Auction closed for item #{ $item_id } ({ $title }).
+
+    🎉 Congratulations! You won with a bid of { $amount }.
+    Track your order under 🧾 My wins.
+notify-closed-final =
+    🏁 Auction closed for item #{ $item_id } ({ $title }).
+    Final price: { $amount }. Thanks for taking part!
+notify-closed-no-bids =
+    🏁 Auction closed for item #{ $item_id } ({ $title }).
+    The item closed with no bids.
+notify-new-lots = 🆕 New lots available!
+notify-new-lot = • #{ $item_id } { $title } — start { $price }
+notify-bid-retracted =
+    ↩️ Your bid of { $amount } on item #{ $item_id } ({ $title }) was retracted by an admin.
+    Reason: { $reason }
+notify-new-leader = 🏆 A higher bid on item #{ $item_id } ({ $title }) was retracted. You're now the highest bidder at { $amount }.
+notify-order-status = 🧾 Your order #{ $order_id } for item #{ $item_id } ({ $title }) is now: { $status }
+
+## Orders
+
+wins-empty = 🧾 You have not won any auctions yet.
+wins-header = 🧾 My wins ({ $count }):
+wins-order =
+    #{ $item_id } { $title } — { $amount }
+    Order #{ $order_id } · { $status }
+order-status-awaiting-payment = ⏳ Awaiting payment
+order-status-paid = 💳 Paid
+order-status-shipped = 🚚 Shipped
+order-status-completed = ✅ Completed
+order-status-cancelled = ❌ Cancelled
+
+## Second chance
+
+second-chance-offer =
+    🥈 Good news! Item #{ $item_id } ({ $title }) is available again, and you can have it at your highest bid of { $amount }.
+
+    This offer expires { $expires } UTC.
+second-chance-buy = ✅ Buy it
+second-chance-no-thanks = ❌ No thanks
+second-chance-unavailable = ⌛ This offer is no longer available.
+second-chance-sold-meanwhile = ⚠️ This lot was sold meanwhile. An admin will get in touch.
+second-chance-bought = ✅ You bought item #{ $item_id } ({ $title }) for { $amount }. Track your order under 🧾 My wins.
+second-chance-declined = 👌 Offer declined. Thanks for letting us know.
+second-chance-expired = ⌛ The offer for item #{ $item_id } has expired.
+
+## Payments
+
+invoice-description = Winning bid on item #{ $item_id } ({ $title }).
+invoice-label = Winning bid #{ $item_id }
+checkout-order-missing = This order no longer exists.
+checkout-wrong-payer = This invoice belongs to another winner.
+checkout-order-status = This order is already { $status }.
+checkout-amount-changed = The amount due has changed to { $amount }. Please wait for a new invoice.
+checkout-unavailable = We couldn't check this order right now. Please try again in a moment.
+payment-under-review = 💳 Payment received. An admin will review order #{ $order_id } shortly.
+payment-received = ✅ Payment of { $amount } received for item #{ $item_id } ({ $title }). Track your order under 🧾 My wins.
+payment-removed-item = removed item
+
+## Bid history
+
+history-open = 📜 Bid history
+history-title = 📜 Bid history — #{ $item_id } { $title }
+history-unique-bidders = 👥 Unique bidders: { $count }
+history-bids = 🔢 Bids: { $count }
+history-retracted-count = ({ $count } retracted)
+history-first-bid = ⏱ First bid after: { $delay }
+history-uplift = 📈 Uplift: { $amount } ({ $percent }) over the start price of { $start }
+history-no-bids = 📈 No bids yet over the start price of { $start }
+history-page = Page { $page }/{ $pages }
+history-retracted = ↩️ retracted
+history-retracted-by = by { $admin }
+history-newer = ◀️ Newer
+history-older = Older ▶️
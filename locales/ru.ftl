@@ -0,0 +1,182 @@
+## Menus
+
+main-menu = 🤖 Что вы хотите сделать?
+help-footer = Все функции аукциона доступны через кнопки меню. Отправьте /start, чтобы снова открыть меню.
+menu-catalogue = 🗂️ Каталог
+menu-my-bids = 🪙 Мои ставки
+menu-favorites = ⭐ Избранное
+menu-my-wins = 🧾 Мои выигрыши
+menu-settings = ⚙️ Настройки
+menu-admin = 🛡️ Панель администратора
+menu-main = ⬅️ Главное меню
+menu-categories = ⬅️ Категории
+menu-favorites-sent = ⭐ Избранное отправлено.
+menu-bids-sent = 🪙 Ваши ставки отправлены.
+menu-wins-sent = 🧾 Ваши выигрыши отправлены.
+admins-only = 🛡️ Только для администраторов.
+callback-stale = ⌛ Эта кнопка больше не работает. Откройте меню командой /start.
+error-reply = 😕 Что-то пошло не так на нашей стороне. Попробуйте позже. Если ошибка повторяется, напишите в поддержку и укажите код { $reference }.
+idle-unknown = Не понял сообщение. Воспользуйтесь кнопками меню или /help.
+
+## Settings
+
+settings-title = ⚙️ Настройки
+settings-notifications-on = 🔔 Уведомления ВКЛЮЧЕНЫ
+settings-notifications-off = 🔕 Уведомления ВЫКЛЮЧЕНЫ
+settings-hint = Переключите ниже, чтобы управлять уведомлениями об аукционах.
+settings-language = 🌐 Язык: { $language }
+settings-enable-updates = 🔔 Включить уведомления
+settings-mute-updates = 🔕 Выключить уведомления
+settings-notifications-muted = 🔕 Уведомления выключены.
+settings-notifications-enabled = 🔔 Уведомления включены.
+settings-language-prompt = 🌐 Выберите язык:
+settings-language-auto = 🔄 Как в Telegram
+settings-language-updated = 🌐 Язык изменён.
+
+## Catalogue
+
+catalogue-empty = 🗂️ Категорий пока нет. Загляните позже.
+catalogue-choose = 🗂️ Выберите категорию:
+category-header = 🗂️ Категория: { $name }
+category-empty = 📭 В этой категории пока нет лотов.
+category-select = 🛍️ Выберите лот:
+category-not-found = ❓ Категория не найдена
+favorites-empty = ⭐ В избранном пока пусто.
+favorites-header = ⭐ Избранное ({ $count }):
+favorite-added = ⭐ Добавлено в избранное
+favorite-removed = ❌ Удалено из избранного
+bids-empty = 🪙 Вы ещё не делали ставок.
+bids-header = 🪙 Активные ставки (лотов: { $count }):
+
+## Item cards
+
+item-not-found = ❓ Лот не найден
+item-closed = 🔒 Аукцион закрыт
+item-start = 💰 Стартовая цена: { $price }
+item-relisted-from = ♻️ Повторно выставлен, исходный лот #{ $item_id }
+item-best = 🏆 Лучшая ставка: { $price }
+item-your-bid = 🎯 Ваша лучшая ставка: { $price }
+item-favorited = ⭐ В избранном
+item-new = 🆕 Новый лот
+item-status-open = 📦 Статус: ОТКРЫТ
+item-status-closed = 📦 Статус: ЗАКРЫТ
+item-archived = 🗃 В архиве
+item-scheduled = 🗓 Публикация { $time } UTC
+item-closes = ⏰ Закрытие { $time } UTC
+item-place-bid = 💸 Сделать ставку
+item-add-favorite = ⭐ В избранное
+item-remove-favorite = ❌ Убрать из избранного
+images-all-shown = 📷 Все фото показаны.
+images-all-already-shown = 📷 Все фото уже показаны.
+images-sent-more = 📷 Отправлены ещё фото.
+images-more = 📷 Ещё фото: { $count }.
+images-show-more = Показать ещё ({ $count })
+
+## Bidding
+
+bid-prompt = Введите ставку на лот #{ $item_id }, например 50 или 1,250:
+bid-in-progress = Уже идёт ввод другой ставки.
+bid-amount-missing = Укажите сумму ставки, например 50 или 1,250.
+bid-placed = Ставка { $amount } на лот #{ $item_id } принята.
+bid-placed-quick = ✅ Ставка { $amount } на лот #{ $item_id } принята.
+bid-highest = 🎉 Теперь ваша ставка самая высокая!
+bid-failed = Не удалось сделать ставку, попробуйте позже.
+bid-confirm-below = Подтвердите ставку ниже.
+bid-confirm-warning = ⚠️ { $amount } намного выше текущей цены { $reference }.
+bid-confirm-question = Подтвердить ставку { $amount } на лот #{ $item_id } ({ $title })?
+bid-confirm-question-group = { $name }, подтвердите ставку { $amount } на лот #{ $item_id }.
+bid-confirm-button = ✅ Подтвердить { $amount }
+bid-confirm-cancel = ❌ Отмена
+bid-placed-group = ✅ { $name }: ставка { $amount } на #{ $item_id }.
+bid-new-leader = 🏆 Новый лидер!
+bid-confirmation-foreign = Это подтверждение предназначено другому участнику.
+bid-cancelled = ❌ Ставка отменена.
+bid-error-invalid-amount = Неверная сумма: { $reason }.
+bid-error-not-found = Лот не найден.
+bid-error-closed = Аукцион закрыт.
+bid-error-too-low = Ставка должна быть выше { $amount }.
+bid-error-below-start = Ставка должна быть не меньше { $amount }.
+money-invalid-format = укажите сумму вида 50, 1,250.50 или 1.2k
+money-ambiguous = «{ $input }» можно прочитать по-разному: используйте точку для дробной части и запятые между тысячами
+money-too-precise = в сумме больше знаков после точки, чем допускает { $currency }
+money-wrong-currency = цены здесь указаны в { $currency }
+money-out-of-range = сумма слишком велика
+
+## Notifications
+
+notify-outbid = ⚠️ Вашу ставку { $previous } на лот #{ $item_id } ({ $title }) перебил { $bidder }. Новая лучшая ставка: { $amount }.
+notify-seller-bid = Новая ставка на лот #{ $item_id } ({ $title }): @{ $bidder } предлагает { $amount }
+notify-closed-won =
+    🏁 Аукцион по лоту #{ $item_id } ({ $title }) закрыт.
+
+    🎉 Поздравляем! Вы победили со ставкой { $amount }.
+    Следите за заказом в разделе 🧾 Мои выигрыши.
+notify-closed-final =
+    🏁 Аукцион по лоту #{ $item_id } ({ $title }) закрыт.
+    Итоговая цена: { $amount }. Спасибо за участие!
+notify-closed-no-bids =
+    🏁 Аукцион по лоту #{ $item_id } ({ $title }) закрыт.
+    Ставок не было.
+notify-new-lots = 🆕 Новые лоты!
+notify-new-lot = • #{ $item_id } { $title } — старт { $price }
+notify-bid-retracted =
+    ↩️ Администратор отменил вашу ставку { $amount } на лот #{ $item_id } ({ $title }).
+    Причина: { $reason }
+notify-new-leader = 🏆 Более высокая ставка на лот #{ $item_id } ({ $title }) отменена. Теперь ваша ставка { $amount } самая высокая.
+notify-order-status = 🧾 Статус вашего заказа #{ $order_id } на лот #{ $item_id } ({ $title }): { $status }
+
+## Orders
+
+wins-empty = 🧾 Вы пока не выиграли ни одного аукциона.
+wins-header = 🧾 Мои выигрыши ({ $count }):
+wins-order =
+    #{ $item_id } { $title } — { $amount }
+    Заказ #{ $order_id } · { $status }
+order-status-awaiting-payment = ⏳ Ожидает оплаты
+order-status-paid = 💳 Оплачен
+order-status-shipped = 🚚 Отправлен
+order-status-completed = ✅ Выполнен
+order-status-cancelled = ❌ Отменён
+
+## Second chance
+
+second-chance-offer =
+    🥈 Хорошие новости! Лот #{ $item_id } ({ $title }) снова доступен, и вы можете купить его по вашей лучшей ставке { $amount }.
+
+    Предложение действует до { $expires } UTC.
+second-chance-buy = ✅ Купить
+second-chance-no-thanks = ❌ Нет, спасибо
+second-chance-unavailable = ⌛ Это предложение больше не действует.
+second-chance-sold-meanwhile = ⚠️ Этот лот уже продан. Администратор свяжется с вами.
+second-chance-bought = ✅ Вы купили лот #{ $item_id } ({ $title }) за { $amount }. Следите за заказом в разделе 🧾 Мои выигрыши.
+second-chance-declined = 👌 Предложение отклонено. Спасибо, что сообщили.
+second-chance-expired = ⌛ Срок предложения по лоту #{ $item_id } истёк.
+
+## Payments
+
+invoice-description = Выигрышная ставка на лот #{ $item_id } ({ $title }).
+invoice-label = Выигрышная ставка #{ $item_id }
+checkout-order-missing = Этот заказ больше не существует.
+checkout-wrong-payer = Этот счёт выставлен другому победителю.
+checkout-order-status = Статус заказа уже: { $status }.
+checkout-amount-changed = Сумма к оплате изменилась на { $amount }. Дождитесь нового счёта.
+checkout-unavailable = Сейчас не удаётся проверить заказ. Попробуйте ещё раз чуть позже.
+payment-under-review = 💳 Оплата получена. Администратор скоро проверит заказ #{ $order_id }.
+payment-received = ✅ Оплата { $amount } за лот #{ $item_id } ({ $title }) получена. Следите за заказом в разделе 🧾 Мои выигрыши.
+payment-removed-item = удалённый лот
+
+## Bid history
+
+history-open = 📜 История ставок
+history-title = 📜 История ставок — #{ $item_id } { $title }
+history-unique-bidders = 👥 Участников: { $count }
+history-bids = 🔢 Ставок: { $count }
+history-retracted-count = (отменено: { $count })
+history-first-bid = ⏱ Первая ставка через: { $delay }
+history-uplift = 📈 Рост: { $amount } ({ $percent }) от стартовой цены { $start }
+history-no-bids = 📈 Ставок выше стартовой цены { $start } пока нет
+history-page = Страница { $page }/{ $pages }
+history-retracted = ↩️ отменена
+history-retracted-by = администратором { $admin }
+history-newer = ◀️ Новее
+history-older = Старее ▶️
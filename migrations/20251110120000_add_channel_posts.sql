@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS channel_posts (
+    item_id     BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    chat_id     BIGINT NOT NULL,
+    message_id  INT NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (item_id, chat_id)
+);
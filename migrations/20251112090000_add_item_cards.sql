@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS item_cards (
+    chat_id         BIGINT NOT NULL,
+    message_id      INT NOT NULL,
+    item_id         BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    viewer_tg_id    BIGINT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (chat_id, message_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_item_cards_item_created ON item_cards(item_id, created_at DESC);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS group_lots (
+    chat_id     BIGINT NOT NULL,
+    message_id  INT NOT NULL,
+    item_id     BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    posted_by   BIGINT NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (chat_id, message_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_group_lots_item ON group_lots(item_id);
@@ -0,0 +1,8 @@
+ALTER TABLE bids
+  ADD COLUMN retracted_at TIMESTAMPTZ,
+  ADD COLUMN retracted_by BIGINT,
+  ADD COLUMN retraction_reason TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_bids_item_active_amount
+    ON bids(item_id, amount DESC)
+    WHERE retracted_at IS NULL;
@@ -0,0 +1,14 @@
+-- One order per closed item with a winner, tracking checkout after the auction
+CREATE TABLE IF NOT EXISTS orders (
+    id              BIGSERIAL PRIMARY KEY,
+    item_id         BIGINT NOT NULL UNIQUE REFERENCES items(id) ON DELETE CASCADE,
+    winner_tg_id    BIGINT NOT NULL,
+    amount          BIGINT NOT NULL,
+    status          TEXT NOT NULL DEFAULT 'awaiting_payment'
+                    CHECK (status IN ('awaiting_payment', 'paid', 'shipped', 'completed', 'cancelled')),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_orders_winner ON orders(winner_tg_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_orders_status ON orders(status);
@@ -0,0 +1,5 @@
+-- Telegram Payments receipts for orders paid through an invoice
+ALTER TABLE orders
+  ADD COLUMN paid_at TIMESTAMPTZ,
+  ADD COLUMN telegram_payment_charge_id TEXT,
+  ADD COLUMN provider_payment_charge_id TEXT;
@@ -0,0 +1,20 @@
+-- Offers of a closed lot to runner-up bidders after the winner backs out
+CREATE TABLE IF NOT EXISTS second_chance_offers (
+    id              BIGSERIAL PRIMARY KEY,
+    item_id         BIGINT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    bidder_tg_id    BIGINT NOT NULL,
+    amount          BIGINT NOT NULL,
+    offered_by      BIGINT NOT NULL,
+    status          TEXT NOT NULL DEFAULT 'pending'
+                    CHECK (status IN ('pending', 'accepted', 'declined', 'expired')),
+    expires_at      TIMESTAMPTZ NOT NULL,
+    responded_at    TIMESTAMPTZ,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_second_chance_one_pending
+    ON second_chance_offers(item_id)
+    WHERE status = 'pending';
+CREATE INDEX IF NOT EXISTS idx_second_chance_expiry
+    ON second_chance_offers(expires_at)
+    WHERE status = 'pending';
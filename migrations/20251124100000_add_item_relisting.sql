@@ -0,0 +1,5 @@
+-- Relisted items point back at the listing they were cloned from
+ALTER TABLE items
+  ADD COLUMN relisted_from_id BIGINT REFERENCES items(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_items_relisted_from ON items(relisted_from_id);
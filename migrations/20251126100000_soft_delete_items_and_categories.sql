@@ -0,0 +1,16 @@
+-- Removing items and categories archives them instead of deleting rows, so bids, favorites and images survive
+ALTER TABLE items
+  ADD COLUMN deleted_at TIMESTAMPTZ,
+  ADD COLUMN deleted_by BIGINT;
+
+ALTER TABLE categories
+  ADD COLUMN deleted_at TIMESTAMPTZ,
+  ADD COLUMN deleted_by BIGINT;
+
+-- Archived categories must not block reusing their name
+ALTER TABLE categories DROP CONSTRAINT IF EXISTS categories_name_key;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_active_name
+    ON categories(name)
+    WHERE deleted_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_items_deleted_at ON items(deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,13 @@
+-- Record of privileged admin actions: who did what to which record, with action-specific details
+CREATE TABLE IF NOT EXISTS audit_log (
+    id              BIGSERIAL PRIMARY KEY,
+    actor_tg_id     BIGINT NOT NULL,
+    action          TEXT NOT NULL,
+    target_type     TEXT,
+    target_id       BIGINT,
+    payload         JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_created ON audit_log(created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_log_action ON audit_log(action, created_at DESC, id DESC);
@@ -0,0 +1,4 @@
+-- Optional per-category currency. Items snapshot the currency they were listed in, so changing a category or the
+-- deployment default later never re-denominates existing prices. NULL means the deployment currency.
+ALTER TABLE categories ADD COLUMN IF NOT EXISTS currency TEXT;
+ALTER TABLE items ADD COLUMN IF NOT EXISTS currency TEXT;
@@ -0,0 +1,4 @@
+-- language_code mirrors what Telegram reports; locale is the user's explicit choice and wins when set.
+ALTER TABLE users
+  ADD COLUMN language_code TEXT,
+  ADD COLUMN locale TEXT;
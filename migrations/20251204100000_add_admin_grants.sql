@@ -0,0 +1,6 @@
+-- Admins granted from the operator console, on top of the ones configured in telegram.admins
+CREATE TABLE IF NOT EXISTS admin_grants (
+    tg_id       BIGINT PRIMARY KEY,
+    granted_by  BIGINT NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
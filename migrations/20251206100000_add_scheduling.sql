@@ -0,0 +1,19 @@
+-- Auction events group lots under a shared start and end
+CREATE TABLE IF NOT EXISTS auction_events (
+    id          BIGSERIAL PRIMARY KEY,
+    name        TEXT NOT NULL,
+    starts_at   TIMESTAMPTZ NOT NULL,
+    ends_at     TIMESTAMPTZ NOT NULL CHECK (ends_at > starts_at),
+    created_by  BIGINT NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Scheduled items stay hidden while publish_at is set; the scheduler clears it once the item goes live
+ALTER TABLE items
+  ADD COLUMN publish_at TIMESTAMPTZ,
+  ADD COLUMN closes_at TIMESTAMPTZ,
+  ADD COLUMN event_id BIGINT REFERENCES auction_events(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_items_publish_at ON items(publish_at) WHERE publish_at IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_items_closes_at ON items(closes_at) WHERE closes_at IS NOT NULL AND is_open;
+CREATE INDEX IF NOT EXISTS idx_items_event ON items(event_id);
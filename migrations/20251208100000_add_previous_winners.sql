@@ -0,0 +1,2 @@
+-- Winners an order was taken from when it was reassigned, so a lot is never offered back to them
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS previous_winner_ids BIGINT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,4 @@
+-- Items that went live but are not yet posted to the channel and announced; the scheduler retries them until done
+ALTER TABLE items ADD COLUMN IF NOT EXISTS publish_pending BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX IF NOT EXISTS idx_items_publish_pending ON items(id) WHERE publish_pending;
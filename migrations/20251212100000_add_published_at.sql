@@ -0,0 +1,4 @@
+-- When an item went live. Scheduled items get it once the scheduler publishes them; older items count from creation
+ALTER TABLE items ADD COLUMN IF NOT EXISTS published_at TIMESTAMPTZ;
+
+UPDATE items SET published_at = created_at WHERE publish_at IS NULL AND published_at IS NULL;
@@ -1,39 +1,81 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use teloxide::dispatching::UpdateHandler;
 use teloxide::dispatching::dialogue::InMemStorage;
 use teloxide::dptree;
+use teloxide::error_handlers::LoggingErrorHandler;
 use teloxide::prelude::*;
+use teloxide::update_listeners::webhooks;
+use tracing::info;
 
 use crate::bot;
 use crate::bot::AppContext;
 use crate::bot::DialogueStorage;
+use crate::bot::errors::ErrorReporter;
+use crate::config::Config;
+use crate::config::UpdateMode;
 use crate::db::Db;
+use crate::server;
 
 pub struct App {
   bot: Bot,
   context: Arc<AppContext>,
   handler: UpdateHandler<anyhow::Error>,
+  port: u16,
+  updates: UpdateMode,
 }
 
 impl App {
-  pub fn new(bot: Bot, db: Db, admins: Vec<i64>) -> Self {
-    let context = Arc::new(AppContext::new(db, admins));
+  pub fn new(bot: Bot, db: Db, config: &Config) -> Self {
+    let context = Arc::new(AppContext::new(db, config));
     let handler = bot::build_schema();
-    Self { bot, context, handler }
+    Self {
+      bot,
+      context,
+      handler,
+      port: config.server.port,
+      updates: config.telegram.updates.clone(),
+    }
   }
 
   pub async fn run(self) -> anyhow::Result<()> {
     let storage: Arc<DialogueStorage> = InMemStorage::new();
 
     let me = self.bot.get_me().await?;
+    bot::second_chance::spawn_expiry_sweeper(self.bot.clone(), self.context.clone());
+    bot::scheduler::spawn(self.bot.clone(), self.context.clone());
 
-    Dispatcher::builder(self.bot.clone(), self.handler)
+    let listener = server::bind(self.port).await?;
+    let routes = server::routes(self.context.db().clone());
+    let mut dispatcher = Dispatcher::builder(self.bot.clone(), self.handler)
       .dependencies(dptree::deps![self.context.clone(), storage.clone(), me])
+      .error_handler(ErrorReporter::new(self.bot.clone(), self.context.clone()))
       .enable_ctrlc_handler()
-      .build()
-      .dispatch()
-      .await;
+      .build();
+
+    match self.updates {
+      UpdateMode::Polling => {
+        info!("receiving updates by long polling");
+        server::spawn(listener, routes, std::future::pending());
+        dispatcher.dispatch().await;
+      },
+      UpdateMode::Webhook { url, secret } => {
+        // The webhook is registered here instead of through `webhooks::axum_to_router`, which deletes it again on
+        // shutdown. During a rolling deploy that would unregister the webhook the replacement machine just set.
+        self.bot.set_webhook(url.clone()).secret_token(secret.clone()).await?;
+        info!(%url, "receiving updates by webhook");
+        let options = webhooks::Options::new(SocketAddr::from(([0, 0, 0, 0], self.port)), url).secret_token(secret);
+        let (updates, stopped, webhook) = webhooks::axum_no_setup(options);
+        server::spawn(listener, webhook.merge(routes), stopped);
+        dispatcher
+          .dispatch_with_listener(
+            updates,
+            LoggingErrorHandler::with_custom_text("webhook listener failed"),
+          )
+          .await;
+      },
+    }
 
     Ok(())
   }
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Result;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardMarkup;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::HandlerResult;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::context::AppContext;
+use crate::config::Config;
+use crate::metrics;
+
+/// Repeats of an alert within this window are counted instead of posted.
+const DEDUP_WINDOW: Duration = Duration::from_secs(15 * 60);
+/// At most [`MAX_ALERTS_PER_WINDOW`] alerts are posted per window, whatever their keys, so an outage cannot flood the
+/// alert chat.
+const RATE_WINDOW: Duration = Duration::from_secs(60 * 60);
+const MAX_ALERTS_PER_WINDOW: usize = 20;
+/// How long the "mute" button silences an alert.
+const MUTE_DURATION: Duration = Duration::from_secs(60 * 60);
+
+/// Operational alerts for the admins. They go to the alert chat when one is configured, otherwise to every admin,
+/// and are deduplicated by key and rate limited. Every alert carries a button that mutes its key for an hour.
+#[derive(Clone)]
+pub struct Alerts {
+  targets: Arc<[ChatId]>,
+  state: Arc<Mutex<AlertState>>,
+}
+
+impl Alerts {
+  pub fn new(config: &Config) -> Self {
+    let targets = match config.notifications.alert_chat_id {
+      Some(chat_id) => vec![ChatId(chat_id)],
+      None => config.telegram.admins.iter().copied().map(ChatId).collect(),
+    };
+    Self {
+      targets: targets.into(),
+      state: Arc::default(),
+    }
+  }
+
+  /// Whether `chat` receives alerts. Anyone who can read the alert chat may mute them.
+  pub fn is_target(&self, chat: ChatId) -> bool {
+    self.targets.contains(&chat)
+  }
+
+  /// Posts an alert unless its key was alerted on recently, is muted, or the rate limit is used up. Alerts that are
+  /// held back are counted and mentioned in the next one posted for the same key.
+  pub async fn raise(&self, bot: &Bot, key: &str, text: impl Display) {
+    warn!(alert = key, message = %text, "alert raised");
+    let hash = key_hash(key);
+    let admitted = self
+      .state
+      .lock()
+      .expect("alert state poisoned")
+      .admit(hash, Instant::now());
+    let Some(suppressed) = admitted else {
+      info!(alert = key, "alert suppressed");
+      return;
+    };
+
+    let mut text = text.to_string();
+    if suppressed > 0 {
+      text.push_str(&format!(
+        "\n\n(+{suppressed} similar alert(s) held back since the last one)"
+      ));
+    }
+    let keyboard = InlineKeyboardMarkup::new([[CallbackData::MuteAlert(hash).button("🔕 Mute for 1h")]]);
+    for chat in self.targets.iter() {
+      let request = bot.send_message(*chat, &text).reply_markup(keyboard.clone());
+      if let Err(err) = metrics::notification("alert", request).await {
+        warn!(chat_id = %chat, alert = key, error = %err, "failed to post alert");
+      }
+    }
+  }
+
+  fn mute(&self, hash: u64) {
+    self
+      .state
+      .lock()
+      .expect("alert state poisoned")
+      .mute(hash, Instant::now() + MUTE_DURATION);
+  }
+}
+
+/// Keys are hashed so the mute button fits into callback data. The hash only has to be stable within one process,
+/// like the mutes themselves.
+fn key_hash(key: &str) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  key.hash(&mut hasher);
+  hasher.finish()
+}
+
+#[derive(Default)]
+struct AlertState {
+  keys: HashMap<u64, KeyState>,
+  /// When the alerts of the current rate window were posted, oldest first.
+  sent: VecDeque<Instant>,
+}
+
+#[derive(Default)]
+struct KeyState {
+  last_sent: Option<Instant>,
+  suppressed: u32,
+  muted_until: Option<Instant>,
+}
+
+impl AlertState {
+  /// Returns the number of held back repeats to mention when the alert should be posted, `None` when it is held back.
+  fn admit(&mut self, hash: u64, now: Instant) -> Option<u32> {
+    while self
+      .sent
+      .front()
+      .is_some_and(|sent| now.duration_since(*sent) >= RATE_WINDOW)
+    {
+      self.sent.pop_front();
+    }
+
+    let key = self.keys.entry(hash).or_default();
+    let muted = key.muted_until.is_some_and(|until| now < until);
+    let recent = key
+      .last_sent
+      .is_some_and(|sent| now.duration_since(sent) < DEDUP_WINDOW);
+    if muted || recent || self.sent.len() >= MAX_ALERTS_PER_WINDOW {
+      key.suppressed += 1;
+      return None;
+    }
+
+    key.last_sent = Some(now);
+    self.sent.push_back(now);
+    Some(std::mem::take(&mut key.suppressed))
+  }
+
+  fn mute(&mut self, hash: u64, until: Instant) {
+    self.keys.entry(hash).or_default().muted_until = Some(until);
+  }
+}
+
+/// Mutes an alert for an hour. Admins can mute from anywhere, everyone else only inside the alert chat.
+#[instrument(skip(bot, ctx, query))]
+pub async fn handle_callback(bot: Bot, ctx: Arc<AppContext>, query: CallbackQuery, hash: u64) -> HandlerResult {
+  let text = mute(&bot, &ctx, &query, hash).await?;
+  callback::answer(&bot, &query, Some(text.to_string())).await
+}
+
+async fn mute(bot: &Bot, ctx: &AppContext, query: &CallbackQuery, hash: u64) -> Result<&'static str> {
+  let chat = query.message.as_ref().map(|message| message.chat().id);
+  let allowed = ctx.is_admin(query.from.id.0 as i64) || chat.is_some_and(|chat| ctx.alerts().is_target(chat));
+  if !allowed {
+    return Ok("🛡️ Admins only.");
+  }
+
+  ctx.alerts().mute(hash);
+  info!(user_id = query.from.id.0, "alert muted");
+  if let Some(message) = &query.message {
+    bot.edit_message_reply_markup(message.chat().id, message.id()).await?;
+  }
+  Ok("🔕 Muted for 1 hour.")
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+  use std::time::Instant;
+
+  use super::AlertState;
+  use super::DEDUP_WINDOW;
+  use super::MAX_ALERTS_PER_WINDOW;
+  use super::RATE_WINDOW;
+
+  #[test]
+  fn deduplicates_repeats_and_reports_them_later() {
+    let mut state = AlertState::default();
+    let start = Instant::now();
+    assert_eq!(state.admit(1, start), Some(0));
+    assert_eq!(state.admit(1, start + Duration::from_secs(1)), None);
+    assert_eq!(state.admit(1, start + Duration::from_secs(2)), None);
+    assert_eq!(state.admit(2, start + Duration::from_secs(3)), Some(0));
+    assert_eq!(state.admit(1, start + DEDUP_WINDOW), Some(2));
+  }
+
+  #[test]
+  fn limits_alerts_per_window() {
+    let mut state = AlertState::default();
+    let start = Instant::now();
+    for key in 0 .. MAX_ALERTS_PER_WINDOW as u64 {
+      assert_eq!(state.admit(key, start), Some(0));
+    }
+    assert_eq!(state.admit(99, start), None);
+    assert_eq!(state.admit(99, start + RATE_WINDOW), Some(1));
+  }
+
+  #[test]
+  fn mutes_a_key_until_the_deadline() {
+    let mut state = AlertState::default();
+    let start = Instant::now();
+    state.mute(1, start + Duration::from_secs(3600));
+    assert_eq!(state.admit(1, start), None);
+    assert_eq!(state.admit(2, start), Some(0));
+    assert_eq!(state.admit(1, start + Duration::from_secs(3600)), Some(1));
+  }
+}
@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::json;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardButton;
+use teloxide::types::InlineKeyboardMarkup;
+use tracing::info;
+use tracing::instrument;
+
+use crate::bot::HandlerResult;
+use crate::bot::audit;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::callback::MenuTarget;
+use crate::bot::context::AppContext;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
+use crate::models::CategoryRow;
+
+type SharedContext = Arc<AppContext>;
+
+/// How many archived items and categories the admin view lists.
+const ARCHIVE_LIMIT: i64 = 20;
+
+/// An admin decision in the archive: restoring an entry, or where the items of a category being removed go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveAction {
+  RestoreItem { item_id: i64 },
+  RestoreCategory { category_id: i64 },
+  MoveItems { from: i64, to: i64 },
+  ArchiveWithItems { category_id: i64 },
+}
+
+pub fn restore_item_button(item_id: i64) -> InlineKeyboardButton {
+  CallbackData::Archive(ArchiveAction::RestoreItem { item_id }).button("♻️ Restore")
+}
+
+/// Archives an empty category right away. A category that still has items gets a picker instead, so the admin can
+/// move them elsewhere or archive them along with it.
+#[instrument(skip(bot, ctx, category), fields(category_id = category.id))]
+pub async fn remove_category(
+  bot: &Bot,
+  ctx: &SharedContext,
+  chat: ChatId,
+  admin_tg_id: i64,
+  category: &CategoryRow,
+) -> HandlerResult {
+  let item_count = ctx.db().list_items_by_category(category.id).await?.len();
+  if item_count == 0 {
+    let archived = ctx.db().archive_category(category.id, admin_tg_id).await?;
+    info!(admin_tg_id, archived, "archived empty category");
+    let text = if archived {
+      record_category_archived(ctx, admin_tg_id, category, json!({ "name": category.name })).await;
+      format!("🗃 Category '{}' archived.", category.name)
+    } else {
+      "❓ Category not found or already removed.".to_string()
+    };
+    bot.send_message(chat, text).await?;
+    return Ok(());
+  }
+
+  let targets: Vec<CategoryRow> = ctx
+    .db()
+    .list_categories()
+    .await?
+    .into_iter()
+    .filter(|other| other.id != category.id)
+    .collect();
+  bot
+    .send_message(
+      chat,
+      format!(
+        "🗃 Category '{}' still has {} item(s). Move them to another category, or archive them with it:",
+        category.name, item_count
+      ),
+    )
+    .reply_markup(removal_keyboard(category.id, &targets))
+    .await?;
+  Ok(())
+}
+
+fn removal_keyboard(category_id: i64, targets: &[CategoryRow]) -> InlineKeyboardMarkup {
+  let mut rows: Vec<Vec<InlineKeyboardButton>> = targets
+    .chunks(2)
+    .map(|chunk| {
+      chunk
+        .iter()
+        .map(|target| {
+          CallbackData::Archive(ArchiveAction::MoveItems {
+            from: category_id,
+            to: target.id,
+          })
+          .button(format!("➡️ {}", target.name))
+        })
+        .collect()
+    })
+    .collect();
+  rows.push(vec![
+    CallbackData::Archive(ArchiveAction::ArchiveWithItems { category_id }).button("🗃 Archive with items"),
+  ]);
+  rows.push(vec![CallbackData::Menu(MenuTarget::Root).button("❌ Keep category")]);
+  InlineKeyboardMarkup::new(rows)
+}
+
+/// Lists recently archived categories and items with restore buttons.
+#[instrument(skip(bot, ctx))]
+pub async fn send_archive(bot: &Bot, ctx: &SharedContext, chat: ChatId) -> HandlerResult {
+  let categories = ctx.db().list_archived_categories(ARCHIVE_LIMIT).await?;
+  let items = ctx.db().list_archived_items(ARCHIVE_LIMIT).await?;
+  if categories.is_empty() && items.is_empty() {
+    bot.send_message(chat, "🗃 The archive is empty.").await?;
+    return Ok(());
+  }
+
+  let mut admin_ids: Vec<i64> = categories
+    .iter()
+    .filter_map(|(_, by)| *by)
+    .chain(items.iter().filter_map(|(_, by)| *by))
+    .collect();
+  admin_ids.sort_unstable();
+  admin_ids.dedup();
+  let names: HashMap<i64, String> = ctx
+    .db()
+    .list_users(&admin_ids)
+    .await?
+    .into_iter()
+    .map(|user| (user.id, user.display_name()))
+    .collect();
+  let archived_by = |deleted_by: Option<i64>| match deleted_by {
+    Some(id) => names.get(&id).cloned().unwrap_or_else(|| format!("user {id}")),
+    None => "unknown".to_string(),
+  };
+
+  let mut text = "🗃 Archive".to_string();
+  let mut rows = Vec::new();
+  if !categories.is_empty() {
+    text.push_str("\n\nCategories:");
+    for (category, deleted_by) in &categories {
+      text.push_str(&format!("\n• {} (by {})", category.name, archived_by(*deleted_by)));
+      rows.push(vec![
+        CallbackData::Archive(ArchiveAction::RestoreCategory {
+          category_id: category.id,
+        })
+        .button(format!("♻️ Category {}", category.name)),
+      ]);
+    }
+  }
+  if !items.is_empty() {
+    text.push_str("\n\nItems:");
+    for (item, deleted_by) in &items {
+      text.push_str(&format!(
+        "\n• #{} {} (by {})",
+        item.id,
+        item.title,
+        archived_by(*deleted_by)
+      ));
+    }
+    for chunk in items.chunks(4) {
+      rows.push(
+        chunk
+          .iter()
+          .map(|(item, _)| {
+            CallbackData::Archive(ArchiveAction::RestoreItem { item_id: item.id }).button(format!("♻️ #{}", item.id))
+          })
+          .collect(),
+      );
+    }
+  }
+
+  bot
+    .send_message(chat, text)
+    .reply_markup(InlineKeyboardMarkup::new(rows))
+    .await?;
+  Ok(())
+}
+
+/// Handles the admin-only archive callbacks.
+#[instrument(skip(bot, ctx, query))]
+pub async fn handle_callback(
+  bot: Bot,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  action: ArchiveAction,
+) -> HandlerResult {
+  let text = apply_action(&bot, &ctx, &query, action).await?;
+  callback::answer(&bot, &query, text).await
+}
+
+/// Runs an archive action and returns the text for the callback answer, if any.
+async fn apply_action(
+  bot: &Bot,
+  ctx: &SharedContext,
+  query: &CallbackQuery,
+  action: ArchiveAction,
+) -> Result<Option<String>> {
+  let admin_tg_id = query.from.id.0 as i64;
+  if !ctx.is_admin(admin_tg_id) {
+    return Ok(Some("🛡️ Admins only.".to_string()));
+  }
+
+  match action {
+    ArchiveAction::RestoreItem { item_id } => {
+      if ctx.db().restore_item(item_id).await? {
+        info!(admin_tg_id, item_id, "restored item");
+        audit::record(
+          ctx,
+          admin_tg_id,
+          AuditAction::ItemRestored,
+          AuditTarget::Item(item_id),
+          json!({}),
+        )
+        .await;
+        Ok(Some(format!("♻️ Item #{item_id} restored.")))
+      } else {
+        Ok(Some(
+          "Item is not archived, or its category must be restored first.".to_string(),
+        ))
+      }
+    },
+    ArchiveAction::RestoreCategory { category_id } => {
+      if ctx.db().restore_category(category_id).await? {
+        info!(admin_tg_id, category_id, "restored category");
+        audit::record(
+          ctx,
+          admin_tg_id,
+          AuditAction::CategoryRestored,
+          AuditTarget::Category(category_id),
+          json!({}),
+        )
+        .await;
+        Ok(Some("♻️ Category restored. Restore its items one by one.".to_string()))
+      } else {
+        Ok(Some(
+          "Category is not archived, or another category already uses its name.".to_string(),
+        ))
+      }
+    },
+    ArchiveAction::MoveItems { from, to } => {
+      let (Some(source), Some(target)) = (ctx.db().get_category(from).await?, ctx.db().get_category(to).await?)
+      else {
+        return Ok(Some("❓ Category not found".to_string()));
+      };
+      let Some(moved) = ctx.db().archive_category_moving_items(from, to, admin_tg_id).await? else {
+        return Ok(Some(format!(
+          "⚠️ Nothing changed: '{}' is already archived or '{}' is no longer active.",
+          source.name, target.name
+        )));
+      };
+      info!(admin_tg_id, from, to, moved, "moved items and archived category");
+      record_category_archived(
+        ctx,
+        admin_tg_id,
+        &source,
+        json!({ "name": source.name, "items_moved_to": target.name, "items_moved": moved }),
+      )
+      .await;
+      close_picker(
+        bot,
+        query,
+        &format!(
+          "🗃 Category '{}' archived. {} item(s) moved to '{}'.",
+          source.name, moved, target.name
+        ),
+      )
+      .await?;
+      Ok(None)
+    },
+    ArchiveAction::ArchiveWithItems { category_id } => {
+      let Some(category) = ctx.db().get_category(category_id).await? else {
+        return Ok(Some("❓ Category not found".to_string()));
+      };
+      let archived = ctx.db().archive_category(category_id, admin_tg_id).await?;
+      info!(admin_tg_id, category_id, archived, "archived category with its items");
+      let text = if archived {
+        record_category_archived(
+          ctx,
+          admin_tg_id,
+          &category,
+          json!({ "name": category.name, "items_archived": true }),
+        )
+        .await;
+        format!(
+          "🗃 Category '{}' and its items archived. Bids and favorites are kept.",
+          category.name
+        )
+      } else {
+        "❓ Category not found or already removed.".to_string()
+      };
+      close_picker(bot, query, &text).await?;
+      Ok(None)
+    },
+  }
+}
+
+async fn record_category_archived(
+  ctx: &SharedContext,
+  admin_tg_id: i64,
+  category: &CategoryRow,
+  payload: serde_json::Value,
+) {
+  audit::record(
+    ctx,
+    admin_tg_id,
+    AuditAction::CategoryArchived,
+    AuditTarget::Category(category.id),
+    payload,
+  )
+  .await;
+}
+
+async fn close_picker(bot: &Bot, query: &CallbackQuery, text: &str) -> Result<()> {
+  let Some(message) = query.message.as_ref() else {
+    return Ok(());
+  };
+  let request = bot
+    .edit_message_text(message.chat().id, message.id(), text)
+    .reply_markup(InlineKeyboardMarkup::default());
+  match request.await {
+    Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(()),
+    Err(err) => Err(err.into()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::removal_keyboard;
+  use crate::models::CategoryRow;
+
+  #[test]
+  fn removal_keyboard_offers_every_other_category() {
+    let targets = vec![
+      CategoryRow {
+        id: 5,
+        name: "Books".to_string(),
+        currency: None,
+      },
+      CategoryRow {
+        id: 6,
+        name: "Lamps".to_string(),
+        currency: None,
+      },
+    ];
+    let keyboard = removal_keyboard(2, &targets);
+    let labels: Vec<&str> = keyboard
+      .inline_keyboard
+      .iter()
+      .flatten()
+      .map(|button| button.text.as_str())
+      .collect();
+    assert_eq!(labels, vec![
+      "➡️ Books",
+      "➡️ Lamps",
+      "🗃 Archive with items",
+      "❌ Keep category"
+    ]);
+  }
+}
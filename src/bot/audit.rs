@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Utc;
+use serde_json::Value;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardMarkup;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::HandlerResult;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::context::AppContext;
+use crate::models::AuditAction;
+use crate::models::AuditEntryRow;
+use crate::models::AuditTarget;
+
+type SharedContext = Arc<AppContext>;
+
+/// Entries listed per audit log page.
+const AUDIT_PAGE_SIZE: usize = 10;
+/// Longest payload value shown in the log before it is cut short.
+const PAYLOAD_VALUE_LIMIT: usize = 60;
+/// Actor recorded for actions with no Telegram user behind them: the operator console subcommands and the
+/// scheduler.
+pub const SYSTEM_ACTOR: i64 = 0;
+
+/// Records a privileged action. A failed write is logged but never fails the action itself.
+pub async fn record(ctx: &AppContext, actor_tg_id: i64, action: AuditAction, target: AuditTarget, payload: Value) {
+  if let Err(err) = ctx.db().record_audit(actor_tg_id, action, target, &payload).await {
+    warn!(error = %err, actor_tg_id, action = action.as_str(), "failed to write audit log entry");
+  }
+}
+
+/// A view of the audit log: a page of entries under an action filter, or the filter picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditView {
+  Page { filter: Option<AuditAction>, page: usize },
+  Filters { current: Option<AuditAction> },
+}
+
+/// Posts the first page of the unfiltered audit log as a new message.
+#[instrument(skip(bot, ctx))]
+pub async fn send_audit_log(bot: &Bot, ctx: &SharedContext, chat: ChatId) -> HandlerResult {
+  let (text, keyboard) = load_page(ctx, None, 0).await?;
+  bot.send_message(chat, text).reply_markup(keyboard).await?;
+  Ok(())
+}
+
+/// Handles the admin-only audit log callbacks. Pages flip in place and the filter button swaps the page for the
+/// action picker.
+#[instrument(skip(bot, ctx, query))]
+pub async fn handle_callback(bot: Bot, ctx: SharedContext, query: CallbackQuery, view: AuditView) -> HandlerResult {
+  let text = show_view(&bot, &ctx, &query, view).await?;
+  callback::answer(&bot, &query, text).await
+}
+
+async fn show_view(bot: &Bot, ctx: &SharedContext, query: &CallbackQuery, view: AuditView) -> Result<Option<String>> {
+  let user_id = query.from.id.0 as i64;
+  if !ctx.is_admin(user_id) {
+    return Ok(Some("🛡️ Admins only.".to_string()));
+  }
+  let Some(message) = query.message.as_ref() else {
+    return Ok(None);
+  };
+
+  let (text, keyboard) = match view {
+    AuditView::Page { filter, page } => {
+      info!(user_id, ?filter, page, "browsing audit log");
+      load_page(ctx, filter, page).await?
+    },
+    AuditView::Filters { current } => (
+      "📋 Audit log — show which actions?".to_string(),
+      filter_keyboard(current),
+    ),
+  };
+  let request = bot
+    .edit_message_text(message.chat().id, message.id(), text)
+    .reply_markup(keyboard);
+  match request.await {
+    Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(None),
+    Err(err) => Err(err.into()),
+  }
+}
+
+async fn load_page(
+  ctx: &SharedContext,
+  filter: Option<AuditAction>,
+  page: usize,
+) -> Result<(String, InlineKeyboardMarkup)> {
+  // One extra row tells whether an older page exists.
+  let mut entries = ctx
+    .db()
+    .list_audit_log(filter, AUDIT_PAGE_SIZE as i64 + 1, (page * AUDIT_PAGE_SIZE) as i64)
+    .await?;
+  let has_older = entries.len() > AUDIT_PAGE_SIZE;
+  entries.truncate(AUDIT_PAGE_SIZE);
+
+  let mut actor_ids: Vec<i64> = entries.iter().map(|entry| entry.actor_tg_id).collect();
+  actor_ids.sort_unstable();
+  actor_ids.dedup();
+  let names: HashMap<i64, String> = ctx
+    .db()
+    .list_users(&actor_ids)
+    .await?
+    .into_iter()
+    .map(|user| (user.id, user.display_name()))
+    .collect();
+
+  Ok((
+    render_page(&entries, &names, filter, page),
+    page_keyboard(filter, page, has_older),
+  ))
+}
+
+fn render_page(
+  entries: &[AuditEntryRow],
+  names: &HashMap<i64, String>,
+  filter: Option<AuditAction>,
+  page: usize,
+) -> String {
+  let mut text = "📋 Audit log".to_string();
+  if let Some(action) = filter {
+    text.push_str(&format!(" — {}", action.label()));
+  }
+  if entries.is_empty() {
+    text.push_str("\n\nNo entries.");
+    return text;
+  }
+
+  text.push_str(&format!("\nPage {}", page + 1));
+  for entry in entries {
+    let actor = match names.get(&entry.actor_tg_id) {
+      Some(name) => name.clone(),
+      None if entry.actor_tg_id == SYSTEM_ACTOR => "system".to_string(),
+      None => format!("user {}", entry.actor_tg_id),
+    };
+    text.push_str(&format!(
+      "\n\n#{} · {} · {}\n{}",
+      entry.id,
+      format_timestamp(entry.created_at),
+      actor,
+      entry.action.label()
+    ));
+    if let Some(target) = entry.target.and_then(describe_target) {
+      text.push_str(&format!(" · {target}"));
+    }
+    let details = summarize_payload(&entry.payload);
+    if !details.is_empty() {
+      text.push_str(&format!("\n{details}"));
+    }
+  }
+  text
+}
+
+fn describe_target(target: AuditTarget) -> Option<String> {
+  target.id().map(|id| format!("{} #{id}", target.kind()))
+}
+
+/// Renders a payload object as `key: value` pairs, cutting long values short.
+fn summarize_payload(payload: &Value) -> String {
+  let Value::Object(fields) = payload else {
+    return String::new();
+  };
+  fields
+    .iter()
+    .filter(|(_, value)| !value.is_null())
+    .map(|(key, value)| {
+      let value = match value {
+        Value::String(text) => text.clone(),
+        Value::Array(values) => values
+          .iter()
+          .map(|value| value.to_string())
+          .collect::<Vec<_>>()
+          .join(", "),
+        other => other.to_string(),
+      };
+      format!("{key}: {}", truncate(&value, PAYLOAD_VALUE_LIMIT))
+    })
+    .collect::<Vec<_>>()
+    .join(" · ")
+}
+
+fn truncate(value: &str, limit: usize) -> String {
+  let mut chars = value.chars();
+  let head: String = chars.by_ref().take(limit).collect();
+  if chars.next().is_some() {
+    format!("{head}…")
+  } else {
+    head
+  }
+}
+
+fn format_timestamp(at: DateTime<Utc>) -> String {
+  at.format("%d %b %H:%M").to_string()
+}
+
+fn page_keyboard(filter: Option<AuditAction>, page: usize, has_older: bool) -> InlineKeyboardMarkup {
+  let mut navigation = Vec::new();
+  if page > 0 {
+    navigation.push(CallbackData::Audit(AuditView::Page { filter, page: page - 1 }).button("⬅️ Newer"));
+  }
+  if has_older {
+    navigation.push(CallbackData::Audit(AuditView::Page { filter, page: page + 1 }).button("Older ➡️"));
+  }
+
+  let mut rows = Vec::new();
+  if !navigation.is_empty() {
+    rows.push(navigation);
+  }
+  rows.push(vec![
+    CallbackData::Audit(AuditView::Filters { current: filter }).button("🔎 Filter"),
+  ]);
+  InlineKeyboardMarkup::new(rows)
+}
+
+fn filter_keyboard(current: Option<AuditAction>) -> InlineKeyboardMarkup {
+  let mark = |selected: bool, label: &str| {
+    if selected {
+      format!("✅ {label}")
+    } else {
+      label.to_string()
+    }
+  };
+  let mut rows = vec![vec![
+    CallbackData::Audit(AuditView::Page { filter: None, page: 0 }).button(mark(current.is_none(), "All actions")),
+  ]];
+  rows.extend(AuditAction::ALL.chunks(2).map(|chunk| {
+    chunk
+      .iter()
+      .map(|action| {
+        CallbackData::Audit(AuditView::Page {
+          filter: Some(*action),
+          page: 0,
+        })
+        .button(mark(current == Some(*action), action.label()))
+      })
+      .collect()
+  }));
+  InlineKeyboardMarkup::new(rows)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use chrono::Utc;
+  use serde_json::json;
+
+  use super::page_keyboard;
+  use super::render_page;
+  use super::summarize_payload;
+  use crate::models::AuditAction;
+  use crate::models::AuditEntryRow;
+  use crate::models::AuditTarget;
+
+  #[test]
+  fn summarizes_payload_fields() {
+    let payload = json!({
+      "title": "Clock",
+      "item_ids": [3, 4],
+      "order_id": null,
+      "text": "x".repeat(80),
+    });
+    let summary = summarize_payload(&payload);
+    assert!(summary.contains("title: Clock"));
+    assert!(summary.contains("item_ids: 3, 4"));
+    assert!(!summary.contains("order_id"));
+    assert!(summary.contains(&format!("text: {}…", "x".repeat(60))));
+  }
+
+  #[test]
+  fn renders_entries_with_actor_and_target() {
+    let entries = vec![AuditEntryRow {
+      id: 7,
+      actor_tg_id: 42,
+      action: AuditAction::ItemClosed,
+      target: Some(AuditTarget::Item(5)),
+      payload: json!({ "title": "Clock" }),
+      created_at: Utc::now(),
+    }];
+    let names = HashMap::from([(42, "@alice".to_string())]);
+    let text = render_page(&entries, &names, Some(AuditAction::ItemClosed), 0);
+    assert!(text.starts_with("📋 Audit log — 🛑 Item closed"));
+    assert!(text.contains("@alice\n🛑 Item closed · item #5\ntitle: Clock"));
+  }
+
+  #[test]
+  fn keyboard_pages_within_the_filter() {
+    let keyboard = page_keyboard(Some(AuditAction::Broadcast), 1, true);
+    let data: Vec<String> = keyboard
+      .inline_keyboard
+      .iter()
+      .flatten()
+      .map(|button| format!("{:?}", button.kind))
+      .collect();
+    assert!(data[0].contains("audit:page:broadcast:0"));
+    assert!(data[1].contains("audit:page:broadcast:2"));
+    assert!(data[2].contains("audit:filters:broadcast"));
+  }
+}
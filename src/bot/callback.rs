@@ -0,0 +1,526 @@
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardButton;
+
+use crate::bot::HandlerResult;
+use crate::bot::archive::ArchiveAction;
+use crate::bot::audit::AuditView;
+use crate::bot::history::HistoryView;
+use crate::bot::import::ImportAction;
+use crate::bot::quick_bid::QuickBidAction;
+use crate::bot::second_chance::SecondChanceAction;
+use crate::i18n::Locale;
+use crate::metrics;
+use crate::models::AuditAction;
+use crate::models::OrderStatus;
+
+/// Telegram rejects buttons whose callback data is longer than this many bytes.
+const MAX_CALLBACK_DATA_LEN: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuTarget {
+  Root,
+  Catalogue,
+  Favorites,
+  MyBids,
+  MyWins,
+  Settings,
+  Admin,
+}
+
+impl MenuTarget {
+  const ALL: [MenuTarget; 7] = [
+    Self::Root,
+    Self::Catalogue,
+    Self::Favorites,
+    Self::MyBids,
+    Self::MyWins,
+    Self::Settings,
+    Self::Admin,
+  ];
+
+  fn as_str(self) -> &'static str {
+    match self {
+      Self::Root => "root",
+      Self::Catalogue => "catalogue",
+      Self::Favorites => "favorites",
+      Self::MyBids => "my_bids",
+      Self::MyWins => "my_wins",
+      Self::Settings => "settings",
+      Self::Admin => "admin",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminAction {
+  AddCategory,
+  AddItem,
+  RemoveItem,
+  RemoveCategory,
+  CloseItem,
+  Broadcast,
+  NotifyNew,
+  Orders,
+  Archive,
+  Audit,
+  Export,
+  Import,
+  Events,
+}
+
+impl AdminAction {
+  const ALL: [AdminAction; 13] = [
+    Self::AddCategory,
+    Self::AddItem,
+    Self::RemoveItem,
+    Self::RemoveCategory,
+    Self::CloseItem,
+    Self::Broadcast,
+    Self::NotifyNew,
+    Self::Orders,
+    Self::Archive,
+    Self::Audit,
+    Self::Export,
+    Self::Import,
+    Self::Events,
+  ];
+
+  fn as_str(self) -> &'static str {
+    match self {
+      Self::AddCategory => "add_category",
+      Self::AddItem => "add_item",
+      Self::RemoveItem => "remove_item",
+      Self::RemoveCategory => "remove_category",
+      Self::CloseItem => "close_item",
+      Self::Broadcast => "broadcast",
+      Self::NotifyNew => "notify_new",
+      Self::Orders => "orders",
+      Self::Archive => "archive",
+      Self::Audit => "audit",
+      Self::Export => "export",
+      Self::Import => "import",
+      Self::Events => "events",
+    }
+  }
+}
+
+/// A choice in the category picker of the add-item flow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategoryPick {
+  New,
+  Existing(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FavoriteAction {
+  Add,
+  Remove,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsAction {
+  ToggleNotifications,
+  ChooseLanguage,
+  /// `None` goes back to following the Telegram language.
+  SetLocale(Option<Locale>),
+}
+
+/// Everything an inline button can ask the bot to do. Keyboards build their buttons from these values and incoming
+/// queries are decoded back before routing, so the wire format lives in this one place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackData {
+  Menu(MenuTarget),
+  Admin(AdminAction),
+  PickCategory(CategoryPick),
+  Category(i64),
+  BackToCategories,
+  Item(i64),
+  MoreImages {
+    item_id: i64,
+    offset: usize,
+  },
+  Bid(i64),
+  Favorite {
+    action: FavoriteAction,
+    item_id: i64,
+  },
+  Settings(SettingsAction),
+  QuickBid(QuickBidAction),
+  History(HistoryView),
+  SetOrderStatus {
+    order_id: i64,
+    status: OrderStatus,
+  },
+  Audit(AuditView),
+  Archive(ArchiveAction),
+  Relist(i64),
+  SecondChance(SecondChanceAction),
+  RetractBid(i64),
+  /// Mutes the alert with this key hash.
+  MuteAlert(u64),
+  Import(ImportAction),
+}
+
+impl CallbackData {
+  pub fn encode(&self) -> String {
+    let data = match self {
+      Self::Menu(target) => format!("menu:{}", target.as_str()),
+      Self::Admin(action) => format!("admin:{}", action.as_str()),
+      Self::PickCategory(CategoryPick::New) => "pickcat:new".to_string(),
+      Self::PickCategory(CategoryPick::Existing(id)) => format!("pickcat:{id}"),
+      Self::Category(id) => format!("cat:{id}"),
+      Self::BackToCategories => "back:categories".to_string(),
+      Self::Item(id) => format!("item:{id}"),
+      Self::MoreImages { item_id, offset } => format!("img:{item_id}:{offset}"),
+      Self::Bid(id) => format!("bid:{id}"),
+      Self::Favorite {
+        action: FavoriteAction::Add,
+        item_id,
+      } => format!("fav:add:{item_id}"),
+      Self::Favorite {
+        action: FavoriteAction::Remove,
+        item_id,
+      } => format!("fav:remove:{item_id}"),
+      Self::Settings(SettingsAction::ToggleNotifications) => "settings:toggle_notifications".to_string(),
+      Self::Settings(SettingsAction::ChooseLanguage) => "settings:language".to_string(),
+      Self::Settings(SettingsAction::SetLocale(locale)) => {
+        format!("settings:locale:{}", locale.map_or("auto", Locale::code))
+      },
+      Self::QuickBid(QuickBidAction::Ask { item_id, amount }) => format!("qbid:ask:{item_id}:{amount}"),
+      Self::QuickBid(QuickBidAction::Confirm {
+        item_id,
+        amount,
+        user_id,
+      }) => format!("qbid:ok:{item_id}:{amount}:{user_id}"),
+      Self::QuickBid(QuickBidAction::Cancel { user_id }) => format!("qbid:cancel:{user_id}"),
+      Self::History(HistoryView::Open { item_id }) => format!("hist:open:{item_id}"),
+      Self::History(HistoryView::Page { item_id, page }) => format!("hist:page:{item_id}:{page}"),
+      Self::SetOrderStatus { order_id, status } => format!("order:set:{order_id}:{}", status.as_str()),
+      Self::Audit(AuditView::Page { filter, page }) => format!("audit:page:{}:{page}", audit_filter(*filter)),
+      Self::Audit(AuditView::Filters { current }) => format!("audit:filters:{}", audit_filter(*current)),
+      Self::Archive(ArchiveAction::RestoreItem { item_id }) => format!("archive:restore_item:{item_id}"),
+      Self::Archive(ArchiveAction::RestoreCategory { category_id }) => {
+        format!("archive:restore_category:{category_id}")
+      },
+      Self::Archive(ArchiveAction::MoveItems { from, to }) => format!("archive:move:{from}:{to}"),
+      Self::Archive(ArchiveAction::ArchiveWithItems { category_id }) => format!("archive:drop:{category_id}"),
+      Self::Relist(item_id) => format!("relist:{item_id}"),
+      Self::SecondChance(SecondChanceAction::Offer { item_id }) => format!("second:offer:{item_id}"),
+      Self::SecondChance(SecondChanceAction::Accept { offer_id }) => format!("second:accept:{offer_id}"),
+      Self::SecondChance(SecondChanceAction::Decline { offer_id }) => format!("second:decline:{offer_id}"),
+      Self::RetractBid(bid_id) => format!("retract:pick:{bid_id}"),
+      Self::MuteAlert(hash) => format!("mute:{hash}"),
+      Self::Import(action) => format!("import:{}", action.as_str()),
+    };
+    debug_assert!(data.len() <= MAX_CALLBACK_DATA_LEN, "callback data too long: {data}");
+    data
+  }
+
+  /// Parses callback data produced by [`CallbackData::encode`]. Anything else, including buttons from a format that
+  /// no longer exists, yields `None`.
+  pub fn decode(data: &str) -> Option<Self> {
+    let parts: Vec<&str> = data.split(':').collect();
+    let decoded = match parts.as_slice() {
+      ["menu", target] => Self::Menu(
+        MenuTarget::ALL
+          .into_iter()
+          .find(|candidate| candidate.as_str() == *target)?,
+      ),
+      ["admin", action] => Self::Admin(
+        AdminAction::ALL
+          .into_iter()
+          .find(|candidate| candidate.as_str() == *action)?,
+      ),
+      ["pickcat", "new"] => Self::PickCategory(CategoryPick::New),
+      ["pickcat", id] => Self::PickCategory(CategoryPick::Existing(id.parse().ok()?)),
+      ["cat", id] => Self::Category(id.parse().ok()?),
+      ["back", "categories"] => Self::BackToCategories,
+      ["item", id] => Self::Item(id.parse().ok()?),
+      ["img", item_id, offset] => Self::MoreImages {
+        item_id: item_id.parse().ok()?,
+        offset: offset.parse().ok()?,
+      },
+      ["bid", id] => Self::Bid(id.parse().ok()?),
+      ["fav", action, item_id] => Self::Favorite {
+        action: match *action {
+          "add" => FavoriteAction::Add,
+          "remove" => FavoriteAction::Remove,
+          _ => return None,
+        },
+        item_id: item_id.parse().ok()?,
+      },
+      ["settings", "toggle_notifications"] => Self::Settings(SettingsAction::ToggleNotifications),
+      ["settings", "language"] => Self::Settings(SettingsAction::ChooseLanguage),
+      ["settings", "locale", "auto"] => Self::Settings(SettingsAction::SetLocale(None)),
+      ["settings", "locale", code] => Self::Settings(SettingsAction::SetLocale(Some(
+        Locale::ALL.into_iter().find(|locale| locale.code() == *code)?,
+      ))),
+      ["qbid", "ask", item_id, amount] => Self::QuickBid(QuickBidAction::Ask {
+        item_id: item_id.parse().ok()?,
+        amount: amount.parse().ok()?,
+      }),
+      ["qbid", "ok", item_id, amount, user_id] => Self::QuickBid(QuickBidAction::Confirm {
+        item_id: item_id.parse().ok()?,
+        amount: amount.parse().ok()?,
+        user_id: user_id.parse().ok()?,
+      }),
+      ["qbid", "cancel", user_id] => Self::QuickBid(QuickBidAction::Cancel {
+        user_id: user_id.parse().ok()?,
+      }),
+      ["hist", "open", item_id] => Self::History(HistoryView::Open {
+        item_id: item_id.parse().ok()?,
+      }),
+      ["hist", "page", item_id, page] => Self::History(HistoryView::Page {
+        item_id: item_id.parse().ok()?,
+        page: page.parse().ok()?,
+      }),
+      ["order", "set", order_id, status] => Self::SetOrderStatus {
+        order_id: order_id.parse().ok()?,
+        status: OrderStatus::parse(status)?,
+      },
+      ["audit", "page", filter, page] => Self::Audit(AuditView::Page {
+        filter: parse_audit_filter(filter)?,
+        page: page.parse().ok()?,
+      }),
+      ["audit", "filters", filter] => Self::Audit(AuditView::Filters {
+        current: parse_audit_filter(filter)?,
+      }),
+      ["archive", "restore_item", item_id] => Self::Archive(ArchiveAction::RestoreItem {
+        item_id: item_id.parse().ok()?,
+      }),
+      ["archive", "restore_category", category_id] => Self::Archive(ArchiveAction::RestoreCategory {
+        category_id: category_id.parse().ok()?,
+      }),
+      ["archive", "move", from, to] => Self::Archive(ArchiveAction::MoveItems {
+        from: from.parse().ok()?,
+        to: to.parse().ok()?,
+      }),
+      ["archive", "drop", category_id] => Self::Archive(ArchiveAction::ArchiveWithItems {
+        category_id: category_id.parse().ok()?,
+      }),
+      ["relist", item_id] => Self::Relist(item_id.parse().ok()?),
+      ["second", "offer", item_id] => Self::SecondChance(SecondChanceAction::Offer {
+        item_id: item_id.parse().ok()?,
+      }),
+      ["second", "accept", offer_id] => Self::SecondChance(SecondChanceAction::Accept {
+        offer_id: offer_id.parse().ok()?,
+      }),
+      ["second", "decline", offer_id] => Self::SecondChance(SecondChanceAction::Decline {
+        offer_id: offer_id.parse().ok()?,
+      }),
+      ["retract", "pick", bid_id] => Self::RetractBid(bid_id.parse().ok()?),
+      ["mute", hash] => Self::MuteAlert(hash.parse().ok()?),
+      ["import", action] => Self::Import(ImportAction::parse(action)?),
+      _ => return None,
+    };
+    Some(decoded)
+  }
+
+  /// The route's name in metrics and traces, matching its wire prefix.
+  pub fn route(&self) -> &'static str {
+    match self {
+      Self::Menu(_) => "menu",
+      Self::Admin(_) => "admin",
+      Self::PickCategory(_) => "pickcat",
+      Self::Category(_) => "cat",
+      Self::BackToCategories => "back",
+      Self::Item(_) => "item",
+      Self::MoreImages { .. } => "img",
+      Self::Bid(_) => "bid",
+      Self::Favorite { .. } => "fav",
+      Self::Settings(_) => "settings",
+      Self::QuickBid(_) => "qbid",
+      Self::History(_) => "hist",
+      Self::SetOrderStatus { .. } => "order",
+      Self::Audit(_) => "audit",
+      Self::Archive(_) => "archive",
+      Self::Relist(_) => "relist",
+      Self::SecondChance(_) => "second",
+      Self::RetractBid(_) => "retract",
+      Self::MuteAlert(_) => "mute",
+      Self::Import(_) => "import",
+    }
+  }
+
+  pub fn button(&self, label: impl Into<String>) -> InlineKeyboardButton {
+    InlineKeyboardButton::callback(label, self.encode())
+  }
+}
+
+/// `all` selects every audit action; anything else must name one.
+fn audit_filter(filter: Option<AuditAction>) -> &'static str {
+  filter.map_or("all", AuditAction::as_str)
+}
+
+fn parse_audit_filter(value: &str) -> Option<Option<AuditAction>> {
+  match value {
+    "all" => Some(None),
+    other => AuditAction::parse(other).map(Some),
+  }
+}
+
+/// Answers a callback query, with a toast when `text` is set. Every route answers exactly once so the client stops
+/// showing its loading spinner.
+pub async fn answer(bot: &Bot, query: &CallbackQuery, text: Option<String>) -> HandlerResult {
+  let mut request = bot.answer_callback_query(query.id.clone());
+  if let Some(text) = text {
+    request = request.text(text);
+  }
+  metrics::telegram("answerCallbackQuery", request).await?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::AdminAction;
+  use super::CallbackData;
+  use super::CategoryPick;
+  use super::FavoriteAction;
+  use super::MAX_CALLBACK_DATA_LEN;
+  use super::MenuTarget;
+  use super::SettingsAction;
+  use crate::bot::archive::ArchiveAction;
+  use crate::bot::audit::AuditView;
+  use crate::bot::history::HistoryView;
+  use crate::bot::import::ImportAction;
+  use crate::bot::quick_bid::QuickBidAction;
+  use crate::bot::second_chance::SecondChanceAction;
+  use crate::i18n::Locale;
+  use crate::models::AuditAction;
+  use crate::models::OrderStatus;
+
+  fn samples() -> Vec<CallbackData> {
+    let mut samples: Vec<CallbackData> = MenuTarget::ALL.into_iter().map(CallbackData::Menu).collect();
+    samples.extend(AdminAction::ALL.into_iter().map(CallbackData::Admin));
+    samples.extend(
+      Locale::ALL
+        .into_iter()
+        .map(|locale| CallbackData::Settings(SettingsAction::SetLocale(Some(locale)))),
+    );
+    samples.extend([
+      CallbackData::PickCategory(CategoryPick::New),
+      CallbackData::PickCategory(CategoryPick::Existing(3)),
+      CallbackData::Category(3),
+      CallbackData::BackToCategories,
+      CallbackData::Item(42),
+      CallbackData::MoreImages { item_id: 5, offset: 10 },
+      CallbackData::Bid(42),
+      CallbackData::Favorite {
+        action: FavoriteAction::Add,
+        item_id: 42,
+      },
+      CallbackData::Favorite {
+        action: FavoriteAction::Remove,
+        item_id: 42,
+      },
+      CallbackData::Settings(SettingsAction::ToggleNotifications),
+      CallbackData::Settings(SettingsAction::ChooseLanguage),
+      CallbackData::Settings(SettingsAction::SetLocale(None)),
+      CallbackData::QuickBid(QuickBidAction::Ask {
+        item_id: 4,
+        amount: 6000,
+      }),
+      // The longest button in practice: large ids, a bid in the billions and a ten-digit Telegram user id.
+      CallbackData::QuickBid(QuickBidAction::Confirm {
+        item_id: 99_999_999,
+        amount: 999_999_999_999,
+        user_id: 9_999_999_999,
+      }),
+      CallbackData::QuickBid(QuickBidAction::Cancel { user_id: 99 }),
+      CallbackData::History(HistoryView::Open { item_id: 8 }),
+      CallbackData::History(HistoryView::Page { item_id: 8, page: 2 }),
+      CallbackData::SetOrderStatus {
+        order_id: 5,
+        status: OrderStatus::Shipped,
+      },
+      CallbackData::Audit(AuditView::Page { filter: None, page: 2 }),
+      CallbackData::Audit(AuditView::Page {
+        filter: Some(AuditAction::SecondChanceOffered),
+        page: 0,
+      }),
+      CallbackData::Audit(AuditView::Filters {
+        current: Some(AuditAction::Broadcast),
+      }),
+      CallbackData::Archive(ArchiveAction::RestoreItem { item_id: 4 }),
+      CallbackData::Archive(ArchiveAction::RestoreCategory { category_id: 2 }),
+      CallbackData::Archive(ArchiveAction::MoveItems { from: 2, to: 5 }),
+      CallbackData::Archive(ArchiveAction::ArchiveWithItems { category_id: 2 }),
+      CallbackData::Relist(7),
+      CallbackData::MuteAlert(u64::MAX),
+      CallbackData::SecondChance(SecondChanceAction::Offer { item_id: 7 }),
+      CallbackData::SecondChance(SecondChanceAction::Accept { offer_id: 3 }),
+      CallbackData::SecondChance(SecondChanceAction::Decline { offer_id: 3 }),
+      CallbackData::RetractBid(11),
+      CallbackData::Import(ImportAction::Confirm),
+      CallbackData::Import(ImportAction::Cancel),
+    ]);
+    samples
+  }
+
+  #[test]
+  fn round_trips_every_route() {
+    for data in samples() {
+      let encoded = data.encode();
+      assert!(encoded.len() <= MAX_CALLBACK_DATA_LEN, "{encoded} is too long");
+      assert!(encoded.starts_with(&format!("{}:", data.route())), "{encoded}");
+      assert_eq!(CallbackData::decode(&encoded), Some(data), "{encoded}");
+    }
+  }
+
+  #[test]
+  fn keeps_the_wire_format_of_existing_buttons() {
+    let cases = [
+      ("menu:root", CallbackData::Menu(MenuTarget::Root)),
+      ("pickcat:new", CallbackData::PickCategory(CategoryPick::New)),
+      ("fav:add:42", CallbackData::Favorite {
+        action: FavoriteAction::Add,
+        item_id: 42,
+      }),
+      ("img:5:10", CallbackData::MoreImages { item_id: 5, offset: 10 }),
+      (
+        "qbid:ok:4:6000:99",
+        CallbackData::QuickBid(QuickBidAction::Confirm {
+          item_id: 4,
+          amount: 6000,
+          user_id: 99,
+        }),
+      ),
+      (
+        "audit:page:item_closed:0",
+        CallbackData::Audit(AuditView::Page {
+          filter: Some(AuditAction::ItemClosed),
+          page: 0,
+        }),
+      ),
+    ];
+    for (wire, data) in cases {
+      assert_eq!(CallbackData::decode(wire), Some(data.clone()), "{wire}");
+      assert_eq!(data.encode(), wire);
+    }
+  }
+
+  #[test]
+  fn rejects_unknown_and_malformed_data() {
+    for wire in [
+      "",
+      "menu",
+      "menu:nowhere",
+      "item:abc",
+      "item:1:2",
+      "fav:toggle:1",
+      "img:5",
+      "qbid:ask:4",
+      "qbid:cancel:99:1",
+      "settings:locale:de",
+      "order:set:5:lost",
+      "audit:page:unknown:0",
+      "archive:move:2",
+      "second:accept:x",
+      "retract:drop:3",
+      "legacy:1",
+    ] {
+      assert_eq!(CallbackData::decode(wire), None, "{wire}");
+    }
+  }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Result;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::types::MessageId;
+use teloxide::types::ParseMode;
+use tracing::Instrument;
+use tracing::info;
+use tracing::info_span;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::channel;
+use crate::bot::context::AppContext;
+use crate::bot::group;
+use crate::bot::handlers::build_item_viewer_context;
+use crate::bot::handlers::item_action_keyboard;
+use crate::bot::handlers::render_item_message;
+
+/// Pause between two card edits so a busy lot does not hit Telegram's flood limits.
+const CARD_EDIT_INTERVAL: Duration = Duration::from_millis(50);
+/// Only the most recently opened cards of an item are kept live.
+const MAX_REFRESHED_CARDS: i64 = 50;
+/// Edits tried per card; a rate-limited card is sent again after the requested wait instead of being skipped.
+const MAX_CARD_ATTEMPTS: u32 = 3;
+/// A refresh task still looping after this long means the item is bid on faster than its views can be updated.
+const SLOW_REFRESH_ALERT_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// Tracks which items have a refresh in flight, so a burst of bids results in at most one running refresh plus one
+/// follow-up per item.
+#[derive(Clone, Default)]
+pub struct RefreshQueue {
+  pending: Arc<Mutex<HashMap<i64, bool>>>,
+}
+
+impl RefreshQueue {
+  /// Returns `true` when the caller should start a refresh task. Otherwise the running task is asked to go again.
+  fn begin(&self, item_id: i64) -> bool {
+    let mut pending = self.pending.lock().expect("refresh queue poisoned");
+    match pending.get_mut(&item_id) {
+      Some(rerun) => {
+        *rerun = true;
+        false
+      },
+      None => {
+        pending.insert(item_id, false);
+        true
+      },
+    }
+  }
+
+  /// Returns `true` when the running task may stop, `false` when another refresh was requested meanwhile.
+  fn finish(&self, item_id: i64) -> bool {
+    let mut pending = self.pending.lock().expect("refresh queue poisoned");
+    match pending.get_mut(&item_id) {
+      Some(rerun) if *rerun => {
+        *rerun = false;
+        false
+      },
+      _ => {
+        pending.remove(&item_id);
+        true
+      },
+    }
+  }
+
+  /// Number of items with a refresh in flight.
+  fn len(&self) -> usize {
+    self.pending.lock().expect("refresh queue poisoned").len()
+  }
+}
+
+/// Refreshes the channel post, group status messages and every open card of an item in the background.
+pub fn schedule_refresh(bot: &Bot, ctx: &Arc<AppContext>, item_id: i64) {
+  if !ctx.card_refreshes().begin(item_id) {
+    info!(item_id, "item refresh already running, coalesced");
+    return;
+  }
+
+  let bot = bot.clone();
+  let ctx = ctx.clone();
+  tokio::spawn(
+    async move {
+      let started = Instant::now();
+      let mut alerted = false;
+      loop {
+        refresh_item(&bot, &ctx, item_id).await;
+        if ctx.card_refreshes().finish(item_id) {
+          break;
+        }
+        if !alerted && started.elapsed() >= SLOW_REFRESH_ALERT_AFTER {
+          alerted = true;
+          let alert = format!(
+            "🐢 The refresh of item #{item_id} has been running for {} min. {} item refresh(es) in flight.",
+            started.elapsed().as_secs() / 60,
+            ctx.card_refreshes().len()
+          );
+          ctx.alerts().raise(&bot, "queue:item_refresh", alert).await;
+        }
+      }
+    }
+    .instrument(info_span!("item_refresh", item_id)),
+  );
+}
+
+/// Refreshes the channel post, group status messages and every open card of an item, logging what fails. Handlers
+/// go through [`schedule_refresh`]; this is for callers that have to wait for the result, like the console.
+pub async fn refresh_item(bot: &Bot, ctx: &Arc<AppContext>, item_id: i64) {
+  if let Err(err) = channel::refresh_item_post(bot, ctx, item_id).await {
+    warn!(error = %err, item_id, "failed to refresh channel post");
+  }
+  if let Err(err) = refresh_item_cards(bot, ctx, item_id).await {
+    warn!(error = %err, item_id, "failed to refresh item cards");
+  }
+  if let Err(err) = group::refresh_group_lots(bot, ctx, item_id).await {
+    warn!(error = %err, item_id, "failed to refresh group lots");
+  }
+}
+
+#[instrument(skip(bot, ctx))]
+async fn refresh_item_cards(bot: &Bot, ctx: &Arc<AppContext>, item_id: i64) -> Result<()> {
+  let Some(item) = ctx.db().get_item(item_id).await? else {
+    return Ok(());
+  };
+  let best = ctx.db().best_bid_for_item(item_id).await?;
+  let currency = ctx.item_currency(&item);
+  let cards = ctx.db().list_item_cards(item_id, MAX_REFRESHED_CARDS).await?;
+  info!(item_id, count = cards.len(), "refreshing item cards");
+
+  for card in cards {
+    let viewer = match card.viewer_tg_id {
+      Some(viewer_id) => Some(build_item_viewer_context(ctx, item_id, viewer_id).await?),
+      None => None,
+    };
+    let chat = ChatId(card.chat_id);
+    let text = render_item_message(&item, currency, best, viewer.as_ref());
+    let keyboard = item_action_keyboard(&item, currency, best, viewer.as_ref());
+    for attempt in 1 ..= MAX_CARD_ATTEMPTS {
+      let request = bot
+        .edit_message_text(chat, MessageId(card.message_id), text.clone())
+        .parse_mode(ParseMode::MarkdownV2)
+        .reply_markup(keyboard.clone());
+      match request.await {
+        Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => {},
+        Err(RequestError::Api(
+          ApiError::MessageToEditNotFound
+          | ApiError::MessageCantBeEdited
+          | ApiError::MessageIdInvalid
+          | ApiError::BotBlocked
+          | ApiError::ChatNotFound
+          | ApiError::UserDeactivated,
+        )) => {
+          info!(item_id, chat_id = %chat, message_id = card.message_id, "item card gone, forgetting it");
+          ctx.db().forget_item_card(card.chat_id, card.message_id).await?;
+        },
+        Err(RequestError::RetryAfter(wait)) if attempt < MAX_CARD_ATTEMPTS => {
+          warn!(item_id, retry_after = ?wait.duration(), attempt, "rate limited while refreshing cards, retrying");
+          tokio::time::sleep(wait.duration()).await;
+          continue;
+        },
+        Err(err) => {
+          warn!(error = %err, item_id, chat_id = %chat, message_id = card.message_id, "failed to refresh item card");
+        },
+      }
+      break;
+    }
+    tokio::time::sleep(CARD_EDIT_INTERVAL).await;
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::RefreshQueue;
+
+  #[test]
+  fn first_request_starts_a_refresh() {
+    let queue = RefreshQueue::default();
+    assert!(queue.begin(1));
+    assert!(queue.begin(2));
+  }
+
+  #[test]
+  fn requests_during_refresh_are_coalesced_into_one_rerun() {
+    let queue = RefreshQueue::default();
+    assert!(queue.begin(1));
+    assert!(!queue.begin(1));
+    assert!(!queue.begin(1));
+
+    assert!(!queue.finish(1));
+    assert!(queue.finish(1));
+    assert!(queue.begin(1));
+  }
+}
@@ -0,0 +1,161 @@
+use anyhow::Result;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::types::MessageId;
+use teloxide::types::ParseMode;
+use teloxide::utils::markdown;
+use tracing::info;
+use tracing::instrument;
+
+use crate::bot::context::AppContext;
+use crate::bot::handlers::send_item_images_chunk;
+use crate::models::ItemRow;
+use crate::util::Currency;
+
+/// Publishes a freshly created lot to the configured channel: the photos first, then a status card that is kept up
+/// to date by [`refresh_item_post`]. Scheduled lots are skipped until the scheduler publishes them, and lots that
+/// already have a post are skipped so that retries never post twice.
+#[instrument(skip(bot, ctx))]
+pub async fn publish_item(bot: &Bot, ctx: &AppContext, item_id: i64) -> Result<()> {
+  let Some(channel) = ctx.channel() else {
+    return Ok(());
+  };
+  let Some(item) = ctx.db().get_item(item_id).await?.filter(ItemRow::is_published) else {
+    return Ok(());
+  };
+  if ctx.db().channel_post_message_id(item.id, channel.0).await?.is_some() {
+    return Ok(());
+  }
+
+  let mut images = ctx.db().list_item_images(item.id).await?;
+  if images.is_empty()
+    && let Some(legacy_cover) = item.image_file_id.clone()
+  {
+    images.push(legacy_cover);
+  }
+
+  let mut offset = 0;
+  while offset < images.len() {
+    offset = send_item_images_chunk(bot, ctx, channel, &images, offset, None).await?;
+  }
+
+  let best = ctx.db().best_bid_for_item(item.id).await?;
+  let bid_count = ctx.db().count_bids_for_item(item.id).await?;
+  let message = bot
+    .send_message(
+      channel,
+      render_channel_post(&item, ctx.item_currency(&item), best, bid_count),
+    )
+    .parse_mode(ParseMode::MarkdownV2)
+    .await?;
+  ctx.db().record_channel_post(item.id, channel.0, message.id.0).await?;
+  info!(item_id, chat_id = %channel, message_id = %message.id, "published item to channel");
+  Ok(())
+}
+
+/// Re-renders the channel card of an item after a bid or a closure. Items that were never posted are skipped.
+#[instrument(skip(bot, ctx))]
+pub async fn refresh_item_post(bot: &Bot, ctx: &AppContext, item_id: i64) -> Result<()> {
+  let Some(channel) = ctx.channel() else {
+    return Ok(());
+  };
+  let Some(message_id) = ctx.db().channel_post_message_id(item_id, channel.0).await? else {
+    return Ok(());
+  };
+  let Some(item) = ctx.db().get_item(item_id).await? else {
+    return Ok(());
+  };
+
+  let best = ctx.db().best_bid_for_item(item.id).await?;
+  let bid_count = ctx.db().count_bids_for_item(item.id).await?;
+  let request = bot
+    .edit_message_text(
+      channel,
+      MessageId(message_id),
+      render_channel_post(&item, ctx.item_currency(&item), best, bid_count),
+    )
+    .parse_mode(ParseMode::MarkdownV2);
+  match request.await {
+    Ok(_) => info!(item_id, chat_id = %channel, message_id, "refreshed channel post"),
+    Err(RequestError::Api(ApiError::MessageNotModified)) => {
+      info!(item_id, chat_id = %channel, message_id, "channel post already current");
+    },
+    Err(err) => return Err(err.into()),
+  }
+  Ok(())
+}
+
+fn render_channel_post(item: &ItemRow, currency: Currency, best: Option<i64>, bid_count: i64) -> String {
+  let escaped_id = markdown::escape(&format!("#{}", item.id));
+  let escaped_title = markdown::escape(&item.title);
+
+  let mut text = format!("🔨 *{}* — *{}*", escaped_id, escaped_title);
+
+  if let Some(description) = item.description.as_deref()
+    && !description.trim().is_empty()
+  {
+    text.push_str(&format!("\n\n{}", markdown::escape(description)));
+  }
+
+  text.push_str(&format!(
+    "\n\n💰 Start: {}",
+    markdown::escape(&currency.format(item.start_price))
+  ));
+
+  match best {
+    Some(best_bid) => {
+      let label = if item.is_open { "Current best" } else { "Final price" };
+      text.push_str(&format!(
+        "\n🏆 {}: {}",
+        label,
+        markdown::escape(&currency.format(best_bid))
+      ));
+    },
+    None => text.push_str("\n🏆 No bids yet"),
+  }
+
+  text.push_str(&format!("\n🔢 Bids: {bid_count}"));
+  text.push_str(&format!(
+    "\n📦 Status: {}",
+    if item.is_open { "OPEN" } else { "CLOSED" }
+  ));
+  text
+}
+
+#[cfg(test)]
+mod tests {
+  use super::render_channel_post;
+  use crate::models::ItemRow;
+  use crate::util::Currency;
+
+  fn item(is_open: bool) -> ItemRow {
+    ItemRow {
+      is_open,
+      is_new: true,
+      ..ItemRow::fixture(7, "Vase", 5000)
+    }
+  }
+
+  #[test]
+  fn renders_open_post_with_bids() {
+    let text = render_channel_post(&item(true), Currency::AED, Some(7500), 3);
+    assert!(text.contains("Current best: AED 75\\.00"));
+    assert!(text.contains("Bids: 3"));
+    assert!(text.contains("Status: OPEN"));
+  }
+
+  #[test]
+  fn renders_closed_post_without_bids() {
+    let text = render_channel_post(&item(false), Currency::USD, None, 0);
+    assert!(text.contains("Start: $"));
+    assert!(text.contains("No bids yet"));
+    assert!(text.contains("Status: CLOSED"));
+  }
+
+  #[test]
+  fn renders_final_price_for_closed_post() {
+    let text = render_channel_post(&item(false), Currency::AED, Some(9000), 4);
+    assert!(text.contains("Final price: AED 90\\.00"));
+  }
+}
@@ -8,3 +8,12 @@ pub enum Command {
   /// Show the help text
   Help,
 }
+
+#[derive(BotCommands, Clone, Debug)]
+#[command(rename_rule = "lowercase", description = "Group auction commands:")]
+pub enum GroupCommand {
+  /// Post a lot into this group: /auction <item id>
+  Auction(String),
+  /// Show how group bidding works
+  Help,
+}
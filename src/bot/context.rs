@@ -1,18 +1,39 @@
 use std::collections::HashSet;
 
+use teloxide::types::ChatId;
+
+use crate::bot::alerts::Alerts;
+use crate::bot::cards::RefreshQueue;
+use crate::config::AuctionConfig;
+use crate::config::Config;
+use crate::config::NotificationConfig;
 use crate::db::Db;
+use crate::models::ItemRow;
+use crate::util::Currency;
 
 #[derive(Clone)]
 pub struct AppContext {
   db: Db,
   admins: HashSet<i64>,
+  channel: Option<ChatId>,
+  card_refreshes: RefreshQueue,
+  alerts: Alerts,
+  payment_provider_token: Option<String>,
+  auction: AuctionConfig,
+  notifications: NotificationConfig,
 }
 
 impl AppContext {
-  pub fn new(db: Db, admins: Vec<i64>) -> Self {
+  pub fn new(db: Db, config: &Config) -> Self {
     Self {
       db,
-      admins: admins.into_iter().collect(),
+      admins: config.telegram.admins.iter().copied().collect(),
+      channel: config.telegram.channel_id.map(ChatId),
+      card_refreshes: RefreshQueue::default(),
+      alerts: Alerts::new(config),
+      payment_provider_token: config.telegram.payment_provider_token.clone(),
+      auction: config.auction,
+      notifications: config.notifications,
     }
   }
 
@@ -23,4 +44,42 @@ impl AppContext {
   pub fn is_admin(&self, tg_id: i64) -> bool {
     self.admins.contains(&tg_id)
   }
+
+  pub fn admin_ids(&self) -> impl Iterator<Item = i64> + '_ {
+    self.admins.iter().copied()
+  }
+
+  pub fn channel(&self) -> Option<ChatId> {
+    self.channel
+  }
+
+  pub fn card_refreshes(&self) -> &RefreshQueue {
+    &self.card_refreshes
+  }
+
+  pub fn alerts(&self) -> &Alerts {
+    &self.alerts
+  }
+
+  pub fn auction(&self) -> &AuctionConfig {
+    &self.auction
+  }
+
+  pub fn notifications(&self) -> &NotificationConfig {
+    &self.notifications
+  }
+
+  pub fn payment_provider_token(&self) -> Option<&str> {
+    self.payment_provider_token.as_deref()
+  }
+
+  /// Resolves a stored currency code, falling back to the deployment currency for `None` or unknown codes. Items
+  /// and categories without their own code are priced in the deployment currency.
+  pub fn currency_for(&self, code: Option<&str>) -> Currency {
+    code.and_then(Currency::from_code).unwrap_or(self.auction.currency)
+  }
+
+  pub fn item_currency(&self, item: &ItemRow) -> Currency {
+    self.currency_for(item.currency.as_deref())
+  }
 }
@@ -0,0 +1,268 @@
+use std::fmt;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::error_handlers::ErrorHandler;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQueryId;
+use teloxide::types::UpdateKind;
+use thiserror::Error;
+use tracing::debug;
+use tracing::error;
+use tracing::warn;
+
+use crate::bot::context::AppContext;
+use crate::i18n::Locale;
+use crate::i18n::tr;
+use crate::metrics;
+
+/// Longest error text quoted in an admin alert. Telegram rejects messages over 4096 characters.
+const MAX_ALERT_ERROR_CHARS: usize = 3000;
+
+/// Why handling an update failed, sorted by what it means for the user and for the admins. Built from the
+/// `anyhow::Error` a handler returns by looking for the typed error underneath its context messages.
+#[derive(Debug, Error)]
+pub enum BotError {
+  /// A Telegram Bot API call failed.
+  #[error("telegram request failed: {0:#}")]
+  Telegram(anyhow::Error),
+  /// A database call failed.
+  #[error("database request failed: {0:#}")]
+  Database(anyhow::Error),
+  /// Anything else, usually a broken invariant.
+  #[error("{0:#}")]
+  Internal(anyhow::Error),
+}
+
+impl BotError {
+  /// Failures that are part of normal operation: the user blocked the bot, the message to edit is already gone or
+  /// shows the same content. They are logged but get neither a reply text nor an admin alert.
+  pub fn is_expected(&self) -> bool {
+    let Self::Telegram(err) = self else {
+      return false;
+    };
+    matches!(
+      err.chain().find_map(|cause| cause.downcast_ref::<RequestError>()),
+      Some(RequestError::Api(
+        ApiError::BotBlocked
+          | ApiError::UserDeactivated
+          | ApiError::BotKicked
+          | ApiError::BotKickedFromSupergroup
+          | ApiError::BotKickedFromChannel
+          | ApiError::CantInitiateConversation
+          | ApiError::MessageNotModified
+          | ApiError::MessageToEditNotFound
+          | ApiError::MessageToDeleteNotFound
+          | ApiError::InvalidQueryId
+      ))
+    )
+  }
+
+  /// A stable name for the variant, used in logs and alerts.
+  pub fn kind(&self) -> &'static str {
+    match self {
+      Self::Telegram(_) => "telegram",
+      Self::Database(_) => "database",
+      Self::Internal(_) => "internal",
+    }
+  }
+}
+
+impl From<anyhow::Error> for BotError {
+  fn from(err: anyhow::Error) -> Self {
+    let err = match err.downcast::<BotError>() {
+      Ok(err) => return err,
+      Err(err) => err,
+    };
+    if err.chain().any(|cause| cause.is::<RequestError>()) {
+      Self::Telegram(err)
+    } else if err.chain().any(|cause| cause.is::<sqlx::Error>()) {
+      Self::Database(err)
+    } else {
+      Self::Internal(err)
+    }
+  }
+}
+
+/// A failed update, with what the error handler needs to answer the user who sent it.
+#[derive(Debug, Error)]
+#[error("update {reference} ({route}) failed: {error}")]
+pub struct UpdateFailure {
+  /// Short id shown to the user and logged with the error, so a support request can be matched to the log line.
+  reference: String,
+  route: &'static str,
+  user_id: Option<i64>,
+  chat: Option<ChatId>,
+  callback: Option<CallbackQueryId>,
+  locale: Locale,
+  #[source]
+  error: BotError,
+}
+
+impl UpdateFailure {
+  pub fn new(update: &Update, route: &'static str, error: anyhow::Error) -> Self {
+    let user = update.from();
+    let callback = match &update.kind {
+      UpdateKind::CallbackQuery(query) => Some(query.id.clone()),
+      _ => None,
+    };
+    Self {
+      reference: reference(update.id.0),
+      route,
+      user_id: user.map(|user| user.id.0 as i64),
+      chat: update.chat().map(|chat| chat.id),
+      callback,
+      // The database may be what failed, so the stored language preference is not looked up.
+      locale: Locale::resolve(user.and_then(|user| user.language_code.as_deref())),
+      error: error.into(),
+    }
+  }
+}
+
+/// Derived from the update id, which Telegram never reuses for a bot, and logged as `update_id` in the update span.
+fn reference(update_id: u32) -> String {
+  format!("U{update_id:08X}")
+}
+
+/// Dispatcher error handler. Logs every failed update, tells the user something went wrong along with a reference
+/// id, and alerts the admins about failures that are not part of normal operation.
+pub struct ErrorReporter {
+  bot: Bot,
+  ctx: Arc<AppContext>,
+}
+
+impl ErrorReporter {
+  pub fn new(bot: Bot, ctx: Arc<AppContext>) -> Arc<Self> {
+    Arc::new(Self { bot, ctx })
+  }
+
+  async fn report(&self, failure: UpdateFailure) {
+    if failure.error.is_expected() {
+      warn!(
+        reference = %failure.reference,
+        route = failure.route,
+        user_id = failure.user_id,
+        error = %failure.error,
+        "update failed"
+      );
+      // Still stop the spinner of a failed button press, just without an alert.
+      if let Some(callback) = &failure.callback
+        && let Err(err) =
+          metrics::telegram("answerCallbackQuery", self.bot.answer_callback_query(callback.clone())).await
+      {
+        debug!(reference = %failure.reference, error = %err, "failed to answer callback of a failed update");
+      }
+      return;
+    }
+    error!(
+      reference = %failure.reference,
+      route = failure.route,
+      user_id = failure.user_id,
+      kind = failure.error.kind(),
+      error = %failure.error,
+      "update failed"
+    );
+    self.reply(&failure).await;
+    let key = format!("update:{}:{}", failure.route, failure.error.kind());
+    self.ctx.alerts().raise(&self.bot, &key, AlertText(&failure)).await;
+  }
+
+  async fn reply(&self, failure: &UpdateFailure) {
+    let text = tr!(failure.locale, "error-reply", reference = failure.reference.as_str());
+    let result = match (&failure.callback, failure.chat) {
+      (Some(callback), _) => {
+        let request = self
+          .bot
+          .answer_callback_query(callback.clone())
+          .text(text)
+          .show_alert(true);
+        metrics::telegram("answerCallbackQuery", request).await.map(drop)
+      },
+      (None, Some(chat)) => metrics::notification("error_reply", self.bot.send_message(chat, text))
+        .await
+        .map(drop),
+      (None, None) => Ok(()),
+    };
+    if let Err(err) = result {
+      debug!(reference = %failure.reference, error = %err, "failed to tell the user about a failed update");
+    }
+  }
+}
+
+impl ErrorHandler<anyhow::Error> for ErrorReporter {
+  fn handle_error(self: Arc<Self>, error: anyhow::Error) -> BoxFuture<'static, ()> {
+    Box::pin(async move {
+      match error.downcast::<UpdateFailure>() {
+        Ok(failure) => self.report(failure).await,
+        // Errors of the update listener carry no update to answer.
+        Err(err) => error!(error = %format!("{err:#}"), "dispatcher error"),
+      }
+    })
+  }
+}
+
+/// The alert for a failed update. Admin messages stay in English like the rest of the admin tooling.
+struct AlertText<'a>(&'a UpdateFailure);
+
+impl fmt::Display for AlertText<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let failure = self.0;
+    writeln!(f, "⚠️ Update {} failed", failure.reference)?;
+    writeln!(f, "Route: {}", failure.route)?;
+    if let Some(user_id) = failure.user_id {
+      writeln!(f, "User: {user_id}")?;
+    }
+    writeln!(f, "Kind: {}", failure.error.kind())?;
+    let error = failure.error.to_string();
+    match error.char_indices().nth(MAX_ALERT_ERROR_CHARS) {
+      Some((cut, _)) => write!(f, "\n{}…", &error[.. cut]),
+      None => write!(f, "\n{error}"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use anyhow::Context;
+  use teloxide::ApiError;
+  use teloxide::RequestError;
+
+  use super::BotError;
+  use super::reference;
+
+  #[test]
+  fn classifies_errors_below_context() {
+    let blocked = Err::<(), _>(RequestError::Api(ApiError::BotBlocked))
+      .context("failed to notify seller")
+      .unwrap_err();
+    let err = BotError::from(blocked);
+    assert!(matches!(err, BotError::Telegram(_)));
+    assert!(err.is_expected());
+    assert!(err.to_string().contains("failed to notify seller"));
+
+    let database = Err::<(), _>(sqlx::Error::PoolTimedOut)
+      .context("failed to load item")
+      .unwrap_err();
+    let err = BotError::from(database);
+    assert!(matches!(err, BotError::Database(_)));
+    assert!(!err.is_expected());
+
+    let internal = BotError::from(anyhow::anyhow!("message missing sender"));
+    assert!(matches!(internal, BotError::Internal(_)));
+    assert!(!internal.is_expected());
+  }
+
+  #[test]
+  fn keeps_an_already_classified_error() {
+    let err = anyhow::Error::new(BotError::Database(anyhow::anyhow!("pool closed")));
+    assert!(matches!(BotError::from(err), BotError::Database(_)));
+  }
+
+  #[test]
+  fn formats_references() {
+    assert_eq!(reference(0x1A2B), "U00001A2B");
+    assert_eq!(reference(u32::MAX), "UFFFFFFFF");
+  }
+}
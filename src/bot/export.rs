@@ -0,0 +1,334 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use chrono::Days;
+use chrono::NaiveDate;
+use chrono::Utc;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+use teloxide::dispatching::dialogue::Dialogue;
+use teloxide::prelude::*;
+use teloxide::types::InputFile;
+use tracing::info;
+use tracing::instrument;
+
+use crate::bot::DialogueStorage;
+use crate::bot::HandlerResult;
+use crate::bot::context::AppContext;
+use crate::bot::state::ConversationState;
+use crate::db::Db;
+use crate::models::ExportFilter;
+
+type SharedContext = Arc<AppContext>;
+type BotDialogue = Dialogue<ConversationState, DialogueStorage>;
+
+/// Which records an export contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Dataset {
+  /// Items with their final prices and winners.
+  Items,
+  /// Every bid, retracted ones included.
+  Bids,
+  /// Registered users.
+  Users,
+}
+
+impl Dataset {
+  const ALL: [Dataset; 3] = [Self::Items, Self::Bids, Self::Users];
+
+  fn as_str(self) -> &'static str {
+    match self {
+      Self::Items => "items",
+      Self::Bids => "bids",
+      Self::Users => "users",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+  Csv,
+  Json,
+}
+
+impl ExportFormat {
+  fn extension(self) -> &'static str {
+    match self {
+      Self::Csv => "csv",
+      Self::Json => "json",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportRequest {
+  pub dataset: Dataset,
+  pub format: ExportFormat,
+  pub filter: ExportFilter,
+}
+
+pub struct ExportFile {
+  pub file_name: String,
+  pub rows: usize,
+  pub contents: Vec<u8>,
+}
+
+/// Builds the filter for records created between `from` and `to`, both days included and read as UTC.
+pub fn date_filter(from: Option<NaiveDate>, to: Option<NaiveDate>, category_id: Option<i64>) -> Result<ExportFilter> {
+  if let (Some(from), Some(to)) = (from, to)
+    && from > to
+  {
+    bail!("the range starts on {from}, after it ends on {to}");
+  }
+  Ok(ExportFilter {
+    since: from.map(|day| day.and_time(Default::default()).and_utc()),
+    until: to
+      .and_then(|day| day.checked_add_days(Days::new(1)))
+      .map(|day| day.and_time(Default::default()).and_utc()),
+    category_id,
+  })
+}
+
+/// Loads the requested records and encodes them. Amounts stay in minor units, like in the database.
+#[instrument(skip(db))]
+pub async fn build(db: &Db, request: &ExportRequest) -> Result<ExportFile> {
+  let filter = &request.filter;
+  let (rows, contents) = match request.dataset {
+    Dataset::Items => encode(&db.export_item_results(filter).await?, request.format)?,
+    Dataset::Bids => encode(&db.export_bid_ledger(filter).await?, request.format)?,
+    Dataset::Users => encode(&db.export_users(filter).await?, request.format)?,
+  };
+  Ok(ExportFile {
+    file_name: format!(
+      "{}-{}.{}",
+      request.dataset.as_str(),
+      Utc::now().format("%Y%m%d-%H%M"),
+      request.format.extension()
+    ),
+    rows,
+    contents,
+  })
+}
+
+fn encode<T: Serialize>(rows: &[T], format: ExportFormat) -> Result<(usize, Vec<u8>)> {
+  let contents = match format {
+    ExportFormat::Json => serde_json::to_vec_pretty(rows)?,
+    ExportFormat::Csv => to_csv(rows)?,
+  };
+  Ok((rows.len(), contents))
+}
+
+/// Writes rows as CSV with one column per serialized field, in declaration order. Rows go through `serde_json` so
+/// flattened structs and enums come out the same as in the JSON export.
+fn to_csv<T: Serialize>(rows: &[T]) -> Result<Vec<u8>> {
+  let mut writer = csv::Writer::from_writer(Vec::new());
+  for (index, row) in rows.iter().enumerate() {
+    let Value::Object(fields) = serde_json::to_value(row)? else {
+      bail!("export rows must serialize to objects");
+    };
+    if index == 0 {
+      writer.write_record(fields.keys())?;
+    }
+    writer.write_record(fields.values().map(csv_cell))?;
+  }
+  writer.into_inner().context("failed to finish CSV export")
+}
+
+fn csv_cell(value: &Value) -> String {
+  match value {
+    Value::Null => String::new(),
+    Value::String(text) => text.clone(),
+    other => other.to_string(),
+  }
+}
+
+/// Parses an export request typed in chat, like `bids json 2025-11-01..2025-11-30 #3`. Dates may be left open on
+/// either side and the format defaults to CSV.
+fn parse_request(text: &str) -> Result<ExportRequest, String> {
+  let mut dataset = None;
+  let mut format = ExportFormat::Csv;
+  let (mut from, mut to, mut category_id) = (None, None, None);
+  for token in text.split_whitespace() {
+    let lower = token.to_lowercase();
+    if let Some(value) = Dataset::ALL.into_iter().find(|value| value.as_str() == lower) {
+      dataset = Some(value);
+    } else if let Ok(value) = ExportFormat::from_str(&lower, true) {
+      format = value;
+    } else if let Some((start, end)) = token.split_once("..") {
+      from = parse_day(start)?;
+      to = parse_day(end)?;
+    } else if let Some(id) = token.strip_prefix('#') {
+      category_id = Some(id.parse().map_err(|_| format!("\"{token}\" is not a category id"))?);
+    } else {
+      return Err(format!("unknown option \"{token}\""));
+    }
+  }
+  let dataset = dataset.ok_or("say what to export: items, bids or users")?;
+  let filter = date_filter(from, to, category_id).map_err(|err| err.to_string())?;
+  Ok(ExportRequest {
+    dataset,
+    format,
+    filter,
+  })
+}
+
+fn parse_day(value: &str) -> Result<Option<NaiveDate>, String> {
+  if value.is_empty() {
+    return Ok(None);
+  }
+  NaiveDate::parse_from_str(value, "%Y-%m-%d")
+    .map(Some)
+    .map_err(|_| format!("\"{value}\" is not a date like 2025-11-30"))
+}
+
+/// Asks the admin what to export, listing the category ids the filter takes.
+pub async fn send_prompt(bot: &Bot, ctx: &AppContext, chat: ChatId) -> HandlerResult {
+  let categories = ctx.db().list_categories().await?;
+  let mut text = "📤 What should be exported? Send the data set and, optionally, the format, a date range and a \
+                  category:\n\nitems csv 2025-11-01..2025-11-30 #3\n\nData sets: items, bids, users. Formats: csv \
+                  (default), json. Either end of the range may be left open. Amounts are in minor units. Type \
+                  cancel to stop."
+    .to_string();
+  if !categories.is_empty() {
+    text.push_str("\n\nCategories:");
+    for category in categories {
+      text.push_str(&format!("\n#{} {}", category.id, category.name));
+    }
+  }
+  bot.send_message(chat, text).await?;
+  Ok(())
+}
+
+#[instrument(skip(bot, ctx, dialogue, msg))]
+pub async fn handle_export_message(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  msg: Message,
+  admin_tg_id: i64,
+) -> HandlerResult {
+  let user = msg.from.as_ref().context("message missing sender")?;
+  if user.id.0 as i64 != admin_tg_id {
+    bot
+      .send_message(msg.chat.id, "Only the admin who started this action can respond.")
+      .await?;
+    return Ok(());
+  }
+
+  let Some(raw_text) = msg.text().map(str::trim).filter(|t| !t.is_empty()) else {
+    bot
+      .send_message(msg.chat.id, "📤 Send what to export, e.g. items csv, or type cancel.")
+      .await?;
+    return Ok(());
+  };
+  if raw_text.eq_ignore_ascii_case("cancel") {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, "❌ Export cancelled.").await?;
+    return Ok(());
+  }
+
+  let request = match parse_request(raw_text) {
+    Ok(request) => request,
+    Err(err) => {
+      bot.send_message(msg.chat.id, format!("⚠️ {err}")).await?;
+      return Ok(());
+    },
+  };
+  dialogue.reset().await?;
+  let export = build(ctx.db(), &request).await?;
+  info!(admin_tg_id, ?request, rows = export.rows, "exported data");
+  if export.rows == 0 {
+    bot.send_message(msg.chat.id, "📭 Nothing matches that filter.").await?;
+    return Ok(());
+  }
+  send(&bot, msg.chat.id, export).await
+}
+
+/// Sends an export as a document.
+pub async fn send(bot: &Bot, chat: ChatId, export: ExportFile) -> HandlerResult {
+  let caption = format!("📤 {} row(s)", export.rows);
+  bot
+    .send_document(chat, InputFile::memory(export.contents).file_name(export.file_name))
+    .caption(caption)
+    .await?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::NaiveDate;
+  use chrono::TimeZone;
+  use chrono::Utc;
+  use serde::Serialize;
+
+  use super::Dataset;
+  use super::ExportFormat;
+  use super::parse_request;
+  use super::to_csv;
+
+  #[test]
+  fn parses_requests() {
+    let request = parse_request("Bids json 2025-11-01..2025-11-30 #3").unwrap();
+    assert_eq!(request.dataset, Dataset::Bids);
+    assert_eq!(request.format, ExportFormat::Json);
+    assert_eq!(
+      request.filter.since,
+      Some(Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap())
+    );
+    assert_eq!(
+      request.filter.until,
+      Some(Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap())
+    );
+    assert_eq!(request.filter.category_id, Some(3));
+
+    let request = parse_request("users 2025-11-01..").unwrap();
+    assert_eq!(request.format, ExportFormat::Csv);
+    assert!(request.filter.until.is_none());
+    assert_eq!(
+      request.filter.since.map(|since| since.date_naive()),
+      NaiveDate::from_ymd_opt(2025, 11, 1)
+    );
+  }
+
+  #[test]
+  fn rejects_bad_requests() {
+    assert!(parse_request("csv").unwrap_err().contains("items, bids or users"));
+    assert!(parse_request("items 2025-13-01..").unwrap_err().contains("2025-13-01"));
+    assert!(parse_request("items 2025-11-30..2025-11-01").is_err());
+    assert!(parse_request("items everything").unwrap_err().contains("everything"));
+  }
+
+  #[test]
+  fn writes_flattened_rows_as_csv() {
+    #[derive(Serialize)]
+    struct Inner {
+      sku: i64,
+      note: Option<String>,
+    }
+    #[derive(Serialize)]
+    struct Row {
+      #[serde(flatten)]
+      inner: Inner,
+      title: String,
+    }
+    let rows = [
+      Row {
+        inner: Inner { sku: 1, note: None },
+        title: "Clock, brass".to_string(),
+      },
+      Row {
+        inner: Inner {
+          sku: 2,
+          note: Some("ok".to_string()),
+        },
+        title: "Lamp".to_string(),
+      },
+    ];
+    let csv = String::from_utf8(to_csv(&rows).unwrap()).unwrap();
+    // Columns follow the declaration order, not the alphabetical one.
+    assert_eq!(csv, "sku,note,title\n1,,\"Clock, brass\"\n2,ok,Lamp\n");
+  }
+}
@@ -0,0 +1,337 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::dispatching::UpdateHandler;
+use teloxide::dptree;
+use teloxide::prelude::*;
+use teloxide::types::InlineKeyboardMarkup;
+use teloxide::types::Message;
+use teloxide::types::MessageId;
+use teloxide::types::ParseMode;
+use teloxide::types::ReplyParameters;
+use teloxide::utils::command::BotCommands;
+use teloxide::utils::markdown;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::GroupCommand;
+use crate::bot::HandlerResult;
+use crate::bot::context::AppContext;
+use crate::bot::handlers::BidError;
+use crate::bot::handlers::PlacedBid;
+use crate::bot::handlers::bid_currency;
+use crate::bot::handlers::ensure_user_record;
+use crate::bot::handlers::place_validated_bid;
+use crate::bot::handlers::record_bid_outcome;
+use crate::bot::handlers::send_item_images_chunk;
+use crate::bot::handlers::user_locale;
+use crate::bot::quick_bid;
+use crate::bot::quick_bid::quick_bid_row;
+use crate::i18n::Locale;
+use crate::i18n::tr;
+use crate::models::ItemRow;
+use crate::util::Currency;
+use crate::util::MoneyError;
+
+type SharedContext = Arc<AppContext>;
+
+/// Handles every message posted in a group or supergroup. Group chats never enter the private dialogue flows, so
+/// several members can bid on the same lot at once. Quick-bid taps arrive as callback queries and are handled with
+/// the private ones.
+pub fn group_branch() -> UpdateHandler<anyhow::Error> {
+  Update::filter_message()
+    .filter(|msg: Message| msg.chat.is_group() || msg.chat.is_supergroup())
+    .branch(
+      dptree::entry()
+        .filter_command::<GroupCommand>()
+        .branch(dptree::case![GroupCommand::Auction(arg)].endpoint(handle_auction_command))
+        .branch(dptree::case![GroupCommand::Help].endpoint(handle_group_help)),
+    )
+    .branch(dptree::endpoint(handle_group_message))
+}
+
+#[instrument(skip(bot, msg))]
+async fn handle_group_help(bot: Bot, msg: Message) -> HandlerResult {
+  let mut text = GroupCommand::descriptions().to_string();
+  text.push_str(
+    "\n\nTo bid, reply to a lot's status message with your amount, e.g. 125.50, or tap one of its quick-bid buttons.",
+  );
+  bot.send_message(msg.chat.id, text).await?;
+  Ok(())
+}
+
+#[instrument(skip(bot, ctx, msg))]
+async fn handle_auction_command(bot: Bot, ctx: SharedContext, msg: Message, arg: String) -> HandlerResult {
+  let user = msg.from.as_ref().context("message missing sender")?;
+  let user_id = user.id.0 as i64;
+  let chat_id = msg.chat.id;
+  info!(user_id, chat_id = %chat_id, "received /auction command");
+
+  if !ctx.is_admin(user_id) {
+    reply(&bot, &msg, "🛡️ Only auction admins can post lots.").await?;
+    return Ok(());
+  }
+
+  let member = bot.get_chat_member(chat_id, user.id).await?;
+  if !member.is_privileged() {
+    reply(&bot, &msg, "🛡️ Only group admins can post lots here.").await?;
+    return Ok(());
+  }
+
+  let Ok(item_id) = arg.trim().trim_start_matches('#').parse::<i64>() else {
+    reply(&bot, &msg, "Usage: /auction <item id>").await?;
+    return Ok(());
+  };
+
+  let Some(item) = ctx
+    .db()
+    .get_item(item_id)
+    .await?
+    .filter(|item| !item.is_archived() && item.is_published())
+  else {
+    reply(&bot, &msg, "❓ Item not found.").await?;
+    return Ok(());
+  };
+  if !item.is_open {
+    reply(&bot, &msg, &format!("🔒 Item #{item_id} is already closed.")).await?;
+    return Ok(());
+  }
+
+  let mut images = ctx.db().list_item_images(item.id).await?;
+  if images.is_empty()
+    && let Some(legacy_cover) = item.image_file_id.clone()
+  {
+    images.push(legacy_cover);
+  }
+  let mut offset = 0;
+  while offset < images.len() {
+    offset = send_item_images_chunk(&bot, &ctx, chat_id, &images, offset, None).await?;
+  }
+
+  let (text, keyboard) = render_lot_status(&ctx, &item).await?;
+  let status = bot
+    .send_message(chat_id, text)
+    .parse_mode(ParseMode::MarkdownV2)
+    .reply_markup(keyboard)
+    .await?;
+  ctx
+    .db()
+    .record_group_lot(chat_id.0, status.id.0, item.id, user_id)
+    .await?;
+
+  if let Err(err) = bot
+    .pin_chat_message(chat_id, status.id)
+    .disable_notification(true)
+    .await
+  {
+    warn!(error = %err, item_id, chat_id = %chat_id, "failed to pin group lot status");
+  }
+  info!(user_id, item_id, chat_id = %chat_id, message_id = %status.id, "posted lot into group");
+  Ok(())
+}
+
+/// Treats a reply to a lot's status message that starts with a digit as a bid. Everything else is group chatter and
+/// is left alone.
+#[instrument(skip(bot, ctx, msg))]
+async fn handle_group_message(bot: Bot, ctx: SharedContext, msg: Message) -> HandlerResult {
+  let Some(target) = msg.reply_to_message() else {
+    return Ok(());
+  };
+  let Some(amount_text) = msg.text().map(str::trim).filter(|text| !text.is_empty()) else {
+    return Ok(());
+  };
+  let Some(item_id) = ctx.db().find_group_lot_item(msg.chat.id.0, target.id.0).await? else {
+    return Ok(());
+  };
+  let currency = bid_currency(&ctx, item_id).await?;
+  let parsed = currency.parse(amount_text);
+  // Ordinary replies to a lot are chatter, not malformed bids.
+  if matches!(parsed, Err(MoneyError::InvalidFormat)) && !amount_text.starts_with(|c: char| c.is_ascii_digit()) {
+    return Ok(());
+  }
+
+  let user = msg.from.as_ref().context("message missing sender")?;
+  ensure_user_record(&ctx, user).await?;
+  let bidder_id = user.id.0 as i64;
+  info!(bidder_id, chat_id = %msg.chat.id, item_id, "handling group bid");
+
+  let result = match parsed {
+    Ok(amount_cents) => {
+      if let Some((item, reference)) = quick_bid::requires_confirmation(&ctx, item_id, amount_cents).await? {
+        quick_bid::send_confirmation_prompt(&bot, msg.chat.id, msg.id, user, quick_bid::BidPrompt {
+          item: &item,
+          currency,
+          amount: amount_cents,
+          reference: Some(reference),
+          locale: user_locale(&ctx, bidder_id).await?,
+        })
+        .await?;
+        info!(bidder_id, item_id, amount_cents, "asked to confirm high group bid");
+        return Ok(());
+      }
+      place_validated_bid(&bot, &ctx, item_id, user, amount_cents).await
+    },
+    Err(err) => Err(err.into()),
+  };
+  record_bid_outcome(&result);
+  match result {
+    Ok(placed) => {
+      reply(&bot, &msg, &group_bid_text(&user.full_name(), item_id, &placed)).await?;
+    },
+    Err(BidError::Internal(err)) => return Err(err.into()),
+    Err(other) => {
+      // Everyone in the group sees the reply, so it stays in the default locale like the lot itself.
+      reply(&bot, &msg, &other.user_message(Locale::default())).await?;
+    },
+  }
+  Ok(())
+}
+
+/// Announces a bid placed in a group. Everyone in the group sees it, so it stays in the default locale.
+pub(super) fn group_bid_text(bidder: &str, item_id: i64, placed: &PlacedBid) -> String {
+  let locale = Locale::default();
+  let mut text = tr!(
+    locale,
+    "bid-placed-group",
+    name = bidder,
+    amount = placed.currency.format(placed.amount_cents),
+    item_id = item_id
+  );
+  if placed.is_highest {
+    text.push_str(&format!(" {}", tr!(locale, "bid-new-leader")));
+  }
+  text
+}
+
+/// Re-renders every group status message of an item. Called from the shared item refresh pipeline.
+#[instrument(skip(bot, ctx))]
+pub async fn refresh_group_lots(bot: &Bot, ctx: &AppContext, item_id: i64) -> Result<()> {
+  let lots = ctx.db().list_group_lots(item_id).await?;
+  if lots.is_empty() {
+    return Ok(());
+  }
+  let Some(item) = ctx.db().get_item(item_id).await? else {
+    return Ok(());
+  };
+
+  let (text, keyboard) = render_lot_status(ctx, &item).await?;
+  for (chat_id, message_id) in lots {
+    let request = bot
+      .edit_message_text(ChatId(chat_id), MessageId(message_id), text.clone())
+      .parse_mode(ParseMode::MarkdownV2)
+      .reply_markup(keyboard.clone());
+    match request.await {
+      Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => {},
+      Err(err) => warn!(error = %err, item_id, chat_id, message_id, "failed to refresh group lot status"),
+    }
+  }
+  Ok(())
+}
+
+async fn render_lot_status(ctx: &AppContext, item: &ItemRow) -> Result<(String, InlineKeyboardMarkup)> {
+  let best = ctx.db().best_bid_with_bidder(item.id).await?;
+  let bid_count = ctx.db().count_bids_for_item(item.id).await?;
+  let leader = match best {
+    Some((bidder_id, amount)) => {
+      let name = match ctx.db().get_user(bidder_id).await? {
+        Some(user) => user.display_name(),
+        None => format!("user {bidder_id}"),
+      };
+      Some((name, amount))
+    },
+    None => None,
+  };
+  let currency = ctx.item_currency(item);
+  let text = render_group_lot(
+    item,
+    currency,
+    leader.as_ref().map(|(name, amount)| (name.as_str(), *amount)),
+    bid_count,
+  );
+  let keyboard = if item.is_open {
+    InlineKeyboardMarkup::new(vec![quick_bid_row(item, currency, best.map(|(_, amount)| amount))])
+  } else {
+    InlineKeyboardMarkup::default()
+  };
+  Ok((text, keyboard))
+}
+
+fn render_group_lot(item: &ItemRow, currency: Currency, leader: Option<(&str, i64)>, bid_count: i64) -> String {
+  let escaped_id = markdown::escape(&format!("#{}", item.id));
+  let escaped_title = markdown::escape(&item.title);
+
+  let mut text = format!("🔨 *{}* — *{}*", escaped_id, escaped_title);
+
+  if let Some(description) = item.description.as_deref()
+    && !description.trim().is_empty()
+  {
+    text.push_str(&format!("\n\n{}", markdown::escape(description)));
+  }
+
+  text.push_str(&format!(
+    "\n\n💰 Start: {}",
+    markdown::escape(&currency.format(item.start_price))
+  ));
+
+  match leader {
+    Some((name, amount)) => text.push_str(&format!(
+      "\n🏆 {}: {} by {}",
+      if item.is_open { "Current best" } else { "Winning bid" },
+      markdown::escape(&currency.format(amount)),
+      markdown::escape(name)
+    )),
+    None => text.push_str("\n🏆 No bids yet"),
+  }
+
+  text.push_str(&format!("\n🔢 Bids: {bid_count}"));
+  if item.is_open {
+    text.push_str("\n📦 Status: OPEN");
+    text.push_str(&markdown::escape(
+      "\n\n↩️ Reply to this message with your bid, e.g. 125.50, or tap a quick bid below.",
+    ));
+  } else {
+    text.push_str("\n📦 Status: CLOSED");
+  }
+  text
+}
+
+async fn reply(bot: &Bot, msg: &Message, text: &str) -> Result<()> {
+  bot
+    .send_message(msg.chat.id, text)
+    .reply_parameters(ReplyParameters::new(msg.id))
+    .await?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::render_group_lot;
+  use crate::models::ItemRow;
+  use crate::util::Currency;
+
+  fn item(is_open: bool) -> ItemRow {
+    ItemRow {
+      is_open,
+      ..ItemRow::fixture(3, "Lamp", 1000)
+    }
+  }
+
+  #[test]
+  fn open_lot_invites_replies() {
+    let text = render_group_lot(&item(true), Currency::AED, Some(("@alice", 2500)), 2);
+    assert!(text.contains("Current best: AED 25\\.00 by @alice"));
+    assert!(text.contains("Reply to this message"));
+  }
+
+  #[test]
+  fn closed_lot_shows_winner_and_stops_bidding() {
+    let text = render_group_lot(&item(false), Currency::AED, Some(("@alice", 2500)), 2);
+    assert!(text.contains("Winning bid"));
+    assert!(text.contains("Status: CLOSED"));
+    assert!(!text.contains("Reply to this message"));
+  }
+}
@@ -1,14 +1,20 @@
 use std::collections::HashSet;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
 use anyhow::Context;
 use anyhow::Result;
-use sqlx::Error as SqlxError;
+use chrono::Utc;
+use serde_json::json;
 use teloxide::ApiError;
 use teloxide::RequestError;
+use teloxide::dispatching::DpHandlerDescription;
 use teloxide::dispatching::UpdateHandler;
 use teloxide::dispatching::dialogue::Dialogue;
 use teloxide::dptree;
+use teloxide::dptree::HandlerDescription;
+use teloxide::dptree::HandlerSignature;
+use teloxide::dptree::di::DependencyMap;
 use teloxide::prelude::*;
 use teloxide::types::CallbackQuery;
 use teloxide::types::ChatId;
@@ -22,34 +28,68 @@ use teloxide::types::Message;
 use teloxide::types::MessageEntity;
 use teloxide::types::MessageId;
 use teloxide::types::ParseMode;
+use teloxide::types::UpdateKind;
 use teloxide::types::User;
 use teloxide::utils::command::BotCommands;
 use teloxide::utils::markdown;
 use thiserror::Error;
+use tracing::Instrument;
 use tracing::info;
+use tracing::info_span;
 use tracing::instrument;
 use tracing::warn;
 
 use crate::bot::Command;
 use crate::bot::DialogueStorage;
 use crate::bot::HandlerResult;
+use crate::bot::alerts;
+use crate::bot::archive;
+use crate::bot::audit;
+use crate::bot::callback;
+use crate::bot::callback::AdminAction;
+use crate::bot::callback::CallbackData;
+use crate::bot::callback::CategoryPick;
+use crate::bot::callback::FavoriteAction;
+use crate::bot::callback::MenuTarget;
+use crate::bot::callback::SettingsAction;
+use crate::bot::cards;
+use crate::bot::channel;
 use crate::bot::context::AppContext;
+use crate::bot::errors::BotError;
+use crate::bot::errors::UpdateFailure;
+use crate::bot::export;
+use crate::bot::group;
+use crate::bot::history;
+use crate::bot::history::HistoryView;
+use crate::bot::import;
+use crate::bot::orders;
+use crate::bot::payments;
+use crate::bot::quick_bid;
+use crate::bot::relist;
+use crate::bot::retraction;
+use crate::bot::scheduler;
+use crate::bot::second_chance;
 use crate::bot::state::AddItemDraft;
 use crate::bot::state::BidDraft;
 use crate::bot::state::ConversationState;
 use crate::bot::state::DraftStage;
+use crate::i18n::Locale;
+use crate::i18n::tr;
+use crate::metrics;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
 use crate::models::CategoryRow;
 use crate::models::ItemRow;
+use crate::models::ItemSchedule;
+use crate::models::NewItem;
+use crate::models::NewItemCategory;
+use crate::models::UserRow;
+use crate::util::Currency;
 use crate::util::MoneyError;
-use crate::util::format_cents;
-use crate::util::parse_money_to_cents;
 
 type SharedContext = Arc<AppContext>;
 type BotDialogue = Dialogue<ConversationState, DialogueStorage>;
 
-const MAIN_MENU_TEXT: &str = "🤖 What would you like to do?";
-const MEDIA_GROUP_BATCH: usize = 10;
-
 pub fn build_schema() -> UpdateHandler<anyhow::Error> {
   let message_handler = Update::filter_message()
     .enter_dialogue::<Message, DialogueStorage, ConversationState>()
@@ -61,13 +101,108 @@ pub fn build_schema() -> UpdateHandler<anyhow::Error> {
     .branch(dptree::case![ConversationState::RemoveItem { admin_tg_id }].endpoint(handle_remove_item_message))
     .branch(dptree::case![ConversationState::RemoveCategory { admin_tg_id }].endpoint(handle_remove_category_message))
     .branch(dptree::case![ConversationState::Broadcast { admin_tg_id }].endpoint(handle_broadcast_message))
+    .branch(dptree::case![ConversationState::Export { admin_tg_id }].endpoint(export::handle_export_message))
+    .branch(
+      dptree::case![ConversationState::CreateEvent { admin_tg_id }].endpoint(scheduler::handle_create_event_message),
+    )
+    .branch(dptree::case![ConversationState::Import { admin_tg_id, lots }].endpoint(import::handle_import_message))
+    .branch(
+      dptree::case![ConversationState::RetractBid { admin_tg_id, bid_id }]
+        .endpoint(retraction::handle_retract_bid_message),
+    )
+    .branch(dptree::case![ConversationState::Relist { admin_tg_id, item_id }].endpoint(relist::handle_relist_message))
     .branch(dptree::endpoint(handle_idle_text));
 
   let callback_handler = Update::filter_callback_query()
     .enter_dialogue::<CallbackQuery, DialogueStorage, ConversationState>()
-    .endpoint(handle_callback_query);
+    .branch(
+      dptree::filter_map_async(decode_callback)
+        .branch(dptree::case![CallbackData::Menu(target)].endpoint(handle_menu_callback))
+        .branch(dptree::case![CallbackData::Admin(action)].endpoint(handle_admin_callback))
+        .branch(dptree::case![CallbackData::PickCategory(pick)].endpoint(handle_pick_category_callback))
+        .branch(dptree::case![CallbackData::Category(category_id)].endpoint(handle_category_callback))
+        .branch(dptree::case![CallbackData::BackToCategories].endpoint(handle_back_callback))
+        .branch(dptree::case![CallbackData::Item(item_id)].endpoint(handle_item_callback))
+        .branch(dptree::case![CallbackData::MoreImages { item_id, offset }].endpoint(handle_more_images_callback))
+        .branch(dptree::case![CallbackData::Bid(item_id)].endpoint(handle_bid_callback))
+        .branch(dptree::case![CallbackData::Favorite { action, item_id }].endpoint(handle_favorite_callback))
+        .branch(dptree::case![CallbackData::Settings(action)].endpoint(handle_settings_callback))
+        .branch(dptree::case![CallbackData::QuickBid(action)].endpoint(quick_bid::handle_callback))
+        .branch(dptree::case![CallbackData::History(view)].endpoint(history::handle_callback))
+        .branch(dptree::case![CallbackData::SetOrderStatus { order_id, status }].endpoint(orders::handle_callback))
+        .branch(dptree::case![CallbackData::Audit(view)].endpoint(audit::handle_callback))
+        .branch(dptree::case![CallbackData::Archive(action)].endpoint(archive::handle_callback))
+        .branch(dptree::case![CallbackData::Relist(item_id)].endpoint(relist::handle_callback))
+        .branch(dptree::case![CallbackData::SecondChance(action)].endpoint(second_chance::handle_callback))
+        .branch(dptree::case![CallbackData::RetractBid(bid_id)].endpoint(retraction::handle_callback))
+        .branch(dptree::case![CallbackData::MuteAlert(hash)].endpoint(alerts::handle_callback))
+        .branch(dptree::case![CallbackData::Import(action)].endpoint(import::handle_callback)),
+    )
+    .branch(dptree::endpoint(handle_unknown_callback));
 
-  dptree::entry().branch(message_handler).branch(callback_handler)
+  traced_entry()
+    .branch(group::group_branch())
+    .branch(payments::payment_branch())
+    .branch(message_handler)
+    .branch(callback_handler)
+}
+
+/// Counts every update and runs the rest of the schema inside an `update` span, so the handler and `Db` spans of one
+/// update, and the trace exported for it, hang off a single root. Handler errors leave as an [`UpdateFailure`], which
+/// tells the dispatcher error handler whom to answer.
+fn traced_entry() -> UpdateHandler<anyhow::Error> {
+  dptree::from_fn_with_description(
+    DpHandlerDescription::entry(),
+    |deps: DependencyMap, cont| async move {
+      let Some(update) = deps.try_get::<Update>() else {
+        return cont(deps).await;
+      };
+      let (kind, route) = update_route(&update);
+      metrics::record_update(kind, route);
+      let span = info_span!(
+        "update",
+        update_id = update.id.0,
+        user_id = update.from().map(|user| user.id.0),
+        kind,
+        route,
+      );
+      match cont(deps).instrument(span).await {
+        ControlFlow::Break(Err(err)) => ControlFlow::Break(Err(UpdateFailure::new(&update, route, err).into())),
+        flow => flow,
+      }
+    },
+    HandlerSignature::Entry,
+  )
+}
+
+/// Names an update's kind and route for metrics: callbacks by their decoded route, messages by what they carry.
+fn update_route(update: &Update) -> (&'static str, &'static str) {
+  match &update.kind {
+    UpdateKind::Message(message) => {
+      let route = if message.successful_payment().is_some() {
+        "payment"
+      } else if message.text().is_some_and(|text| text.starts_with('/')) {
+        "command"
+      } else if message.text().is_some() {
+        "text"
+      } else if message.photo().is_some() {
+        "photo"
+      } else {
+        "other"
+      };
+      ("message", route)
+    },
+    UpdateKind::CallbackQuery(query) => (
+      "callback_query",
+      query
+        .data
+        .as_deref()
+        .and_then(CallbackData::decode)
+        .map_or("unknown", |data| data.route()),
+    ),
+    UpdateKind::PreCheckoutQuery(_) => ("pre_checkout_query", "payment"),
+    _ => ("other", "other"),
+  }
 }
 
 fn command_branch() -> UpdateHandler<anyhow::Error> {
@@ -88,22 +223,21 @@ async fn handle_start(bot: Bot, dialogue: BotDialogue, ctx: SharedContext, msg:
   send_main_menu_message(&bot, &ctx, msg.chat.id, user_id).await
 }
 
-#[instrument(skip(bot, msg))]
-async fn handle_help(bot: Bot, msg: Message) -> HandlerResult {
+#[instrument(skip(bot, ctx, msg))]
+async fn handle_help(bot: Bot, ctx: SharedContext, msg: Message) -> HandlerResult {
   info!(chat_id = %msg.chat.id, "received /help command");
-  let mut text = Command::descriptions().to_string();
-  text.push_str(
-    "\n\nAll auction features are available from the on-screen menu buttons. Use /start to open the menu again.",
-  );
+  let locale = message_locale(&ctx, &msg).await?;
+  let text = format!("{}\n\n{}", Command::descriptions(), tr!(locale, "help-footer"));
   bot.send_message(msg.chat.id, text).await?;
   Ok(())
 }
 
 #[instrument(skip(bot, ctx))]
 async fn send_main_menu_message(bot: &Bot, ctx: &SharedContext, chat: ChatId, user_id: i64) -> HandlerResult {
+  let locale = user_locale(ctx, user_id).await?;
   bot
-    .send_message(chat, MAIN_MENU_TEXT)
-    .reply_markup(main_menu_keyboard(ctx, user_id))
+    .send_message(chat, tr!(locale, "main-menu"))
+    .reply_markup(main_menu_keyboard(ctx, user_id, locale))
     .await?;
   info!(user_id, chat_id = %chat, "sent main menu message");
   Ok(())
@@ -117,9 +251,10 @@ async fn show_main_menu(
   message_id: MessageId,
   user_id: i64,
 ) -> HandlerResult {
-  let keyboard = main_menu_keyboard(ctx, user_id);
+  let locale = user_locale(ctx, user_id).await?;
+  let keyboard = main_menu_keyboard(ctx, user_id, locale);
   let request = bot
-    .edit_message_text(chat, message_id, MAIN_MENU_TEXT)
+    .edit_message_text(chat, message_id, tr!(locale, "main-menu"))
     .reply_markup(keyboard);
   match request.await {
     Ok(_) => info!(user_id, chat_id = %chat, message_id = %message_id, "updated main menu message"),
@@ -132,80 +267,115 @@ async fn show_main_menu(
   Ok(())
 }
 
-fn main_menu_keyboard(ctx: &SharedContext, user_id: i64) -> InlineKeyboardMarkup {
-  let mut rows = vec![vec![InlineKeyboardButton::callback(
-    "🗂️ Catalogue",
-    "menu:catalogue".to_string(),
-  )]];
+fn main_menu_keyboard(ctx: &SharedContext, user_id: i64, locale: Locale) -> InlineKeyboardMarkup {
+  let menu = |target, label| CallbackData::Menu(target).button(label);
+  let mut rows = vec![vec![menu(MenuTarget::Catalogue, tr!(locale, "menu-catalogue"))]];
 
   rows.push(vec![
-    InlineKeyboardButton::callback("🪙 My bids", "menu:my_bids".to_string()),
-    InlineKeyboardButton::callback("⭐ My favorites", "menu:favorites".to_string()),
+    menu(MenuTarget::MyBids, tr!(locale, "menu-my-bids")),
+    menu(MenuTarget::Favorites, tr!(locale, "menu-favorites")),
   ]);
 
-  rows.push(vec![InlineKeyboardButton::callback(
-    "⚙️ My settings",
-    "menu:settings".to_string(),
-  )]);
+  rows.push(vec![
+    menu(MenuTarget::MyWins, tr!(locale, "menu-my-wins")),
+    menu(MenuTarget::Settings, tr!(locale, "menu-settings")),
+  ]);
 
   if ctx.is_admin(user_id) {
-    rows.push(vec![InlineKeyboardButton::callback(
-      "🛡️ Admin panel",
-      "menu:admin".to_string(),
-    )]);
+    rows.push(vec![menu(MenuTarget::Admin, tr!(locale, "menu-admin"))]);
   }
 
   InlineKeyboardMarkup::new(rows)
 }
 
 fn admin_menu_keyboard() -> InlineKeyboardMarkup {
+  let admin = |action, label: &str| CallbackData::Admin(action).button(label);
   InlineKeyboardMarkup::new(vec![
     vec![
-      InlineKeyboardButton::callback("🆕 Add category", "admin:add_category".to_string()),
-      InlineKeyboardButton::callback("📦 Add item", "admin:add_item".to_string()),
+      admin(AdminAction::AddCategory, "🆕 Add category"),
+      admin(AdminAction::AddItem, "📦 Add item"),
+    ],
+    vec![
+      admin(AdminAction::RemoveItem, "🗑 Remove item"),
+      admin(AdminAction::RemoveCategory, "🗑 Remove category"),
+    ],
+    vec![
+      admin(AdminAction::CloseItem, "🛑 Close item"),
+      admin(AdminAction::Broadcast, "📢 Broadcast"),
     ],
     vec![
-      InlineKeyboardButton::callback("🗑 Remove item", "admin:remove_item".to_string()),
-      InlineKeyboardButton::callback("🗑 Remove category", "admin:remove_category".to_string()),
+      admin(AdminAction::NotifyNew, "🔔 Notify new lots"),
+      admin(AdminAction::Orders, "🧾 Orders"),
     ],
     vec![
-      InlineKeyboardButton::callback("🛑 Close item", "admin:close_item".to_string()),
-      InlineKeyboardButton::callback("📢 Broadcast", "admin:broadcast".to_string()),
+      admin(AdminAction::Archive, "🗃 Archive"),
+      admin(AdminAction::Audit, "📋 Audit log"),
     ],
-    vec![InlineKeyboardButton::callback(
-      "🔔 Notify new lots",
-      "admin:notify_new".to_string(),
-    )],
-    vec![InlineKeyboardButton::callback("⬅️ Main menu", "menu:root".to_string())],
+    vec![
+      admin(AdminAction::Export, "📤 Export"),
+      admin(AdminAction::Import, "📥 Import lots"),
+    ],
+    vec![admin(AdminAction::Events, "🗓 Events")],
+    vec![CallbackData::Menu(MenuTarget::Root).button("⬅️ Main menu")],
   ])
 }
 
-fn main_menu_only_keyboard() -> InlineKeyboardMarkup {
-  InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(
-    "⬅️ Main menu",
-    "menu:root".to_string(),
-  )]])
+fn main_menu_only_keyboard(locale: Locale) -> InlineKeyboardMarkup {
+  InlineKeyboardMarkup::new(vec![vec![
+    CallbackData::Menu(MenuTarget::Root).button(tr!(locale, "menu-main")),
+  ]])
 }
 
-fn settings_menu_keyboard(notifications_disabled: bool) -> InlineKeyboardMarkup {
+fn settings_menu_keyboard(notifications_disabled: bool, locale: Locale) -> InlineKeyboardMarkup {
   let toggle_label = if notifications_disabled {
-    "🔔 Enable updates"
+    tr!(locale, "settings-enable-updates")
   } else {
-    "🔕 Mute updates"
+    tr!(locale, "settings-mute-updates")
   };
 
   InlineKeyboardMarkup::new(vec![
-    vec![InlineKeyboardButton::callback(
-      toggle_label.to_string(),
-      "settings:toggle_notifications".to_string(),
-    )],
-    vec![InlineKeyboardButton::callback("⬅️ Main menu", "menu:root".to_string())],
+    vec![CallbackData::Settings(SettingsAction::ToggleNotifications).button(toggle_label)],
+    vec![CallbackData::Settings(SettingsAction::ChooseLanguage).button(tr!(
+      locale,
+      "settings-language",
+      language = locale.native_name()
+    ))],
+    vec![CallbackData::Menu(MenuTarget::Root).button(tr!(locale, "menu-main"))],
   ])
 }
 
+/// Lists every supported language plus a way back to following Telegram's language. `chosen` is the stored
+/// override, if any.
+fn language_keyboard(chosen: Option<Locale>, locale: Locale) -> InlineKeyboardMarkup {
+  let mark = |selected: bool, label: String| if selected { format!("✅ {label}") } else { label };
+  let mut rows: Vec<Vec<InlineKeyboardButton>> = Locale::ALL
+    .into_iter()
+    .map(|option| {
+      vec![
+        CallbackData::Settings(SettingsAction::SetLocale(Some(option)))
+          .button(mark(chosen == Some(option), option.native_name().to_string())),
+      ]
+    })
+    .collect();
+  rows.push(vec![
+    CallbackData::Settings(SettingsAction::SetLocale(None))
+      .button(mark(chosen.is_none(), tr!(locale, "settings-language-auto"))),
+  ]);
+  rows.push(vec![
+    CallbackData::Menu(MenuTarget::Settings).button(tr!(locale, "menu-settings")),
+  ]);
+  InlineKeyboardMarkup::new(rows)
+}
+
 #[instrument(skip(bot, ctx))]
-async fn show_catalogue_menu(bot: &Bot, ctx: &SharedContext, chat: ChatId, message_id: MessageId) -> HandlerResult {
-  update_categories_menu(bot, ctx, chat, message_id).await
+async fn show_catalogue_menu(
+  bot: &Bot,
+  ctx: &SharedContext,
+  chat: ChatId,
+  message_id: MessageId,
+  locale: Locale,
+) -> HandlerResult {
+  update_categories_menu(bot, ctx, chat, message_id, locale).await
 }
 
 #[instrument(skip(bot))]
@@ -233,19 +403,20 @@ async fn show_settings_menu(
   user_id: i64,
 ) -> HandlerResult {
   let notifications_disabled = ctx.db().notifications_disabled(user_id).await?;
+  let locale = user_locale(ctx, user_id).await?;
   let status_line = if notifications_disabled {
-    "🔕 Notifications are OFF"
+    tr!(locale, "settings-notifications-off")
   } else {
-    "🔔 Notifications are ON"
+    tr!(locale, "settings-notifications-on")
   };
-  let hint_line = "Toggle below to control auction updates.";
+  let hint_line = tr!(locale, "settings-hint");
   let request = bot
     .edit_message_text(
       chat,
       message_id,
-      format!("⚙️ Settings\n\n{}\n{}", status_line, hint_line),
+      format!("{}\n\n{}\n{}", tr!(locale, "settings-title"), status_line, hint_line),
     )
-    .reply_markup(settings_menu_keyboard(notifications_disabled));
+    .reply_markup(settings_menu_keyboard(notifications_disabled, locale));
   match request.await {
     Ok(_) => info!(chat_id = %chat, message_id = %message_id, "updated settings menu"),
     Err(RequestError::Api(ApiError::MessageNotModified)) => {
@@ -257,19 +428,43 @@ async fn show_settings_menu(
   Ok(())
 }
 
+#[instrument(skip(bot, ctx))]
+async fn show_language_menu(
+  bot: &Bot,
+  ctx: &SharedContext,
+  chat: ChatId,
+  message_id: MessageId,
+  user_id: i64,
+) -> HandlerResult {
+  let user = ctx.db().get_user(user_id).await?;
+  let chosen = user
+    .as_ref()
+    .and_then(|user| user.locale.as_deref())
+    .and_then(Locale::from_code);
+  let locale = Locale::resolve(user.as_ref().and_then(UserRow::preferred_language));
+  let request = bot
+    .edit_message_text(chat, message_id, tr!(locale, "settings-language-prompt"))
+    .reply_markup(language_keyboard(chosen, locale));
+  match request.await {
+    Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(()),
+    Err(err) => Err(err.into()),
+  }
+}
+
 #[instrument(skip(bot, ctx))]
 async fn send_favorites_list(bot: &Bot, ctx: &SharedContext, chat: ChatId, user_id: i64) -> HandlerResult {
   let favorites = ctx.db().list_favorites(user_id).await?;
+  let locale = user_locale(ctx, user_id).await?;
 
   if favorites.is_empty() {
     info!(user_id, chat_id = %chat, "no favorites to display");
-    bot.send_message(chat, "⭐ No favorites yet.").await?;
+    bot.send_message(chat, tr!(locale, "favorites-empty")).await?;
     return Ok(());
   }
 
   info!(user_id, chat_id = %chat, count = favorites.len(), "sending favorites list");
   bot
-    .send_message(chat, format!("⭐ Favorites ({}):", favorites.len()))
+    .send_message(chat, tr!(locale, "favorites-header", count = favorites.len()))
     .await?;
 
   for item in favorites {
@@ -284,16 +479,17 @@ async fn send_favorites_list(bot: &Bot, ctx: &SharedContext, chat: ChatId, user_
 #[instrument(skip(bot, ctx))]
 async fn send_my_bids_list(bot: &Bot, ctx: &SharedContext, chat: ChatId, user_id: i64) -> HandlerResult {
   let bids = ctx.db().list_user_bid_items(user_id).await?;
+  let locale = user_locale(ctx, user_id).await?;
 
   if bids.is_empty() {
     info!(user_id, chat_id = %chat, "no bids to display");
-    bot.send_message(chat, "🪙 You have not placed any bids yet.").await?;
+    bot.send_message(chat, tr!(locale, "bids-empty")).await?;
     return Ok(());
   }
 
   info!(user_id, chat_id = %chat, count = bids.len(), "sending bid summary");
   bot
-    .send_message(chat, format!("🪙 Active bids ({} items):", bids.len()))
+    .send_message(chat, tr!(locale, "bids-header", count = bids.len()))
     .await?;
 
   for (item, _) in bids {
@@ -372,7 +568,14 @@ async fn handle_additem_message(
         bot.send_message(chat_id, "🗂️ Please provide a category name.").await?;
         return Ok(());
       };
-      let (category, _) = ensure_category(&ctx, name).await?;
+      let (name, currency) = match split_category_currency(name) {
+        Ok(parts) => parts,
+        Err(message) => {
+          bot.send_message(chat_id, format!("⚠️ {message}")).await?;
+          return Ok(());
+        },
+      };
+      let (category, _) = ensure_category(&ctx, draft.seller_tg_id, name, currency).await?;
       draft.category_id = Some(category.id);
       draft.category_name = Some(category.name);
       draft.stage = DraftStage::Title;
@@ -392,12 +595,7 @@ async fn handle_additem_message(
         .await?;
     },
     DraftStage::Description => {
-      let description = text.map(|value| value.to_string());
-      let value = match description.as_deref() {
-        Some("-") | None => None,
-        _ => description,
-      };
-      draft.description = value;
+      draft.description = draft_description(text);
       draft.stage = DraftStage::StartPrice;
       dialogue.update(ConversationState::AddItem(draft)).await?;
       bot.send_message(chat_id, "💰 Enter start price (e.g., 50.00):").await?;
@@ -405,46 +603,122 @@ async fn handle_additem_message(
     DraftStage::StartPrice => {
       let Some(amount_text) = text else {
         bot
-          .send_message(chat_id, "💰 Provide a start price in 0.00 format.")
+          .send_message(chat_id, "💰 Provide a start price, e.g. 50 or 1,250.")
           .await?;
         return Ok(());
       };
-      match parse_money_to_cents(amount_text) {
+      match draft_currency(&ctx, &draft).await?.parse(amount_text) {
         Ok(value) => {
           draft.start_price = Some(value);
-          let image_ids: Vec<String> = draft.image_file_ids.iter().map(|id| id.to_string()).collect();
-          let item_id = ctx
-            .db()
-            .create_item(
-              draft.seller_tg_id,
-              draft.category_id.context("missing category during draft completion")?,
-              draft
-                .title
-                .as_deref()
-                .context("missing title during draft completion")?,
-              draft.description.as_deref(),
-              value,
-              &image_ids,
+          draft.stage = DraftStage::Schedule;
+          dialogue.update(ConversationState::AddItem(draft)).await?;
+          bot
+            .send_message(
+              chat_id,
+              "🗓 When should the lot go live? Send '-' to publish now, a UTC time like 2025-12-01 18:00, a range \
+               like 2025-12-01 18:00..2025-12-03 20:00 to also close it automatically, or an auction event like #3.",
             )
             .await?;
-          dialogue.reset().await?;
-          bot.send_message(chat_id, format!("Item created: #{item_id}")).await?;
-          match send_item(&bot, &ctx, chat_id, item_id, Some(draft.seller_tg_id)).await {
-            Ok(true) => {},
-            Ok(false) => warn!(item_id, "item missing immediately after creation"),
-            Err(err) => warn!(error = %err, item_id, "failed to present new item"),
-          }
         },
         Err(err) => {
           bot.send_message(chat_id, format!("⚠️ Invalid price: {err}")).await?;
         },
       }
     },
+    DraftStage::Schedule => {
+      let Some(schedule_text) = text else {
+        bot
+          .send_message(
+            chat_id,
+            "🗓 Send '-' to publish now, a time like 2025-12-01 18:00 or an event like #3.",
+          )
+          .await?;
+        return Ok(());
+      };
+      let now = Utc::now();
+      let schedule = match scheduler::parse_schedule(schedule_text, now) {
+        Ok(request) => scheduler::resolve_schedule(&ctx, request, now).await?,
+        Err(err) => Err(err),
+      };
+      let schedule = match schedule {
+        Ok(schedule) => schedule,
+        Err(err) => {
+          bot.send_message(chat_id, format!("⚠️ {err}")).await?;
+          return Ok(());
+        },
+      };
+      create_drafted_item(&bot, &dialogue, &ctx, chat_id, draft, schedule).await?;
+    },
   }
 
   Ok(())
 }
 
+async fn draft_currency(ctx: &SharedContext, draft: &AddItemDraft) -> Result<Currency> {
+  let category_id = draft.category_id.context("missing category during draft completion")?;
+  let category_currency = ctx
+    .db()
+    .get_category(category_id)
+    .await?
+    .and_then(|category| category.currency);
+  Ok(ctx.currency_for(category_currency.as_deref()))
+}
+
+async fn create_drafted_item(
+  bot: &Bot,
+  dialogue: &BotDialogue,
+  ctx: &SharedContext,
+  chat_id: ChatId,
+  draft: AddItemDraft,
+  schedule: ItemSchedule,
+) -> HandlerResult {
+  let currency = draft_currency(ctx, &draft).await?;
+  let item = NewItem {
+    category: NewItemCategory::Existing(draft.category_id.context("missing category during draft completion")?),
+    title: draft.title.context("missing title during draft completion")?,
+    description: draft.description,
+    start_price: draft
+      .start_price
+      .context("missing start price during draft completion")?,
+    image_file_ids: draft.image_file_ids,
+    schedule,
+  };
+  let item_id = ctx.db().create_item(draft.seller_tg_id, &item).await?;
+  audit::record(
+    ctx,
+    draft.seller_tg_id,
+    AuditAction::ItemCreated,
+    AuditTarget::Item(item_id),
+    json!({
+      "title": item.title,
+      "category": draft.category_name,
+      "start_price": currency.format(item.start_price),
+      "publish_at": schedule.publish_at,
+      "closes_at": schedule.closes_at,
+      "event_id": schedule.event_id,
+    }),
+  )
+  .await;
+  dialogue.reset().await?;
+  let mut text = format!("Item created: #{item_id}");
+  if let Some(publish_at) = schedule.publish_at {
+    text.push_str(&format!(
+      "\n🗓 It stays hidden until {} UTC and is announced then.",
+      publish_at.format(scheduler::TIME_FORMAT)
+    ));
+  }
+  bot.send_message(chat_id, text).await?;
+  if let Err(err) = channel::publish_item(bot, ctx, item_id).await {
+    warn!(error = %err, item_id, "failed to publish item to channel");
+  }
+  match send_item(bot, ctx, chat_id, item_id, Some(draft.seller_tg_id)).await {
+    Ok(true) => {},
+    Ok(false) => warn!(item_id, "item missing immediately after creation"),
+    Err(err) => warn!(error = %err, item_id, "failed to present new item"),
+  }
+  Ok(())
+}
+
 #[instrument(skip(bot, ctx, dialogue, msg))]
 async fn handle_bid_message(
   bot: Bot,
@@ -457,71 +731,68 @@ async fn handle_bid_message(
   let bidder_id = user.id.0 as i64;
   let chat_id = msg.chat.id;
   info!(bidder_id, chat_id = %chat_id, item_id = draft.item_id, "handling bid input");
+  let locale = user_locale(&ctx, bidder_id).await?;
 
   if bidder_id != draft.bidder_tg_id {
-    bot.send_message(chat_id, "Another bid is already in progress.").await?;
+    bot.send_message(chat_id, tr!(locale, "bid-in-progress")).await?;
     return Ok(());
   }
 
   let Some(amount_text) = message_text(&msg).map(|t| t.trim()).filter(|t| !t.is_empty()) else {
-    bot.send_message(chat_id, "Provide your bid in 0.00 format.").await?;
+    bot.send_message(chat_id, tr!(locale, "bid-amount-missing")).await?;
     return Ok(());
   };
 
-  match validate_bid(&ctx, draft.item_id, amount_text).await {
-    Ok((item, amount_cents, previous_best)) => match ctx.db().place_bid(draft.item_id, bidder_id, amount_cents).await
-    {
-      Ok(_) => {
+  let currency = bid_currency(&ctx, draft.item_id).await?;
+  let result = match currency.parse(amount_text) {
+    Ok(amount_cents) => {
+      if let Some((item, reference)) = quick_bid::requires_confirmation(&ctx, draft.item_id, amount_cents).await? {
         dialogue.reset().await?;
-
-        let highest = ctx.db().best_bid_with_bidder(draft.item_id).await?;
-        let mut confirmation = format!(
-          "Bid placed at {} for item #{}.",
-          format_cents(amount_cents),
-          draft.item_id
-        );
-        let is_highest = matches!(
-          highest,
-          Some((top_bidder, top_amount)) if top_bidder == bidder_id && top_amount == amount_cents
+        quick_bid::send_confirmation_prompt(&bot, chat_id, msg.id, user, quick_bid::BidPrompt {
+          item: &item,
+          currency,
+          amount: amount_cents,
+          reference: Some(reference),
+          locale,
+        })
+        .await?;
+        info!(
+          bidder_id,
+          item_id = draft.item_id,
+          amount_cents,
+          "asked to confirm high bid"
         );
-        if is_highest {
-          confirmation.push_str("\n\n🎉 You're now the highest bidder!");
-        }
-
-        bot.send_message(chat_id, confirmation).await?;
-
-        if is_highest
-          && let Some((outbid_user_id, outbid_amount)) = previous_best
-          && outbid_user_id != bidder_id
-          && let Err(err) =
-            notify_outbid_user(&bot, &ctx, &item, outbid_user_id, outbid_amount, amount_cents, user).await
-        {
-          warn!(error = %err, item_id = item.id, outbid_user_id, "failed to notify outbid user");
-        }
-
-        let _ = notify_seller(&bot, &ctx, &item, user, amount_cents).await;
-        info!(bidder_id, item_id = draft.item_id, amount_cents, "bid accepted");
-        match send_item(&bot, &ctx, chat_id, draft.item_id, Some(bidder_id)).await {
-          Ok(true) => {},
-          Ok(false) => warn!(item_id = draft.item_id, "item no longer available after bid"),
-          Err(err) => warn!(error = %err, item_id = draft.item_id, "failed to present item after bid"),
-        }
-      },
-      Err(err) => {
-        warn!(error = %err, item_id = draft.item_id, bidder_id, "failed to store bid");
-        bot
-          .send_message(chat_id, "Failed to place bid, try again later.")
-          .await?;
-      },
+        return Ok(());
+      }
+      place_validated_bid(&bot, &ctx, draft.item_id, user, amount_cents).await
     },
-    Err(BidError::Storage(err)) => {
-      warn!(error = %err, item_id = draft.item_id, bidder_id, "storage error during bid validation");
-      bot
-        .send_message(chat_id, "Failed to place bid, try again later.")
-        .await?;
+    Err(err) => Err(err.into()),
+  };
+  record_bid_outcome(&result);
+  match result {
+    Ok(placed) => {
+      dialogue.reset().await?;
+
+      let mut confirmation = tr!(
+        locale,
+        "bid-placed",
+        amount = placed.currency.format(placed.amount_cents),
+        item_id = draft.item_id
+      );
+      if placed.is_highest {
+        confirmation.push_str(&format!("\n\n{}", tr!(locale, "bid-highest")));
+      }
+
+      bot.send_message(chat_id, confirmation).await?;
+      match send_item(&bot, &ctx, chat_id, draft.item_id, Some(bidder_id)).await {
+        Ok(true) => {},
+        Ok(false) => warn!(item_id = draft.item_id, "item no longer available after bid"),
+        Err(err) => warn!(error = %err, item_id = draft.item_id, "failed to present item after bid"),
+      }
     },
+    Err(BidError::Internal(err)) => return Err(err.into()),
     Err(other) => {
-      bot.send_message(chat_id, other.user_message()).await?;
+      bot.send_message(chat_id, other.user_message(locale)).await?;
       if matches!(other, BidError::NotFound | BidError::Closed) {
         dialogue.reset().await?;
       }
@@ -562,14 +833,28 @@ async fn handle_add_category_message(
     return Ok(());
   }
 
-  let (category, existing) = ensure_category(&ctx, raw_text).await?;
+  let (name, currency) = match split_category_currency(raw_text) {
+    Ok(parts) => parts,
+    Err(message) => {
+      bot.send_message(msg.chat.id, format!("⚠️ {message}")).await?;
+      return Ok(());
+    },
+  };
+  let (category, existing) = ensure_category(&ctx, admin_tg_id, name, currency).await?;
   info!(admin_tg_id, category_id = category.id, existing, "ensured category");
   dialogue.reset().await?;
 
+  let currency = ctx.currency_for(category.currency.as_deref());
   let response = if existing {
-    format!("⚠️ Category already exists: {} (#{})", category.name, category.id)
+    format!(
+      "⚠️ Category already exists: {} (#{}), priced in {currency}",
+      category.name, category.id
+    )
   } else {
-    format!("✅ Category created: {} (#{})", category.name, category.id)
+    format!(
+      "✅ Category created: {} (#{}), priced in {currency}",
+      category.name, category.id
+    )
   };
 
   bot.send_message(msg.chat.id, response).await?;
@@ -583,18 +868,15 @@ fn build_category_picker_keyboard(categories: &[CategoryRow]) -> InlineKeyboardM
     rows.push(
       chunk
         .iter()
-        .map(|c| InlineKeyboardButton::callback(c.name.clone(), format!("pickcat:{}", c.id)))
+        .map(|c| CallbackData::PickCategory(CategoryPick::Existing(c.id)).button(c.name.clone()))
         .collect::<Vec<_>>(),
     );
   }
 
   // footer
-  let mut footer = vec![InlineKeyboardButton::callback(
-    "➕ New category",
-    "pickcat:new".to_string(),
-  )];
+  let mut footer = vec![CallbackData::PickCategory(CategoryPick::New).button("➕ New category")];
 
-  footer.push(InlineKeyboardButton::callback("⬅️ Main menu", "menu:root".to_string()));
+  footer.push(CallbackData::Menu(MenuTarget::Root).button("⬅️ Main menu"));
 
   rows.push(footer);
   InlineKeyboardMarkup::new(rows)
@@ -680,17 +962,61 @@ async fn handle_close_item_message(
     return Ok(());
   }
 
-  ctx.db().close_item(item_id).await?;
-  info!(admin_tg_id, item_id, "closed item");
+  let closed = close_item(&bot, &ctx, admin_tg_id, &item).await?;
   dialogue.reset().await?;
-  bot
-    .send_message(msg.chat.id, format!("🛑 Item #{item_id} closed."))
-    .await?;
+  let text = if closed {
+    format!("🛑 Item #{item_id} closed.")
+  } else {
+    format!("ℹ️ Item #{item_id} is already closed.")
+  };
+  bot.send_message(msg.chat.id, text).await?;
+  if closed {
+    cards::schedule_refresh(&bot, &ctx, item_id);
+  }
+  Ok(())
+}
+
+/// Closes an open item for an admin: opens the winner's order, records the closure, tells the watchers and sends
+/// the invoice. Everything after the closure itself is best effort. Refreshing the posted cards is up to the caller.
+/// Returns `false` if someone else closed the item first.
+pub async fn close_item(bot: &Bot, ctx: &SharedContext, admin_tg_id: i64, item: &ItemRow) -> Result<bool> {
+  let item_id = item.id;
+  if !ctx.db().close_item(item_id).await? {
+    return Ok(false);
+  }
+  info!(admin_tg_id, item_id, "closed item");
 
-  if let Err(err) = notify_item_closed(&bot, &ctx, &item).await {
+  let order = match orders::open_for_closed_item(ctx, item).await {
+    Ok(order) => order,
+    Err(err) => {
+      warn!(error = %err, item_id, "failed to open order for closed item");
+      None
+    },
+  };
+  audit::record(
+    ctx,
+    admin_tg_id,
+    AuditAction::ItemClosed,
+    AuditTarget::Item(item_id),
+    json!({
+      "title": item.title,
+      "order_id": order.as_ref().map(|order| order.id),
+      "winning_bid": order.as_ref().map(|order| ctx.item_currency(item).format(order.amount)),
+    }),
+  )
+  .await;
+  if let Err(err) = notify_item_closed(bot, ctx, item).await {
     warn!(error = %err, item_id, "failed to notify watchers about closed item");
   }
-  Ok(())
+  if let (Some(order), Some(provider_token)) = (order, ctx.payment_provider_token()) {
+    let locale = user_locale(ctx, order.winner_tg_id).await?;
+    if let Err(err) =
+      payments::send_order_invoice(bot, provider_token, locale, ctx.item_currency(item), &order, item).await
+    {
+      warn!(error = %err, item_id, order_id = order.id, "failed to send order invoice");
+    }
+  }
+  Ok(true)
 }
 
 #[instrument(skip(bot, ctx, dialogue, msg))]
@@ -737,7 +1063,22 @@ async fn handle_broadcast_message(
     return Ok(());
   }
 
-  let delivered = broadcast_text(&bot, &recipients, &text, (!entities.is_empty()).then_some(&entities)).await;
+  let delivered = broadcast_text(
+    &bot,
+    &ctx,
+    &recipients,
+    &text,
+    (!entities.is_empty()).then_some(&entities),
+  )
+  .await;
+  audit::record(
+    &ctx,
+    admin_tg_id,
+    AuditAction::Broadcast,
+    AuditTarget::Users,
+    json!({ "text": text, "recipients": recipients.len(), "delivered": delivered }),
+  )
+  .await;
 
   dialogue.reset().await?;
   bot
@@ -785,13 +1126,21 @@ async fn handle_remove_item_message(
     },
   };
 
-  if ctx.db().delete_item(item_id).await? {
+  if ctx.db().archive_item(item_id, admin_tg_id).await? {
     dialogue.reset().await?;
-    info!(admin_tg_id, item_id, "item removed");
+    info!(admin_tg_id, item_id, "item archived");
+    audit::record(
+      &ctx,
+      admin_tg_id,
+      AuditAction::ItemArchived,
+      AuditTarget::Item(item_id),
+      json!({}),
+    )
+    .await;
     bot
       .send_message(
         msg.chat.id,
-        format!("🗑 Item #{item_id} removed. Related bids and favorites were deleted."),
+        format!("🗃 Item #{item_id} archived. Bids and favorites are kept, and it can be restored from 🗃 Archive."),
       )
       .await?;
   } else {
@@ -844,33 +1193,14 @@ async fn handle_remove_category_message(
   };
 
   info!(admin_tg_id, category_id = category.id, "category found for removal");
-
-  let item_count = ctx.db().list_items_by_category(category.id).await?.len();
-  info!(admin_tg_id, category_id = category.id, item_count, "removing category");
-  if ctx.db().delete_category(category.id).await? {
-    dialogue.reset().await?;
-    info!(admin_tg_id, category_id = category.id, item_count, "category removed");
-    bot
-      .send_message(
-        msg.chat.id,
-        format!(
-          "🗑 Category '{}' removed. {} item(s) and related bids/favorites were deleted.",
-          category.name, item_count
-        ),
-      )
-      .await?;
-  } else {
-    info!(admin_tg_id, category_id = category.id, "category not removed");
-    bot
-      .send_message(msg.chat.id, "❓ Category not found or already removed.")
-      .await?;
-  }
+  dialogue.reset().await?;
+  archive::remove_category(&bot, &ctx, msg.chat.id, admin_tg_id, &category).await?;
 
   Ok(())
 }
 
-#[instrument(skip(bot, msg))]
-async fn handle_idle_text(bot: Bot, msg: Message, state: ConversationState) -> HandlerResult {
+#[instrument(skip(bot, ctx, msg))]
+async fn handle_idle_text(bot: Bot, ctx: SharedContext, msg: Message, state: ConversationState) -> HandlerResult {
   if matches!(state, ConversationState::Idle)
     && let Some(text) = msg.text()
   {
@@ -878,421 +1208,594 @@ async fn handle_idle_text(bot: Bot, msg: Message, state: ConversationState) -> H
       // unknown command, ignore to let telegram handle
     } else {
       info!(chat_id = %msg.chat.id, "idle state received unrecognized message");
-      bot
-        .send_message(msg.chat.id, "I did not understand that. Use the menu buttons or /help.")
-        .await?;
+      let locale = message_locale(&ctx, &msg).await?;
+      bot.send_message(msg.chat.id, tr!(locale, "idle-unknown")).await?;
     }
   }
   Ok(())
 }
 
-#[instrument(skip(bot, ctx, dialogue, query))]
-async fn handle_callback_query(
+/// Registers the user behind a callback and decodes its data. Undecodable data falls through to
+/// [`handle_unknown_callback`].
+async fn decode_callback(ctx: SharedContext, query: CallbackQuery) -> Option<CallbackData> {
+  let user_id = query.from.id.0 as i64;
+  if let Err(err) = ensure_user_record(&ctx, &query.from).await {
+    warn!(error = %err, user_id, "failed to record callback sender");
+  }
+  let data = query.data.as_deref().unwrap_or("<empty>");
+  match query.message.as_ref() {
+    Some(message) => info!(user_id, chat_id = %message.chat().id, callback = data, "handling callback query"),
+    None => info!(
+      user_id,
+      callback = data,
+      "handling callback query without message context"
+    ),
+  }
+  query.data.as_deref().and_then(CallbackData::decode)
+}
+
+/// The chat and message a callback's button is attached to, if the message is still accessible.
+fn callback_message(query: &CallbackQuery) -> Option<(ChatId, MessageId)> {
+  query.message.as_ref().map(|message| (message.chat().id, message.id()))
+}
+
+#[instrument(skip(bot, ctx, query))]
+async fn handle_unknown_callback(bot: Bot, ctx: SharedContext, query: CallbackQuery) -> HandlerResult {
+  let user_id = query.from.id.0 as i64;
+  warn!(
+    user_id,
+    callback = query.data.as_deref(),
+    "ignoring unknown callback data"
+  );
+  let locale = user_locale(&ctx, user_id).await?;
+  callback::answer(&bot, &query, Some(tr!(locale, "callback-stale"))).await
+}
+
+#[instrument(skip(bot, dialogue, ctx, query))]
+async fn handle_menu_callback(
   bot: Bot,
+  dialogue: BotDialogue,
   ctx: SharedContext,
   query: CallbackQuery,
+  target: MenuTarget,
+) -> HandlerResult {
+  let user_id = query.from.id.0 as i64;
+  let locale = user_locale(&ctx, user_id).await?;
+  let Some((chat_id, message_id)) = callback_message(&query) else {
+    return callback::answer(&bot, &query, None).await;
+  };
+
+  let text = match target {
+    MenuTarget::Root => {
+      dialogue.reset().await?;
+      show_main_menu(&bot, &ctx, chat_id, message_id, user_id).await?;
+      None
+    },
+    MenuTarget::Catalogue => {
+      dialogue.reset().await?;
+      show_catalogue_menu(&bot, &ctx, chat_id, message_id, locale).await?;
+      None
+    },
+    MenuTarget::Favorites => {
+      send_favorites_list(&bot, &ctx, chat_id, user_id).await?;
+      Some(tr!(locale, "menu-favorites-sent"))
+    },
+    MenuTarget::MyBids => {
+      send_my_bids_list(&bot, &ctx, chat_id, user_id).await?;
+      Some(tr!(locale, "menu-bids-sent"))
+    },
+    MenuTarget::MyWins => {
+      orders::send_my_wins(&bot, &ctx, chat_id, user_id).await?;
+      Some(tr!(locale, "menu-wins-sent"))
+    },
+    MenuTarget::Settings => {
+      dialogue.reset().await?;
+      show_settings_menu(&bot, &ctx, chat_id, message_id, user_id).await?;
+      None
+    },
+    MenuTarget::Admin if ctx.is_admin(user_id) => {
+      dialogue.reset().await?;
+      show_admin_menu(&bot, chat_id, message_id).await?;
+      None
+    },
+    MenuTarget::Admin => Some(tr!(locale, "admins-only")),
+  };
+  callback::answer(&bot, &query, text).await
+}
+
+#[instrument(skip(bot, dialogue, ctx, query))]
+async fn handle_admin_callback(
+  bot: Bot,
   dialogue: BotDialogue,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  action: AdminAction,
 ) -> HandlerResult {
-  ensure_user_record(&ctx, &query.from).await?;
-  let mut callback_text: Option<String> = None;
   let user_id = query.from.id.0 as i64;
-  let message_ctx = query.message.as_ref().map(|message| (message.chat().id, message.id()));
-  let callback_data = query.data.as_deref().unwrap_or("<empty>");
-  if let Some((chat_id, _)) = message_ctx {
-    info!(user_id, chat_id = %chat_id, callback = callback_data, "handling callback query");
-  } else {
-    info!(
-      user_id,
-      callback = callback_data,
-      "handling callback query without message context"
-    );
+  if !ctx.is_admin(user_id) {
+    let locale = user_locale(&ctx, user_id).await?;
+    return callback::answer(&bot, &query, Some(tr!(locale, "admins-only"))).await;
   }
+  let chat = callback_message(&query).map(|(chat_id, _)| chat_id);
+  dialogue.reset().await?;
 
-  if let Some(data) = query.data.as_deref()
-    && let Some((prefix, value)) = data.split_once(':')
-  {
-    match prefix {
-      "menu" => match value {
-        "root" => {
-          dialogue.reset().await?;
-          if let Some((chat_id, message_id)) = message_ctx {
-            show_main_menu(&bot, &ctx, chat_id, message_id, user_id).await?;
-          }
-        },
-        "catalogue" => {
-          dialogue.reset().await?;
-          if let Some((chat_id, message_id)) = message_ctx {
-            show_catalogue_menu(&bot, &ctx, chat_id, message_id).await?;
-          }
-        },
-        "favorites" => {
-          if let Some((chat_id, _)) = message_ctx {
-            send_favorites_list(&bot, &ctx, chat_id, user_id).await?;
-            callback_text = Some("⭐ Sent your favorites.".to_string());
-          }
-        },
-        "my_bids" => {
-          if let Some((chat_id, _)) = message_ctx {
-            send_my_bids_list(&bot, &ctx, chat_id, user_id).await?;
-            callback_text = Some("🪙 Sent your bids.".to_string());
-          }
-        },
-        "settings" => {
-          dialogue.reset().await?;
-          if let Some((chat_id, message_id)) = message_ctx {
-            show_settings_menu(&bot, &ctx, chat_id, message_id, user_id).await?;
-          }
-        },
-        "admin" => {
-          if ctx.is_admin(user_id) {
-            dialogue.reset().await?;
-            if let Some((chat_id, message_id)) = message_ctx {
-              show_admin_menu(&bot, chat_id, message_id).await?;
-            }
-          } else {
-            callback_text = Some("🛡️ Admins only.".to_string());
-          }
-        },
-        _ => {},
-      },
-      "admin" => {
-        if !ctx.is_admin(user_id) {
-          callback_text = Some("🛡️ Admins only.".to_string());
-        } else {
-          match value {
-            "add_category" => {
-              dialogue.reset().await?;
-              dialogue
-                .update(ConversationState::AddCategory { admin_tg_id: user_id })
-                .await?;
-              if let Some((chat_id, _)) = message_ctx {
-                bot.send_message(chat_id, "🆕 Send the new category name:").await?;
-              }
-              callback_text = Some("🆕 Waiting for category name.".to_string());
-            },
-            "add_item" => {
-              dialogue.reset().await?;
-              dialogue
-                .update(ConversationState::AddItem(AddItemDraft::new(user_id, None)))
-                .await?;
-              if let Some((chat_id, _)) = message_ctx {
-                // show picker
-                send_category_picker_message(&bot, &ctx, chat_id).await?;
-              }
-              callback_text = Some("📦 Starting item creation.".to_string());
-            },
-            "remove_item" => {
-              dialogue.reset().await?;
-              dialogue
-                .update(ConversationState::RemoveItem { admin_tg_id: user_id })
-                .await?;
-              if let Some((chat_id, _)) = message_ctx {
-                bot
-                  .send_message(
-                    chat_id,
-                    "🗑 Send the item ID to remove (bids and favorites will also be removed). Type cancel to stop.",
-                  )
-                  .await?;
-              }
-              callback_text = Some("🗑 Awaiting item ID to remove.".to_string());
-            },
-            "remove_category" => {
-              dialogue.reset().await?;
-              dialogue
-                .update(ConversationState::RemoveCategory { admin_tg_id: user_id })
-                .await?;
-              if let Some((chat_id, _)) = message_ctx {
-                bot
-                  .send_message(
-                    chat_id,
-                    "🗑 Send the category ID to remove (all items, bids, and favorites under it will be deleted). \
-                     Type cancel to stop.",
-                  )
-                  .await?;
-              }
-              callback_text = Some("🗑 Awaiting category ID to remove.".to_string());
-            },
-            "close_item" => {
-              dialogue.reset().await?;
-              dialogue
-                .update(ConversationState::CloseItem { admin_tg_id: user_id })
-                .await?;
-              if let Some((chat_id, _)) = message_ctx {
-                bot.send_message(chat_id, "🛑 Send the item ID to close:").await?;
-              }
-              callback_text = Some("🛑 Awaiting item ID.".to_string());
-            },
-            "broadcast" => {
-              dialogue.reset().await?;
-              dialogue
-                .update(ConversationState::Broadcast { admin_tg_id: user_id })
-                .await?;
-              if let Some((chat_id, _)) = message_ctx {
-                bot
-                  .send_message(chat_id, "📢 Send the announcement text to broadcast to all users.")
-                  .await?;
-              }
-              callback_text = Some("📢 Waiting for announcement text.".to_string());
-            },
-            "notify_new" => {
-              dialogue.reset().await?;
-              let new_items = ctx.db().list_new_items().await?;
-              if new_items.is_empty() {
-                if let Some((chat_id, _)) = message_ctx {
-                  bot
-                    .send_message(chat_id, "🔔 No new lots are marked for notification.")
-                    .await?;
-                }
-                callback_text = Some("🔔 No new lots.".to_string());
-              } else {
-                let user_ids = ctx.db().list_user_ids().await?;
-                if user_ids.is_empty() {
-                  if let Some((chat_id, _)) = message_ctx {
-                    bot
-                      .send_message(chat_id, "🔔 No users are registered to receive the update.")
-                      .await?;
-                  }
-                  callback_text = Some("🔔 No users registered.".to_string());
-                } else {
-                  let mut announcement = String::from("🆕 New lots available!\n\n");
-                  for item in &new_items {
-                    let line = format!(
-                      "• #{} {} — start {}\n",
-                      item.id,
-                      item.title,
-                      format_cents(item.start_price)
-                    );
-                    announcement.push_str(&line);
-                  }
-
-                  info!(
-                    admin_tg_id = user_id,
-                    lot_count = new_items.len(),
-                    recipient_count = user_ids.len(),
-                    "broadcasting new lots"
-                  );
-                  let delivered = broadcast_text(&bot, &user_ids, &announcement, None).await;
-                  let ids: Vec<i64> = new_items.iter().map(|item| item.id).collect();
-                  ctx.db().clear_new_item_flags(&ids).await?;
-
-                  if let Some((chat_id, _)) = message_ctx {
-                    bot
-                      .send_message(
-                        chat_id,
-                        format!("🔔 Notified {delivered} user(s) about {} new lot(s).", new_items.len()),
-                      )
-                      .await?;
-                  }
-                  callback_text = Some("🔔 Notification sent.".to_string());
-                }
-              }
-            },
-            _ => {},
-          }
-        }
-      },
-      "pickcat" => {
-        if let Some((chat_id, _message_id)) = message_ctx {
-          match value {
-            "new" => {
-              let state = dialogue.get().await?;
-              if !matches!(state, Some(ConversationState::AddItem(_))) {
-                dialogue
-                  .update(ConversationState::AddItem(AddItemDraft::new(user_id, None)))
-                  .await?;
-              }
-              bot
-                .send_message(chat_id, "🆕 Send the new category name (or type cancel).")
-                .await?;
-              callback_text = Some("🆕 Waiting for category name.".to_string());
-            },
-            id_str => {
-              if let Ok(category_id) = id_str.parse::<i64>() {
-                let categories = ctx.db().list_categories().await?;
-                if let Some(category) = categories.into_iter().find(|c| c.id == category_id) {
-                  if let Some(ConversationState::AddItem(mut draft)) = dialogue.get().await? {
-                    draft.category_id = Some(category.id);
-                    draft.category_name = Some(category.name);
-                    draft.stage = DraftStage::Title;
-                    dialogue.update(ConversationState::AddItem(draft)).await?;
-                    bot.send_message(chat_id, "📝 Enter item title:").await?;
-                    callback_text = Some("🗂️ Category selected.".to_string());
-                  } else {
-                    let mut draft = AddItemDraft::new(user_id, None);
-                    draft.category_id = Some(category.id);
-                    draft.category_name = Some(category.name);
-                    draft.stage = DraftStage::Title;
-                    dialogue.update(ConversationState::AddItem(draft)).await?;
-                    bot.send_message(chat_id, "📝 Enter item title:").await?;
-                    callback_text = Some("🗂️ Category selected.".to_string());
-                  }
-                } else {
-                  callback_text = Some("❓ Category not found".to_string());
-                }
-              }
-            },
-          }
-        }
-      },
-      "cat" => {
-        if let Ok(category_id) = value.parse::<i64>()
-          && let Some((chat_id, message_id)) = message_ctx
-        {
-          let categories = ctx.db().list_categories().await?;
-          if let Some(category) = categories.into_iter().find(|c| c.id == category_id) {
-            show_category_items_menu(&bot, &ctx, chat_id, message_id, category.id, category.name.as_str()).await?;
-          } else {
-            callback_text = Some("❓ Category not found".to_string());
-          }
-        }
-      },
-      "item" => {
-        if let Ok(item_id) = value.parse::<i64>()
-          && let Some((chat_id, _)) = message_ctx
-          && !send_item(&bot, &ctx, chat_id, item_id, Some(user_id)).await?
-        {
-          callback_text = Some("❓ Item not found".to_string());
-        }
-      },
-      "img" => {
-        let mut parts = value.split(':');
-        if let (Some(item_str), Some(offset_str)) = (parts.next(), parts.next())
-          && let (Ok(item_id), Ok(offset)) = (item_str.parse::<i64>(), offset_str.parse::<usize>())
-        {
-          let images = ctx.db().list_item_images(item_id).await?;
-          if let Some((chat_id, message_id)) = message_ctx {
-            let total = images.len();
-            if offset >= total {
-              let request = bot
-                .edit_message_text(chat_id, message_id, "📷 All images shown.")
-                .reply_markup(InlineKeyboardMarkup::default());
-              if let Err(err) = request.await
-                && !matches!(err, RequestError::Api(ApiError::MessageNotModified))
-              {
-                return Err(err.into());
-              }
-              callback_text = Some("📷 All images already shown.".to_string());
-            } else {
-              let next = send_item_images_chunk(&bot, chat_id, &images, offset, None).await?;
-              if next < total {
-                let remaining = total - next;
-                let keyboard = InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(
-                  format!("Show more images ({remaining})"),
-                  format!("img:{item_id}:{next}"),
-                )]]);
-                let request = bot
-                  .edit_message_text(chat_id, message_id, format!("📷 {remaining} more photo(s) available."))
-                  .reply_markup(keyboard);
-                if let Err(err) = request.await
-                  && !matches!(err, RequestError::Api(ApiError::MessageNotModified))
-                {
-                  return Err(err.into());
-                }
-              } else {
-                let request = bot
-                  .edit_message_text(chat_id, message_id, "📷 All images shown.")
-                  .reply_markup(InlineKeyboardMarkup::default());
-                if let Err(err) = request.await
-                  && !matches!(err, RequestError::Api(ApiError::MessageNotModified))
-                {
-                  return Err(err.into());
-                }
-              }
-              callback_text = Some("📷 Sent more photos.".to_string());
-            }
-          }
-        }
-      },
-      "back" => {
-        if value == "categories"
-          && let Some((chat_id, message_id)) = message_ctx
-        {
-          show_catalogue_menu(&bot, &ctx, chat_id, message_id).await?;
-        }
-      },
-      "bid" => {
-        if let Ok(item_id) = value.parse::<i64>() {
-          match ctx.db().get_item(item_id).await? {
-            Some(item) if item.is_open => {
-              dialogue
-                .update(ConversationState::PlaceBid(BidDraft {
-                  item_id,
-                  bidder_tg_id: user_id,
-                }))
-                .await?;
-              if let Some((chat_id, _)) = message_ctx {
-                bot
-                  .send_message(chat_id, format!("Enter your bid for item #{item_id} in 0.00 format:"))
-                  .await?;
-              }
-            },
-            Some(_) => {
-              callback_text = Some("🔒 Auction is closed".to_string());
-            },
-            None => {
-              callback_text = Some("❓ Item not found".to_string());
-            },
-          }
-        }
-      },
-      "fav" => {
-        if let Some((action, item_str)) = value.split_once(':')
-          && let Ok(item_id) = item_str.parse::<i64>()
-        {
-          match action {
-            "add" => {
-              ctx.db().add_favorite(user_id, item_id).await?;
-              callback_text = Some("⭐ Added to favorites".to_string());
-            },
-            "remove" => {
-              ctx.db().remove_favorite(user_id, item_id).await?;
-              callback_text = Some("❌ Removed from favorites".to_string());
-            },
-            _ => {},
-          }
-
-          if let Some((chat_id, message_id)) = message_ctx
-            && let Some(item) = ctx.db().get_item(item_id).await?
-          {
-            let viewer = build_item_viewer_context(&ctx, item_id, user_id).await?;
-            let keyboard = item_action_keyboard(item.id, item.is_open, Some(&viewer));
-            if let Err(err) = bot
-              .edit_message_reply_markup(chat_id, message_id)
-              .reply_markup(keyboard)
-              .await
-              && !matches!(err, RequestError::Api(ApiError::MessageNotModified))
-            {
-              return Err(err.into());
-            }
-          }
-        }
-      },
-      "settings" => match value {
-        "toggle_notifications" => {
-          let currently_disabled = ctx.db().notifications_disabled(user_id).await?;
-          let next = !currently_disabled;
-          ctx.db().set_notifications_disabled(user_id, next).await?;
-          if let Some((chat_id, message_id)) = message_ctx {
-            show_settings_menu(&bot, &ctx, chat_id, message_id, user_id).await?;
-          }
-          callback_text = Some(if next {
-            "🔕 Notifications muted.".to_string()
-          } else {
-            "🔔 Notifications enabled.".to_string()
-          });
-        },
-        _ => {},
-      },
-      _ => {},
+  let text = match action {
+    AdminAction::AddCategory => {
+      dialogue
+        .update(ConversationState::AddCategory { admin_tg_id: user_id })
+        .await?;
+      if let Some(chat_id) = chat {
+        bot
+          .send_message(
+            chat_id,
+            "🆕 Send the new category name. To price it in another currency, add the code in brackets, e.g. Watches \
+             [USD].",
+          )
+          .await?;
+      }
+      "🆕 Waiting for category name."
+    },
+    AdminAction::AddItem => {
+      dialogue
+        .update(ConversationState::AddItem(AddItemDraft::new(user_id, None)))
+        .await?;
+      if let Some(chat_id) = chat {
+        send_category_picker_message(&bot, &ctx, chat_id).await?;
+      }
+      "📦 Starting item creation."
+    },
+    AdminAction::RemoveItem => {
+      dialogue
+        .update(ConversationState::RemoveItem { admin_tg_id: user_id })
+        .await?;
+      if let Some(chat_id) = chat {
+        bot
+          .send_message(
+            chat_id,
+            "🗑 Send the item ID to remove. It will be archived and can be restored later. Type cancel to stop.",
+          )
+          .await?;
+      }
+      "🗑 Awaiting item ID to remove."
+    },
+    AdminAction::RemoveCategory => {
+      dialogue
+        .update(ConversationState::RemoveCategory { admin_tg_id: user_id })
+        .await?;
+      if let Some(chat_id) = chat {
+        bot
+          .send_message(
+            chat_id,
+            "🗑 Send the category name to remove. You can move its items elsewhere or archive them with it. Type \
+             cancel to stop.",
+          )
+          .await?;
+      }
+      "🗑 Awaiting category ID to remove."
+    },
+    AdminAction::CloseItem => {
+      dialogue
+        .update(ConversationState::CloseItem { admin_tg_id: user_id })
+        .await?;
+      if let Some(chat_id) = chat {
+        bot.send_message(chat_id, "🛑 Send the item ID to close:").await?;
+      }
+      "🛑 Awaiting item ID."
+    },
+    AdminAction::Broadcast => {
+      dialogue
+        .update(ConversationState::Broadcast { admin_tg_id: user_id })
+        .await?;
+      if let Some(chat_id) = chat {
+        bot
+          .send_message(chat_id, "📢 Send the announcement text to broadcast to all users.")
+          .await?;
+      }
+      "📢 Waiting for announcement text."
+    },
+    AdminAction::Archive => {
+      if let Some(chat_id) = chat {
+        archive::send_archive(&bot, &ctx, chat_id).await?;
+      }
+      "🗃 Sent the archive."
+    },
+    AdminAction::Audit => {
+      if let Some(chat_id) = chat {
+        audit::send_audit_log(&bot, &ctx, chat_id).await?;
+      }
+      "📋 Sent the audit log."
+    },
+    AdminAction::Orders => {
+      if let Some(chat_id) = chat {
+        orders::send_active_orders(&bot, &ctx, chat_id).await?;
+      }
+      "🧾 Sent open orders."
+    },
+    AdminAction::Export => {
+      dialogue
+        .update(ConversationState::Export { admin_tg_id: user_id })
+        .await?;
+      if let Some(chat_id) = chat {
+        export::send_prompt(&bot, &ctx, chat_id).await?;
+      }
+      "📤 Waiting for the export request."
+    },
+    AdminAction::Import => {
+      dialogue
+        .update(ConversationState::Import {
+          admin_tg_id: user_id,
+          lots: Vec::new(),
+        })
+        .await?;
+      if let Some(chat_id) = chat {
+        import::send_prompt(&bot, chat_id).await?;
+      }
+      "📥 Waiting for the import file."
+    },
+    AdminAction::Events => {
+      dialogue
+        .update(ConversationState::CreateEvent { admin_tg_id: user_id })
+        .await?;
+      if let Some(chat_id) = chat {
+        scheduler::send_events(&bot, &ctx, chat_id).await?;
+      }
+      "🗓 Sent the auction events."
+    },
+    AdminAction::NotifyNew => notify_new_lots(&bot, &ctx, chat, user_id).await?,
+  };
+  callback::answer(&bot, &query, Some(text.to_string())).await
+}
+
+/// Announces the lots flagged as new to every registered user and returns the text for the callback answer.
+async fn notify_new_lots(
+  bot: &Bot,
+  ctx: &SharedContext,
+  chat: Option<ChatId>,
+  admin_tg_id: i64,
+) -> Result<&'static str> {
+  let new_items = ctx.db().list_new_items().await?;
+  if new_items.is_empty() {
+    if let Some(chat_id) = chat {
+      bot
+        .send_message(chat_id, "🔔 No new lots are marked for notification.")
+        .await?;
     }
+    return Ok("🔔 No new lots.");
   }
+  let Some(delivered) = announce_new_lots(bot, ctx, admin_tg_id, &new_items).await? else {
+    if let Some(chat_id) = chat {
+      bot
+        .send_message(chat_id, "🔔 No users are registered to receive the update.")
+        .await?;
+    }
+    return Ok("🔔 No users registered.");
+  };
+
+  if let Some(chat_id) = chat {
+    bot
+      .send_message(
+        chat_id,
+        format!("🔔 Notified {delivered} user(s) about {} new lot(s).", new_items.len()),
+      )
+      .await?;
+  }
+  Ok("🔔 Notification sent.")
+}
+
+/// Announces lots to every user who takes notifications and clears their new flags. Returns how many users got the
+/// announcement, or `None` if nobody is registered to receive it, in which case the flags stay set.
+pub async fn announce_new_lots(
+  bot: &Bot,
+  ctx: &SharedContext,
+  actor_tg_id: i64,
+  new_items: &[ItemRow],
+) -> Result<Option<usize>> {
+  let user_ids = ctx.db().list_user_ids().await?;
+  if user_ids.is_empty() {
+    return Ok(None);
+  }
+
+  // Rendered once per locale, indexed by the `Locale` discriminant.
+  let announcements: Vec<String> = Locale::ALL
+    .into_iter()
+    .map(|locale| {
+      let mut announcement = format!("{}\n\n", tr!(locale, "notify-new-lots"));
+      for item in new_items {
+        let line = tr!(
+          locale,
+          "notify-new-lot",
+          item_id = item.id,
+          title = item.title.as_str(),
+          price = ctx.item_currency(item).format(item.start_price)
+        );
+        announcement.push_str(&line);
+        announcement.push('\n');
+      }
+      announcement
+    })
+    .collect();
+  let messages: Vec<(i64, &str)> = ctx
+    .db()
+    .list_users(&user_ids)
+    .await?
+    .iter()
+    .map(|user| {
+      let locale = Locale::resolve(user.preferred_language());
+      (user.id, announcements[locale as usize].as_str())
+    })
+    .collect();
+
+  info!(
+    actor_tg_id,
+    lot_count = new_items.len(),
+    recipient_count = user_ids.len(),
+    "broadcasting new lots"
+  );
+  let delivered = deliver_broadcast(bot, ctx, &messages, None).await;
+  let ids: Vec<i64> = new_items.iter().map(|item| item.id).collect();
+  ctx.db().clear_new_item_flags(&ids).await?;
+  audit::record(
+    ctx,
+    actor_tg_id,
+    AuditAction::NewLotsNotified,
+    AuditTarget::Users,
+    json!({ "item_ids": ids, "recipients": user_ids.len(), "delivered": delivered }),
+  )
+  .await;
+  Ok(Some(delivered))
+}
+
+#[instrument(skip(bot, dialogue, ctx, query))]
+async fn handle_pick_category_callback(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  pick: CategoryPick,
+) -> HandlerResult {
+  let user_id = query.from.id.0 as i64;
+  let Some((chat_id, _)) = callback_message(&query) else {
+    return callback::answer(&bot, &query, None).await;
+  };
+
+  let text = match pick {
+    CategoryPick::New => {
+      let state = dialogue.get().await?;
+      if !matches!(state, Some(ConversationState::AddItem(_))) {
+        dialogue
+          .update(ConversationState::AddItem(AddItemDraft::new(user_id, None)))
+          .await?;
+      }
+      bot
+        .send_message(chat_id, "🆕 Send the new category name (or type cancel).")
+        .await?;
+      "🆕 Waiting for category name.".to_string()
+    },
+    CategoryPick::Existing(category_id) => {
+      let categories = ctx.db().list_categories().await?;
+      if let Some(category) = categories.into_iter().find(|c| c.id == category_id) {
+        let mut draft = match dialogue.get().await? {
+          Some(ConversationState::AddItem(draft)) => draft,
+          _ => AddItemDraft::new(user_id, None),
+        };
+        draft.category_id = Some(category.id);
+        draft.category_name = Some(category.name);
+        draft.stage = DraftStage::Title;
+        dialogue.update(ConversationState::AddItem(draft)).await?;
+        bot.send_message(chat_id, "📝 Enter item title:").await?;
+        "🗂️ Category selected.".to_string()
+      } else {
+        tr!(user_locale(&ctx, user_id).await?, "category-not-found")
+      }
+    },
+  };
+  callback::answer(&bot, &query, Some(text)).await
+}
+
+#[instrument(skip(bot, ctx, query))]
+async fn handle_category_callback(
+  bot: Bot,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  category_id: i64,
+) -> HandlerResult {
+  let locale = user_locale(&ctx, query.from.id.0 as i64).await?;
+  let mut text = None;
+  if let Some((chat_id, message_id)) = callback_message(&query) {
+    let categories = ctx.db().list_categories().await?;
+    if let Some(category) = categories.into_iter().find(|c| c.id == category_id) {
+      show_category_items_menu(&bot, &ctx, chat_id, message_id, &category, locale).await?;
+    } else {
+      text = Some(tr!(locale, "category-not-found"));
+    }
+  }
+  callback::answer(&bot, &query, text).await
+}
 
-  if let Some(text) = callback_text {
-    bot.answer_callback_query(query.id).text(text).await?;
+#[instrument(skip(bot, ctx, query))]
+async fn handle_back_callback(bot: Bot, ctx: SharedContext, query: CallbackQuery) -> HandlerResult {
+  if let Some((chat_id, message_id)) = callback_message(&query) {
+    let locale = user_locale(&ctx, query.from.id.0 as i64).await?;
+    show_catalogue_menu(&bot, &ctx, chat_id, message_id, locale).await?;
+  }
+  callback::answer(&bot, &query, None).await
+}
+
+#[instrument(skip(bot, ctx, query))]
+async fn handle_item_callback(bot: Bot, ctx: SharedContext, query: CallbackQuery, item_id: i64) -> HandlerResult {
+  let user_id = query.from.id.0 as i64;
+  let mut text = None;
+  if let Some((chat_id, _)) = callback_message(&query)
+    && !send_item(&bot, &ctx, chat_id, item_id, Some(user_id)).await?
+  {
+    text = Some(tr!(user_locale(&ctx, user_id).await?, "item-not-found"));
+  }
+  callback::answer(&bot, &query, text).await
+}
+
+#[instrument(skip(bot, ctx, query))]
+async fn handle_more_images_callback(
+  bot: Bot,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  (item_id, offset): (i64, usize),
+) -> HandlerResult {
+  let Some((chat_id, message_id)) = callback_message(&query) else {
+    return callback::answer(&bot, &query, None).await;
+  };
+  let locale = user_locale(&ctx, query.from.id.0 as i64).await?;
+  let images = ctx.db().list_item_images(item_id).await?;
+  let total = images.len();
+
+  let (status, keyboard, text) = if offset >= total {
+    (
+      tr!(locale, "images-all-shown"),
+      InlineKeyboardMarkup::default(),
+      tr!(locale, "images-all-already-shown"),
+    )
   } else {
-    bot.answer_callback_query(query.id).await?;
+    let next = send_item_images_chunk(&bot, &ctx, chat_id, &images, offset, None).await?;
+    if next < total {
+      let remaining = total - next;
+      let keyboard = InlineKeyboardMarkup::new(vec![vec![
+        CallbackData::MoreImages { item_id, offset: next }.button(tr!(locale, "images-show-more", count = remaining)),
+      ]]);
+      (
+        tr!(locale, "images-more", count = remaining),
+        keyboard,
+        tr!(locale, "images-sent-more"),
+      )
+    } else {
+      (
+        tr!(locale, "images-all-shown"),
+        InlineKeyboardMarkup::default(),
+        tr!(locale, "images-sent-more"),
+      )
+    }
+  };
+  let request = bot
+    .edit_message_text(chat_id, message_id, status)
+    .reply_markup(keyboard);
+  if let Err(err) = request.await
+    && !matches!(err, RequestError::Api(ApiError::MessageNotModified))
+  {
+    return Err(err.into());
   }
-  Ok(())
+  callback::answer(&bot, &query, Some(text)).await
+}
+
+#[instrument(skip(bot, dialogue, ctx, query))]
+async fn handle_bid_callback(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  item_id: i64,
+) -> HandlerResult {
+  let user_id = query.from.id.0 as i64;
+  let locale = user_locale(&ctx, user_id).await?;
+  let text = match ctx.db().get_item(item_id).await? {
+    Some(item) if item.is_open => {
+      dialogue
+        .update(ConversationState::PlaceBid(BidDraft {
+          item_id,
+          bidder_tg_id: user_id,
+        }))
+        .await?;
+      if let Some((chat_id, _)) = callback_message(&query) {
+        bot
+          .send_message(chat_id, tr!(locale, "bid-prompt", item_id = item_id))
+          .await?;
+      }
+      None
+    },
+    Some(_) => Some(tr!(locale, "item-closed")),
+    None => Some(tr!(locale, "item-not-found")),
+  };
+  callback::answer(&bot, &query, text).await
+}
+
+#[instrument(skip(bot, ctx, query))]
+async fn handle_favorite_callback(
+  bot: Bot,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  (action, item_id): (FavoriteAction, i64),
+) -> HandlerResult {
+  let user_id = query.from.id.0 as i64;
+  let locale = user_locale(&ctx, user_id).await?;
+  let text = match action {
+    FavoriteAction::Add => {
+      ctx.db().add_favorite(user_id, item_id).await?;
+      tr!(locale, "favorite-added")
+    },
+    FavoriteAction::Remove => {
+      ctx.db().remove_favorite(user_id, item_id).await?;
+      tr!(locale, "favorite-removed")
+    },
+  };
+
+  if let Some((chat_id, message_id)) = callback_message(&query)
+    && let Some(item) = ctx.db().get_item(item_id).await?
+  {
+    let viewer = build_item_viewer_context(&ctx, item_id, user_id).await?;
+    let best = ctx.db().best_bid_for_item(item_id).await?;
+    let keyboard = item_action_keyboard(&item, ctx.item_currency(&item), best, Some(&viewer));
+    if let Err(err) = bot
+      .edit_message_reply_markup(chat_id, message_id)
+      .reply_markup(keyboard)
+      .await
+      && !matches!(err, RequestError::Api(ApiError::MessageNotModified))
+    {
+      return Err(err.into());
+    }
+  }
+  callback::answer(&bot, &query, Some(text)).await
+}
+
+#[instrument(skip(bot, ctx, query))]
+async fn handle_settings_callback(
+  bot: Bot,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  action: SettingsAction,
+) -> HandlerResult {
+  let user_id = query.from.id.0 as i64;
+  let message = callback_message(&query);
+  let text = match action {
+    SettingsAction::ToggleNotifications => {
+      let next = !ctx.db().notifications_disabled(user_id).await?;
+      ctx.db().set_notifications_disabled(user_id, next).await?;
+      if let Some((chat_id, message_id)) = message {
+        show_settings_menu(&bot, &ctx, chat_id, message_id, user_id).await?;
+      }
+      let locale = user_locale(&ctx, user_id).await?;
+      Some(if next {
+        tr!(locale, "settings-notifications-muted")
+      } else {
+        tr!(locale, "settings-notifications-enabled")
+      })
+    },
+    SettingsAction::ChooseLanguage => {
+      if let Some((chat_id, message_id)) = message {
+        show_language_menu(&bot, &ctx, chat_id, message_id, user_id).await?;
+      }
+      None
+    },
+    SettingsAction::SetLocale(chosen) => {
+      ctx.db().set_user_locale(user_id, chosen.map(Locale::code)).await?;
+      info!(
+        user_id,
+        locale = chosen.map_or("auto", Locale::code),
+        "changed language"
+      );
+      if let Some((chat_id, message_id)) = message {
+        show_settings_menu(&bot, &ctx, chat_id, message_id, user_id).await?;
+      }
+      Some(tr!(user_locale(&ctx, user_id).await?, "settings-language-updated"))
+    },
+  };
+  callback::answer(&bot, &query, text).await
 }
 
 async fn update_categories_menu(
@@ -1300,12 +1803,13 @@ async fn update_categories_menu(
   ctx: &SharedContext,
   chat: ChatId,
   message_id: MessageId,
+  locale: Locale,
 ) -> HandlerResult {
   let categories = ctx.db().list_categories().await?;
   if categories.is_empty() {
     let request = bot
-      .edit_message_text(chat, message_id, "🗂️ No categories yet. Check back soon.")
-      .reply_markup(main_menu_only_keyboard());
+      .edit_message_text(chat, message_id, tr!(locale, "catalogue-empty"))
+      .reply_markup(main_menu_only_keyboard(locale));
     match request.await {
       Ok(_) => info!(chat_id = %chat, message_id = %message_id, "rendered empty categories menu"),
       Err(RequestError::Api(ApiError::MessageNotModified)) => {
@@ -1315,9 +1819,9 @@ async fn update_categories_menu(
       Err(err) => return Err(err.into()),
     }
   } else {
-    let keyboard = build_categories_keyboard(&categories);
+    let keyboard = build_categories_keyboard(&categories, locale);
     let request = bot
-      .edit_message_text(chat, message_id, "🗂️ Choose a category:")
+      .edit_message_text(chat, message_id, tr!(locale, "catalogue-choose"))
       .reply_markup(keyboard);
     match request.await {
       Ok(_) => info!(chat_id = %chat, message_id = %message_id, count = categories.len(), "rendered categories menu"),
@@ -1336,17 +1840,23 @@ async fn show_category_items_menu(
   ctx: &SharedContext,
   chat: ChatId,
   message_id: MessageId,
-  category_id: i64,
-  category_name: &str,
+  category: &CategoryRow,
+  locale: Locale,
 ) -> HandlerResult {
-  let items = ctx.db().list_items_by_category(category_id).await?;
+  let category_id = category.id;
+  let mut items = ctx.db().list_items_by_category(category_id).await?;
+  items.retain(ItemRow::is_published);
   info!(category_id, count = items.len(), chat_id = %chat, "rendering category items menu");
-  let text = if items.is_empty() {
-    format!("🗂️ Category: {category_name}\n📭 No items in this category yet.")
+  let hint = if items.is_empty() {
+    tr!(locale, "category-empty")
   } else {
-    format!("🗂️ Category: {category_name}\n🛍️ Select an item:")
+    tr!(locale, "category-select")
   };
-  let keyboard = build_items_keyboard(ctx, &items).await;
+  let text = format!(
+    "{}\n{hint}",
+    tr!(locale, "category-header", name = category.name.as_str())
+  );
+  let keyboard = build_items_keyboard(ctx, &items, locale).await;
   let request = bot.edit_message_text(chat, message_id, text).reply_markup(keyboard);
   match request.await {
     Ok(_) => info!(category_id, chat_id = %chat, message_id = %message_id, "rendered category items menu"),
@@ -1359,26 +1869,25 @@ async fn show_category_items_menu(
   Ok(())
 }
 
-fn build_categories_keyboard(categories: &[CategoryRow]) -> InlineKeyboardMarkup {
+fn build_categories_keyboard(categories: &[CategoryRow], locale: Locale) -> InlineKeyboardMarkup {
   let mut rows = categories
     .chunks(2)
     .map(|row| {
       row
         .iter()
-        .map(|category| InlineKeyboardButton::callback(category.name.clone(), format!("cat:{}", category.id)))
+        .map(|category| CallbackData::Category(category.id).button(category.name.clone()))
         .collect::<Vec<_>>()
     })
     .collect::<Vec<_>>();
 
-  rows.push(vec![InlineKeyboardButton::callback(
-    "⬅️ Main menu",
-    "menu:root".to_string(),
-  )]);
+  rows.push(vec![
+    CallbackData::Menu(MenuTarget::Root).button(tr!(locale, "menu-main")),
+  ]);
 
   InlineKeyboardMarkup::new(rows)
 }
 
-async fn build_items_keyboard(ctx: &SharedContext, items: &[ItemRow]) -> InlineKeyboardMarkup {
+async fn build_items_keyboard(ctx: &SharedContext, items: &[ItemRow], locale: Locale) -> InlineKeyboardMarkup {
   use futures::future::join_all;
 
   let bids = join_all(items.iter().map(|it| ctx.db().best_bid_for_item(it.id))).await;
@@ -1394,17 +1903,17 @@ async fn build_items_keyboard(ctx: &SharedContext, items: &[ItemRow]) -> InlineK
     let mut label = format!(
       "{}{} — {}",
       if item.is_open { "" } else { "🔴 " },
-      format_cents(price_cents),
+      ctx.item_currency(item).format(price_cents),
       &item.title
     );
-    label = truncate_button_text(&label, 48);
+    label = truncate_button_text(&label, ctx.auction().button_label_chars);
 
-    rows.push(vec![InlineKeyboardButton::callback(label, format!("item:{}", item.id))]);
+    rows.push(vec![CallbackData::Item(item.id).button(label)]);
   }
 
   rows.push(vec![
-    InlineKeyboardButton::callback("⬅️ Categories".to_string(), "back:categories".to_string()),
-    InlineKeyboardButton::callback("⬅️ Main menu".to_string(), "menu:root".to_string()),
+    CallbackData::BackToCategories.button(tr!(locale, "menu-categories")),
+    CallbackData::Menu(MenuTarget::Root).button(tr!(locale, "menu-main")),
   ]);
 
   InlineKeyboardMarkup::new(rows)
@@ -1424,21 +1933,29 @@ fn truncate_button_text(text: &str, max_chars: usize) -> String {
   format!("{truncated}...")
 }
 
-struct ItemViewerContext {
+pub(super) struct ItemViewerContext {
   is_favorite: bool,
   user_best_bid: Option<i64>,
+  is_admin: bool,
+  locale: Locale,
 }
 
-async fn build_item_viewer_context(ctx: &SharedContext, item_id: i64, user_id: i64) -> Result<ItemViewerContext> {
+pub(super) async fn build_item_viewer_context(
+  ctx: &SharedContext,
+  item_id: i64,
+  user_id: i64,
+) -> Result<ItemViewerContext> {
   let is_favorite = ctx.db().is_favorite(user_id, item_id).await?;
   let user_best_bid = ctx.db().user_best_bid_for_item(item_id, user_id).await?;
   Ok(ItemViewerContext {
     is_favorite,
     user_best_bid,
+    is_admin: ctx.is_admin(user_id),
+    locale: user_locale(ctx, user_id).await?,
   })
 }
 
-async fn send_item(
+pub(super) async fn send_item(
   bot: &Bot,
   ctx: &SharedContext,
   chat: ChatId,
@@ -1448,19 +1965,25 @@ async fn send_item(
   let Some(item) = ctx.db().get_item(item_id).await? else {
     return Ok(false);
   };
+  if (!item.is_published() || item.is_archived()) && !viewer_id.is_some_and(|user_id| ctx.is_admin(user_id)) {
+    return Ok(false);
+  }
   let best = ctx.db().best_bid_for_item(item_id).await?;
   let viewer_ctx = match viewer_id {
     Some(user_id) => Some(build_item_viewer_context(ctx, item_id, user_id).await?),
     None => None,
   };
-  let text = render_item_message(&item, best, viewer_ctx.as_ref());
-  let keyboard = item_action_keyboard(item.id, item.is_open, viewer_ctx.as_ref());
+  let text = render_item_message(&item, ctx.item_currency(&item), best, viewer_ctx.as_ref());
+  let keyboard = item_action_keyboard(&item, ctx.item_currency(&item), best, viewer_ctx.as_ref());
 
-  bot
+  let card = bot
     .send_message(chat, text.clone())
     .parse_mode(ParseMode::MarkdownV2)
     .reply_markup(keyboard)
     .await?;
+  if let Err(err) = ctx.db().record_item_card(chat.0, card.id.0, item.id, viewer_id).await {
+    warn!(error = %err, item_id, chat_id = %chat, "failed to remember item card");
+  }
 
   let mut images = ctx.db().list_item_images(item.id).await?;
   if images.is_empty()
@@ -1470,17 +1993,19 @@ async fn send_item(
   }
 
   if !images.is_empty() {
-    let next_offset = send_item_images_chunk(bot, chat, &images, 0, None).await?;
+    let next_offset = send_item_images_chunk(bot, ctx, chat, &images, 0, None).await?;
     if next_offset < images.len() {
-      send_more_images_prompt(bot, chat, item.id, next_offset, images.len()).await?;
+      let locale = viewer_ctx.as_ref().map_or_else(Locale::default, |viewer| viewer.locale);
+      send_more_images_prompt(bot, chat, item.id, next_offset, images.len(), locale).await?;
     }
   }
 
   Ok(true)
 }
 
-async fn send_item_images_chunk(
+pub(super) async fn send_item_images_chunk(
   bot: &Bot,
+  ctx: &AppContext,
   chat: ChatId,
   images: &[FileId],
   start: usize,
@@ -1490,7 +2015,7 @@ async fn send_item_images_chunk(
     return Ok(start);
   }
 
-  let end = (start + MEDIA_GROUP_BATCH).min(images.len());
+  let end = (start + ctx.auction().media_group_size).min(images.len());
   let mut media = Vec::new();
   for (index, file_id) in images[start .. end].iter().enumerate() {
     let mut photo = InputMediaPhoto::new(InputFile::file_id(file_id.clone()));
@@ -1513,21 +2038,31 @@ async fn send_more_images_prompt(
   item_id: i64,
   next_offset: usize,
   total: usize,
+  locale: Locale,
 ) -> HandlerResult {
   let remaining = total.saturating_sub(next_offset);
-  let text = format!("📷 {remaining} more photo(s) available.");
-  let keyboard = InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(
-    format!("Show more images ({remaining})"),
-    format!("img:{item_id}:{next_offset}"),
-  )]]);
+  let text = tr!(locale, "images-more", count = remaining);
+  let keyboard = InlineKeyboardMarkup::new(vec![vec![
+    CallbackData::MoreImages {
+      item_id,
+      offset: next_offset,
+    }
+    .button(tr!(locale, "images-show-more", count = remaining)),
+  ]]);
   bot.send_message(chat, text).reply_markup(keyboard).await?;
   Ok(())
 }
 
-fn render_item_message(item: &ItemRow, best: Option<i64>, viewer: Option<&ItemViewerContext>) -> String {
+pub(super) fn render_item_message(
+  item: &ItemRow,
+  currency: Currency,
+  best: Option<i64>,
+  viewer: Option<&ItemViewerContext>,
+) -> String {
+  let locale = viewer.map_or_else(Locale::default, |viewer| viewer.locale);
   let escaped_id = markdown::escape(&format!("#{}", item.id));
   let escaped_title = markdown::escape(&item.title);
-  let escaped_start = markdown::escape(&format_cents(item.start_price));
+  let line = |text: String| format!("\n{}", markdown::escape(&text));
 
   let mut text = format!("🔨 *{}* — *{}*", escaped_id, escaped_title);
 
@@ -1538,71 +2073,140 @@ fn render_item_message(item: &ItemRow, best: Option<i64>, viewer: Option<&ItemVi
     text.push_str(&format!("\n\n{}", escaped_description));
   }
 
-  text.push_str(&format!("\n\n💰 Start: {}", escaped_start));
+  text.push('\n');
+  text.push_str(&line(tr!(
+    locale,
+    "item-start",
+    price = currency.format(item.start_price)
+  )));
+  if let Some(original_id) = item.relisted_from_id {
+    text.push_str(&line(tr!(locale, "item-relisted-from", item_id = original_id)));
+  }
 
   if let Some(best_bid) = best {
-    let escaped_best = markdown::escape(&format_cents(best_bid));
-    text.push_str(&format!("\n🏆 Current best: {}", escaped_best));
+    text.push_str(&line(tr!(locale, "item-best", price = currency.format(best_bid))));
   }
 
   if let Some(viewer_ctx) = viewer {
     if let Some(user_bid) = viewer_ctx.user_best_bid {
-      let line = markdown::escape(&format!("🎯 Your top bid: {}", format_cents(user_bid)));
-      text.push_str(&format!("\n{}", line));
+      text.push_str(&line(tr!(locale, "item-your-bid", price = currency.format(user_bid))));
     }
     if viewer_ctx.is_favorite {
-      let line = markdown::escape("⭐ Saved to favorites");
-      text.push_str(&format!("\n{}", line));
+      text.push_str(&line(tr!(locale, "item-favorited")));
     }
   }
 
   if item.is_new {
-    let line = markdown::escape("🆕 Newly listed");
-    text.push_str(&format!("\n{}", line));
+    text.push_str(&line(tr!(locale, "item-new")));
   }
 
-  text.push_str(&format!(
-    "\n📦 Status: {}",
-    if item.is_open { "OPEN" } else { "CLOSED" }
-  ));
+  text.push_str(&line(if item.is_open {
+    tr!(locale, "item-status-open")
+  } else {
+    tr!(locale, "item-status-closed")
+  }));
+  if item.is_archived() {
+    text.push_str(&line(tr!(locale, "item-archived")));
+  }
+  if let Some(publish_at) = item.publish_at {
+    let time = publish_at.format(scheduler::TIME_FORMAT).to_string();
+    text.push_str(&line(tr!(locale, "item-scheduled", time = time)));
+  }
+  if let Some(closes_at) = item.closes_at.filter(|_| item.is_open) {
+    let time = closes_at.format(scheduler::TIME_FORMAT).to_string();
+    text.push_str(&line(tr!(locale, "item-closes", time = time)));
+  }
   text
 }
 
-fn item_action_keyboard(item_id: i64, open: bool, viewer: Option<&ItemViewerContext>) -> InlineKeyboardMarkup {
+pub(super) fn item_action_keyboard(
+  item: &ItemRow,
+  currency: Currency,
+  best: Option<i64>,
+  viewer: Option<&ItemViewerContext>,
+) -> InlineKeyboardMarkup {
+  let locale = viewer.map_or_else(Locale::default, |viewer| viewer.locale);
+  let accepts_bids = item.is_open && !item.is_archived() && item.is_published();
+  let mut rows = Vec::new();
+  if accepts_bids {
+    rows.push(quick_bid::quick_bid_row(item, currency, best));
+  }
+
   let mut row = Vec::new();
-  if open {
-    row.push(InlineKeyboardButton::callback("💸 Place bid", format!("bid:{item_id}")));
+  if accepts_bids {
+    row.push(CallbackData::Bid(item.id).button(tr!(locale, "item-place-bid")));
   }
 
   if let Some(viewer_ctx) = viewer {
     let (label, action) = if viewer_ctx.is_favorite {
-      ("❌ Remove favorite", "fav:remove")
+      (tr!(locale, "item-remove-favorite"), FavoriteAction::Remove)
     } else {
-      ("⭐ Add favorite", "fav:add")
+      (tr!(locale, "item-add-favorite"), FavoriteAction::Add)
     };
-    row.push(InlineKeyboardButton::callback(
-      label.to_string(),
-      format!("{action}:{item_id}"),
-    ));
+    row.push(
+      CallbackData::Favorite {
+        action,
+        item_id: item.id,
+      }
+      .button(label),
+    );
+  }
+
+  if !row.is_empty() {
+    rows.push(row);
+  }
+
+  if viewer.is_some_and(|viewer_ctx| viewer_ctx.is_admin) {
+    let mut admin_row =
+      vec![CallbackData::History(HistoryView::Open { item_id: item.id }).button(tr!(locale, "history-open"))];
+    if item.is_archived() {
+      admin_row.push(archive::restore_item_button(item.id));
+    }
+    rows.push(admin_row);
+    if !item.is_open {
+      let mut closed_row = vec![second_chance::offer_button(item.id)];
+      if !item.is_archived() {
+        closed_row.push(relist::relist_button(item.id));
+      }
+      rows.push(closed_row);
+    }
   }
 
-  if row.is_empty() {
+  if rows.is_empty() {
     InlineKeyboardMarkup::default()
   } else {
-    InlineKeyboardMarkup::new(vec![row])
+    InlineKeyboardMarkup::new(rows)
   }
 }
 
-async fn broadcast_text(bot: &Bot, user_ids: &[i64], text: &str, entities: Option<&[MessageEntity]>) -> usize {
+/// Sends `text` to every user and returns how many got it. Raises an alert when a noticeable share of deliveries
+/// fail.
+pub async fn broadcast_text(
+  bot: &Bot,
+  ctx: &SharedContext,
+  user_ids: &[i64],
+  text: &str,
+  entities: Option<&[MessageEntity]>,
+) -> usize {
+  let messages: Vec<(i64, &str)> = user_ids.iter().map(|user_id| (*user_id, text)).collect();
+  deliver_broadcast(bot, ctx, &messages, entities).await
+}
+
+/// Sends each user their own text and returns how many got it.
+async fn deliver_broadcast(
+  bot: &Bot,
+  ctx: &SharedContext,
+  messages: &[(i64, &str)],
+  entities: Option<&[MessageEntity]>,
+) -> usize {
   let mut delivered = 0usize;
-  let payload = text.to_string();
   let entity_payload = entities.map(|data| data.to_vec());
-  for user_id in user_ids {
-    let mut request = bot.send_message(ChatId(*user_id), payload.clone());
+  for (user_id, text) in messages {
+    let mut request = bot.send_message(ChatId(*user_id), *text);
     if let Some(entities) = &entity_payload {
       request = request.entities(entities.clone());
     }
-    match request.await {
+    match metrics::notification("broadcast", request).await {
       Ok(_) => {
         delivered += 1;
       },
@@ -1611,6 +2215,17 @@ async fn broadcast_text(bot: &Bot, user_ids: &[i64], text: &str, entities: Optio
       },
     }
   }
+  let failed = messages.len() - delivered;
+  let thresholds = ctx.notifications();
+  if failed >= thresholds.broadcast_alert_min_failures
+    && failed * 100 >= messages.len() * thresholds.broadcast_alert_failure_percent
+  {
+    let alert = format!(
+      "📉 Broadcast delivery failures: {failed} of {} message(s) were not delivered.",
+      messages.len()
+    );
+    ctx.alerts().raise(bot, "broadcast:failures", alert).await;
+  }
   delivered
 }
 
@@ -1635,16 +2250,19 @@ async fn notify_outbid_user(
     new_bidder.first_name.clone()
   };
 
-  let message = format!(
-    "⚠️ Your bid of {} on item #{} ({}) was beaten by {}. New highest bid: {}.",
-    format_cents(previous_amount_cents),
-    item.id,
-    item.title,
-    bidder_label,
-    format_cents(new_amount_cents),
+  let currency = ctx.item_currency(item);
+  let locale = user_locale(ctx, previous_bidder_id).await?;
+  let message = tr!(
+    locale,
+    "notify-outbid",
+    previous = currency.format(previous_amount_cents),
+    item_id = item.id,
+    title = item.title.as_str(),
+    bidder = bidder_label,
+    amount = currency.format(new_amount_cents),
   );
 
-  bot.send_message(ChatId(previous_bidder_id), message).await?;
+  metrics::notification("outbid", bot.send_message(ChatId(previous_bidder_id), message)).await?;
   Ok(())
 }
 
@@ -1668,27 +2286,29 @@ async fn notify_item_closed(bot: &Bot, ctx: &SharedContext, item: &ItemRow) -> R
     return Ok(());
   }
 
+  let currency = ctx.item_currency(item);
   for user_id in recipients {
+    let locale = user_locale(ctx, user_id).await?;
+    let title = item.title.as_str();
     let text = match winning_bid {
-      Some((winner_id, amount)) if user_id == winner_id => format!(
-        "🏁 Auction closed for item #{} ({}).\n\n🎉 Congratulations! You won with a bid of {}.",
-        item.id,
-        item.title,
-        format_cents(amount),
+      Some((winner_id, amount)) if user_id == winner_id => tr!(
+        locale,
+        "notify-closed-won",
+        item_id = item.id,
+        title = title,
+        amount = currency.format(amount),
       ),
-      Some((_, amount)) => format!(
-        "🏁 Auction closed for item #{} ({}).\nFinal price: {}. Thanks for taking part!",
-        item.id,
-        item.title,
-        format_cents(amount),
-      ),
-      None => format!(
-        "🏁 Auction closed for item #{} ({}).\nThe item closed with no bids.",
-        item.id, item.title,
+      Some((_, amount)) => tr!(
+        locale,
+        "notify-closed-final",
+        item_id = item.id,
+        title = title,
+        amount = currency.format(amount),
       ),
+      None => tr!(locale, "notify-closed-no-bids", item_id = item.id, title = title),
     };
 
-    if let Err(err) = bot.send_message(ChatId(user_id), text).await {
+    if let Err(err) = metrics::notification("item_closed", bot.send_message(ChatId(user_id), text)).await {
       warn!(error = %err, item_id = item.id, user_id, "failed to notify user about item closure");
     }
   }
@@ -1696,7 +2316,7 @@ async fn notify_item_closed(bot: &Bot, ctx: &SharedContext, item: &ItemRow) -> R
   Ok(())
 }
 
-async fn ensure_user_record(ctx: &SharedContext, user: &User) -> Result<()> {
+pub(super) async fn ensure_user_record(ctx: &SharedContext, user: &User) -> Result<()> {
   ctx
     .db()
     .upsert_user(
@@ -1704,41 +2324,99 @@ async fn ensure_user_record(ctx: &SharedContext, user: &User) -> Result<()> {
       user.username.clone(),
       Some(user.first_name.clone()),
       user.last_name.clone(),
+      user.language_code.clone(),
     )
     .await
     .context("failed to upsert user record")
 }
 
+/// The locale to talk to a user in: their choice from settings, then the language Telegram last reported.
+pub(super) async fn user_locale(ctx: &SharedContext, user_id: i64) -> Result<Locale> {
+  let user = ctx.db().get_user(user_id).await?;
+  Ok(Locale::resolve(user.as_ref().and_then(UserRow::preferred_language)))
+}
+
+async fn message_locale(ctx: &SharedContext, msg: &Message) -> Result<Locale> {
+  match msg.from.as_ref() {
+    Some(user) => user_locale(ctx, user.id.0 as i64).await,
+    None => Ok(Locale::default()),
+  }
+}
+
 async fn notify_seller(bot: &Bot, ctx: &SharedContext, item: &ItemRow, user: &User, amount_cents: i64) -> Result<()> {
   if ctx.db().notifications_disabled(item.seller_tg_id).await? {
     return Ok(());
   }
 
   let username = user.username.clone().unwrap_or_else(|| user.id.0.to_string());
-  bot
-    .send_message(
+  let locale = user_locale(ctx, item.seller_tg_id).await?;
+  metrics::notification(
+    "seller_bid",
+    bot.send_message(
       ChatId(item.seller_tg_id),
-      format!(
-        "New bid on item #{} ({}): @{} offered {}",
-        item.id,
-        item.title,
-        username,
-        format_cents(amount_cents),
+      tr!(
+        locale,
+        "notify-seller-bid",
+        item_id = item.id,
+        title = item.title.as_str(),
+        bidder = username,
+        amount = ctx.item_currency(item).format(amount_cents),
       ),
-    )
-    .await?;
+    ),
+  )
+  .await?;
   Ok(())
 }
 
-async fn ensure_category(ctx: &SharedContext, name: &str) -> Result<(CategoryRow, bool)> {
+/// A description as entered in the add-item flow, where '-' skips it.
+pub(super) fn draft_description(text: Option<&str>) -> Option<String> {
+  text
+    .map(str::trim)
+    .filter(|value| !value.is_empty() && *value != "-")
+    .map(str::to_string)
+}
+
+/// Splits an optional currency override off a category name, as in `Watches [USD]`.
+pub(super) fn split_category_currency(input: &str) -> Result<(&str, Option<Currency>), String> {
+  let input = input.trim();
+  let Some((name, code)) = input.strip_suffix(']').and_then(|rest| rest.rsplit_once('[')) else {
+    return Ok((input, None));
+  };
+  let currency = Currency::from_code(code).ok_or_else(|| {
+    let supported: Vec<&str> = Currency::SUPPORTED.iter().map(|currency| currency.code).collect();
+    format!(
+      "Unknown currency '{}'. Use one of {}.",
+      code.trim(),
+      supported.join(", ")
+    )
+  })?;
+  Ok((name.trim(), Some(currency)))
+}
+
+async fn ensure_category(
+  ctx: &SharedContext,
+  admin_tg_id: i64,
+  name: &str,
+  currency: Option<Currency>,
+) -> Result<(CategoryRow, bool)> {
   if let Some(existing) = ctx.db().find_category_by_name(name).await? {
     return Ok((existing, true));
   }
-  let id = ctx.db().create_category(name).await?;
+  let currency = currency.map(|currency| currency.code.to_string());
+  let id = ctx.db().create_category(name, currency.as_deref()).await?;
+  audit::record(
+    ctx,
+    admin_tg_id,
+    AuditAction::CategoryCreated,
+    AuditTarget::Category(id),
+    json!({ "name": name, "currency": currency }),
+  )
+  .await;
   Ok((
     CategoryRow {
       id,
       name: name.to_string(),
+      currency,
     },
     false,
   ))
@@ -1748,45 +2426,140 @@ fn message_text(msg: &Message) -> Option<&str> {
   msg.text().or_else(|| msg.caption())
 }
 
+/// Why a bid was not placed. Rejections are explained to the bidder; [`BidError::Internal`] failures are passed on to
+/// the dispatcher error handler, which replies with a reference id and alerts the admins.
 #[derive(Debug, Error)]
-enum BidError {
-  #[error(transparent)]
-  Storage(#[from] SqlxError),
+pub(super) enum BidError {
   #[error(transparent)]
   InvalidAmount(#[from] MoneyError),
   #[error(transparent)]
-  Anyhow(#[from] anyhow::Error),
+  Internal(#[from] BotError),
   #[error("item not found")]
   NotFound,
   #[error("auction is closed")]
   Closed,
-  #[error("bid must exceed {0}")]
-  TooLow(i64),
-  #[error("bid must be at least {0}")]
-  BelowStart(i64),
+  #[error("bid must exceed {}", .1.format(*.0))]
+  TooLow(i64, Currency),
+  #[error("bid must be at least {}", .1.format(*.0))]
+  BelowStart(i64, Currency),
 }
 
 impl BidError {
-  fn user_message(&self) -> String {
+  /// A stable name for the variant, used as a metrics label.
+  pub(super) fn kind(&self) -> &'static str {
+    match self {
+      Self::InvalidAmount(_) => "invalid_amount",
+      Self::Internal(_) => "internal",
+      Self::NotFound => "not_found",
+      Self::Closed => "closed",
+      Self::TooLow(..) => "too_low",
+      Self::BelowStart(..) => "below_start",
+    }
+  }
+
+  pub(super) fn user_message(&self, locale: Locale) -> String {
     match self {
-      Self::InvalidAmount(_) => "Amount must match 0.00 format".to_string(),
-      Self::NotFound => "Item not found.".to_string(),
-      Self::Closed => "Auction is closed.".to_string(),
-      Self::TooLow(value) => format!("Your bid must exceed {}.", format_cents(*value)),
-      Self::BelowStart(value) => format!("Your bid must be at least {}.", format_cents(*value)),
-      Self::Storage(_) => "Temporary error placing bid.".to_string(),
-      Self::Anyhow(e) => format!("Unhandled error: {e:?}").to_string(),
+      Self::InvalidAmount(err) => tr!(
+        locale,
+        "bid-error-invalid-amount",
+        reason = money_error_message(err, locale)
+      ),
+      Self::NotFound => tr!(locale, "bid-error-not-found"),
+      Self::Closed => tr!(locale, "bid-error-closed"),
+      Self::TooLow(value, currency) => tr!(locale, "bid-error-too-low", amount = currency.format(*value)),
+      Self::BelowStart(value, currency) => tr!(locale, "bid-error-below-start", amount = currency.format(*value)),
+      Self::Internal(_) => tr!(locale, "bid-failed"),
     }
   }
 }
 
+impl From<anyhow::Error> for BidError {
+  fn from(err: anyhow::Error) -> Self {
+    Self::Internal(err.into())
+  }
+}
+
+fn money_error_message(err: &MoneyError, locale: Locale) -> String {
+  match err {
+    MoneyError::InvalidFormat => tr!(locale, "money-invalid-format"),
+    MoneyError::Ambiguous(input) => tr!(locale, "money-ambiguous", input = input.as_str()),
+    MoneyError::TooPrecise(code) => tr!(locale, "money-too-precise", currency = *code),
+    MoneyError::WrongCurrency(code) => tr!(locale, "money-wrong-currency", currency = *code),
+    MoneyError::OutOfRange => tr!(locale, "money-out-of-range"),
+  }
+}
+
+pub(super) struct PlacedBid {
+  pub(super) amount_cents: i64,
+  pub(super) is_highest: bool,
+  pub(super) currency: Currency,
+}
+
+/// The currency bids on an item are typed in. Unknown items fall back to the deployment currency and are rejected
+/// later by the bid validation.
+pub(super) async fn bid_currency(ctx: &SharedContext, item_id: i64) -> Result<Currency> {
+  let item = ctx.db().get_item(item_id).await?;
+  Ok(ctx.currency_for(item.as_ref().and_then(|item| item.currency.as_deref())))
+}
+
+/// Counts a finished bid attempt by outcome. Bids still waiting for the bidder's confirmation are not counted yet.
+pub(super) fn record_bid_outcome(result: &Result<PlacedBid, BidError>) {
+  metrics::record_bid(match result {
+    Ok(_) => "accepted",
+    Err(err) => err.kind(),
+  });
+}
+
+/// Validates and stores a bid, then runs the shared side effects: outbid and seller notifications and the refresh of
+/// every live view of the item. Used by every bidding entry point so they all follow the same rules.
+#[instrument(skip(bot, ctx, bidder))]
+pub(super) async fn place_validated_bid(
+  bot: &Bot,
+  ctx: &SharedContext,
+  item_id: i64,
+  bidder: &User,
+  amount_cents: i64,
+) -> Result<PlacedBid, BidError> {
+  let bidder_id = bidder.id.0 as i64;
+  let (item, amount_cents, previous_best) = validate_bid(ctx, item_id, amount_cents).await?;
+  ctx.db().place_bid(item_id, bidder_id, amount_cents).await?;
+
+  let highest = ctx.db().best_bid_with_bidder(item_id).await?;
+  let is_highest = matches!(
+    highest,
+    Some((top_bidder, top_amount)) if top_bidder == bidder_id && top_amount == amount_cents
+  );
+
+  if is_highest
+    && let Some((outbid_user_id, outbid_amount)) = previous_best
+    && outbid_user_id != bidder_id
+    && let Err(err) = notify_outbid_user(bot, ctx, &item, outbid_user_id, outbid_amount, amount_cents, bidder).await
+  {
+    warn!(error = %err, item_id, outbid_user_id, "failed to notify outbid user");
+  }
+
+  let _ = notify_seller(bot, ctx, &item, bidder, amount_cents).await;
+  info!(bidder_id, item_id, amount_cents, "bid accepted");
+  cards::schedule_refresh(bot, ctx, item_id);
+
+  Ok(PlacedBid {
+    amount_cents,
+    is_highest,
+    currency: ctx.item_currency(&item),
+  })
+}
+
 async fn validate_bid(
   ctx: &SharedContext,
   item_id: i64,
-  amount: &str,
+  amount_cents: i64,
 ) -> Result<(ItemRow, i64, Option<(i64, i64)>), BidError> {
-  let amount_cents = parse_money_to_cents(amount)?;
-  let item = ctx.db().get_item(item_id).await?.ok_or(BidError::NotFound)?;
+  let item = ctx
+    .db()
+    .get_item(item_id)
+    .await?
+    .filter(|item| !item.is_archived() && item.is_published())
+    .ok_or(BidError::NotFound)?;
   if !item.is_open {
     return Err(BidError::Closed);
   }
@@ -1794,10 +2567,10 @@ async fn validate_bid(
   let previous_best = ctx.db().best_bid_with_bidder(item_id).await?;
   if let Some((_, best_amount)) = previous_best {
     if amount_cents <= best_amount {
-      return Err(BidError::TooLow(best_amount));
+      return Err(BidError::TooLow(best_amount, ctx.item_currency(&item)));
     }
   } else if amount_cents < item.start_price {
-    return Err(BidError::BelowStart(item.start_price));
+    return Err(BidError::BelowStart(item.start_price, ctx.item_currency(&item)));
   }
   Ok((item, amount_cents, previous_best))
 }
@@ -1807,57 +2580,131 @@ mod tests {
   use super::ItemViewerContext;
   use super::item_action_keyboard;
   use super::render_item_message;
+  use super::split_category_currency;
+  use crate::i18n::Locale;
   use crate::models::ItemRow;
+  use crate::util::Currency;
   use chrono::Utc;
 
   #[test]
   fn renders_keyboard_only_for_open_items() {
-    let keyboard = item_action_keyboard(1, true, None);
+    let mut item = test_item(1);
+    let keyboard = item_action_keyboard(&item, Currency::AED, None, None);
     assert!(!keyboard.inline_keyboard.is_empty());
 
-    let closed = item_action_keyboard(2, false, None);
+    item.is_open = false;
+    let closed = item_action_keyboard(&item, Currency::AED, None, None);
     assert!(closed.inline_keyboard.is_empty());
   }
 
+  #[test]
+  fn admin_keyboard_offers_bid_history() {
+    let viewer = ItemViewerContext {
+      is_favorite: false,
+      user_best_bid: None,
+      is_admin: true,
+      locale: Locale::En,
+    };
+    let keyboard = item_action_keyboard(&test_item(4), Currency::AED, None, Some(&viewer));
+    let last_row = keyboard.inline_keyboard.last().expect("admin row");
+    assert_eq!(last_row[0].text, "📜 Bid history");
+    assert_eq!(last_row.len(), 1);
+
+    let mut closed = test_item(4);
+    closed.is_open = false;
+    let keyboard = item_action_keyboard(&closed, Currency::AED, None, Some(&viewer));
+    let last_row = keyboard.inline_keyboard.last().expect("closed item row");
+    let labels: Vec<&str> = last_row.iter().map(|button| button.text.as_str()).collect();
+    assert_eq!(labels, vec!["🥈 Second chance", "♻️ Relist"]);
+  }
+
+  #[test]
+  fn archived_item_keyboard_offers_restore_instead_of_bids() {
+    let viewer = ItemViewerContext {
+      is_favorite: false,
+      user_best_bid: None,
+      is_admin: true,
+      locale: Locale::En,
+    };
+    let mut item = test_item(6);
+    item.deleted_at = Some(Utc::now());
+    let keyboard = item_action_keyboard(&item, Currency::AED, None, Some(&viewer));
+    let labels: Vec<&str> = keyboard
+      .inline_keyboard
+      .iter()
+      .flatten()
+      .map(|button| button.text.as_str())
+      .collect();
+    assert_eq!(labels, vec!["⭐ Add favorite", "📜 Bid history", "♻️ Restore"]);
+  }
+
+  #[test]
+  fn open_item_keyboard_offers_quick_bids() {
+    let keyboard = item_action_keyboard(&test_item(3), Currency::AED, Some(12_000), None);
+    let quick_row = &keyboard.inline_keyboard[0];
+    let labels: Vec<&str> = quick_row.iter().map(|button| button.text.as_str()).collect();
+    assert_eq!(labels, vec!["+AED 10", "+AED 50", "+AED 100"]);
+  }
+
+  fn test_item(id: i64) -> ItemRow {
+    ItemRow::fixture(id, "Test", 100)
+  }
+
   #[test]
   fn renders_item_text() {
     let item = ItemRow {
-      id: 1,
-      seller_tg_id: 1,
-      category_id: 1,
-      title: "Test".to_string(),
       description: Some("Description".to_string()),
-      start_price: 100,
-      image_file_id: None,
-      is_open: true,
-      is_new: false,
-      created_at: Utc::now(),
+      ..test_item(1)
     };
-    let text = render_item_message(&item, Some(150), None);
+    let text = render_item_message(&item, Currency::AED, Some(150), None);
     assert!(text.contains("#1"));
     assert!(text.contains("Current best"));
+    assert!(!text.contains("Relisted"));
+  }
+
+  #[test]
+  fn renders_link_to_original_listing() {
+    let mut item = test_item(9);
+    item.relisted_from_id = Some(4);
+    let text = render_item_message(&item, Currency::AED, None, None);
+    assert!(text.contains("♻️ Relisted from \\#4"));
   }
 
   #[test]
   fn renders_viewer_details() {
-    let item = ItemRow {
-      id: 5,
-      seller_tg_id: 1,
-      category_id: 1,
-      title: "Test".to_string(),
-      description: None,
-      start_price: 100,
-      image_file_id: None,
-      is_open: true,
-      is_new: false,
-      created_at: Utc::now(),
-    };
+    let item = test_item(5);
     let ctx = ItemViewerContext {
       is_favorite: true,
       user_best_bid: Some(125),
+      is_admin: false,
+      locale: Locale::En,
     };
-    let text = render_item_message(&item, Some(150), Some(&ctx));
+    let text = render_item_message(&item, Currency::AED, Some(150), Some(&ctx));
     assert!(text.contains("Your top bid"));
     assert!(text.contains("Saved to favorites"));
   }
+
+  #[test]
+  fn renders_item_in_viewer_locale() {
+    let viewer = ItemViewerContext {
+      is_favorite: false,
+      user_best_bid: None,
+      is_admin: false,
+      locale: Locale::Ru,
+    };
+    let text = render_item_message(&test_item(2), Currency::AED, None, Some(&viewer));
+    assert!(text.contains("Стартовая цена: AED 1\\.00"));
+    let keyboard = item_action_keyboard(&test_item(2), Currency::AED, None, Some(&viewer));
+    assert_eq!(keyboard.inline_keyboard[1][0].text, "💸 Сделать ставку");
+  }
+
+  #[test]
+  fn splits_category_currency_suffix() {
+    assert_eq!(split_category_currency("Watches"), Ok(("Watches", None)));
+    assert_eq!(
+      split_category_currency(" Watches [usd] "),
+      Ok(("Watches", Some(Currency::USD)))
+    );
+    assert!(split_category_currency("Watches [XYZ]").is_err());
+  }
 }
@@ -0,0 +1,382 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::TimeDelta;
+use chrono::Utc;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardButton;
+use teloxide::types::InlineKeyboardMarkup;
+use tracing::info;
+use tracing::instrument;
+
+use crate::bot::HandlerResult;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::context::AppContext;
+use crate::bot::handlers::user_locale;
+use crate::i18n::Locale;
+use crate::i18n::tr;
+use crate::models::BidRow;
+use crate::models::BidTotals;
+use crate::models::ItemRow;
+use crate::util::Currency;
+
+type SharedContext = Arc<AppContext>;
+
+/// Bids listed per history page.
+const HISTORY_PAGE_SIZE: usize = 10;
+
+/// Aggregates shown on top of an item's bid history. Retracted bids only count towards `retracted`.
+#[derive(Debug, Clone, PartialEq)]
+struct BidStats {
+  bids: i64,
+  retracted: i64,
+  unique_bidders: i64,
+  time_to_first_bid: Option<TimeDelta>,
+  uplift: Option<(i64, f64)>,
+}
+
+impl BidStats {
+  /// Number of history pages, retracted bids included. An item without bids still has one page.
+  fn pages(&self) -> usize {
+    ((self.bids + self.retracted) as usize)
+      .div_ceil(HISTORY_PAGE_SIZE)
+      .max(1)
+  }
+}
+
+fn summarize_bids(item: &ItemRow, totals: &BidTotals) -> BidStats {
+  let time_to_first_bid = totals
+    .first_bid_at
+    .zip(item.published_at)
+    .map(|(first, live)| first - live);
+  let uplift = totals.best.map(|best| {
+    let delta = best - item.start_price;
+    let percent = if item.start_price > 0 {
+      delta as f64 * 100.0 / item.start_price as f64
+    } else {
+      0.0
+    };
+    (delta, percent)
+  });
+
+  BidStats {
+    bids: totals.active,
+    retracted: totals.retracted,
+    unique_bidders: totals.unique_bidders,
+    time_to_first_bid,
+    uplift,
+  }
+}
+
+/// Formats a duration with its two most significant units, e.g. `2h 15m`.
+fn format_duration(duration: TimeDelta) -> String {
+  let minutes = duration.num_minutes().max(0);
+  let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+  match (days, hours, minutes) {
+    (0, 0, 0) => "<1m".to_string(),
+    (0, 0, m) => format!("{m}m"),
+    (0, h, m) => format!("{h}h {m}m"),
+    (d, h, _) => format!("{d}d {h}h"),
+  }
+}
+
+fn format_timestamp(at: DateTime<Utc>) -> String {
+  at.format("%d %b %H:%M").to_string()
+}
+
+/// Which page of an item's bid history to show. `Open` posts page one as a new message, while `Page` flips the
+/// pages of that message in place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryView {
+  Open { item_id: i64 },
+  Page { item_id: i64, page: usize },
+}
+
+/// Handles the admin-only bid history callbacks.
+#[instrument(skip(bot, ctx, query))]
+pub async fn handle_callback(bot: Bot, ctx: SharedContext, query: CallbackQuery, view: HistoryView) -> HandlerResult {
+  let text = show_view(&bot, &ctx, &query, view).await?;
+  callback::answer(&bot, &query, text).await
+}
+
+async fn show_view(
+  bot: &Bot,
+  ctx: &SharedContext,
+  query: &CallbackQuery,
+  view: HistoryView,
+) -> Result<Option<String>> {
+  let user_id = query.from.id.0 as i64;
+  if !ctx.is_admin(user_id) {
+    return Ok(Some("🛡️ Admins only.".to_string()));
+  }
+  let Some(message) = query.message.as_ref() else {
+    return Ok(None);
+  };
+
+  let (item_id, page) = match view {
+    HistoryView::Open { item_id } => (item_id, 0),
+    HistoryView::Page { item_id, page } => (item_id, page),
+  };
+
+  let Some(item) = ctx.db().get_item(item_id).await? else {
+    return Ok(Some("❓ Item not found".to_string()));
+  };
+  let stats = summarize_bids(&item, &ctx.db().item_bid_totals(item_id).await?);
+  let page = page.min(stats.pages() - 1);
+  let bids = ctx
+    .db()
+    .list_item_bids_page(item_id, HISTORY_PAGE_SIZE as i64, (page * HISTORY_PAGE_SIZE) as i64)
+    .await?;
+  let mut user_ids: Vec<i64> = bids
+    .iter()
+    .flat_map(|bid| std::iter::once(bid.bidder_tg_id).chain(bid.retracted_by))
+    .collect();
+  user_ids.sort_unstable();
+  user_ids.dedup();
+  let names: HashMap<i64, String> = ctx
+    .db()
+    .list_users(&user_ids)
+    .await?
+    .into_iter()
+    .map(|user| (user.id, user.display_name()))
+    .collect();
+
+  let locale = user_locale(ctx, user_id).await?;
+  let (text, keyboard) = render_history(locale, &item, ctx.item_currency(&item), &stats, &bids, &names, page);
+  if matches!(view, HistoryView::Open { .. }) {
+    bot.send_message(message.chat().id, text).reply_markup(keyboard).await?;
+    info!(user_id, item_id, bids = stats.bids, "opened bid history");
+    return Ok(None);
+  }
+
+  let request = bot
+    .edit_message_text(message.chat().id, message.id(), text)
+    .reply_markup(keyboard);
+  match request.await {
+    Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(None),
+    Err(err) => Err(err.into()),
+  }
+}
+
+/// Renders one page of an item's bid history. `page_bids` holds only the bids of `page`.
+fn render_history(
+  locale: Locale,
+  item: &ItemRow,
+  currency: Currency,
+  stats: &BidStats,
+  page_bids: &[BidRow],
+  names: &HashMap<i64, String>,
+  page: usize,
+) -> (String, InlineKeyboardMarkup) {
+  let name_of = |id: i64| names.get(&id).cloned().unwrap_or_else(|| format!("user {id}"));
+  let pages = stats.pages();
+
+  let mut text = tr!(locale, "history-title", item_id = item.id, title = item.title.as_str());
+  text.push_str(&format!(
+    "\n\n{}",
+    tr!(locale, "history-unique-bidders", count = stats.unique_bidders)
+  ));
+  text.push_str(&format!("\n{}", tr!(locale, "history-bids", count = stats.bids)));
+  if stats.retracted > 0 {
+    text.push_str(&format!(
+      " {}",
+      tr!(locale, "history-retracted-count", count = stats.retracted)
+    ));
+  }
+  if let Some(delay) = stats.time_to_first_bid {
+    text.push_str(&format!(
+      "\n{}",
+      tr!(locale, "history-first-bid", delay = format_duration(delay))
+    ));
+  }
+  let uplift = match stats.uplift {
+    Some((delta, percent)) => tr!(
+      locale,
+      "history-uplift",
+      amount = currency.format(delta),
+      percent = format!("{percent:+.0}%"),
+      start = currency.format(item.start_price)
+    ),
+    None => tr!(locale, "history-no-bids", start = currency.format(item.start_price)),
+  };
+  text.push_str(&format!("\n{uplift}"));
+
+  if page_bids.is_empty() {
+    return (text, InlineKeyboardMarkup::default());
+  }
+
+  text.push_str(&format!(
+    "\n\n{}",
+    tr!(locale, "history-page", page = page + 1, pages = pages)
+  ));
+  let mut retract_buttons = Vec::new();
+  for bid in page_bids {
+    text.push_str(&format!(
+      "\n#{} {} — {} — {}",
+      bid.id,
+      currency.format(bid.amount),
+      name_of(bid.bidder_tg_id),
+      format_timestamp(bid.created_at)
+    ));
+    match bid.retracted_at {
+      Some(_) => {
+        text.push_str(&format!(" {}", tr!(locale, "history-retracted")));
+        if let Some(admin) = bid.retracted_by {
+          text.push_str(&format!(
+            " {}",
+            tr!(locale, "history-retracted-by", admin = name_of(admin))
+          ));
+        }
+        if let Some(reason) = bid.retraction_reason.as_deref() {
+          text.push_str(&format!(": {reason}"));
+        }
+      },
+      None if item.is_open => {
+        retract_buttons.push(CallbackData::RetractBid(bid.id).button(format!("↩️ #{}", bid.id)))
+      },
+      None => {},
+    }
+  }
+
+  let mut rows: Vec<Vec<InlineKeyboardButton>> = retract_buttons.chunks(5).map(<[_]>::to_vec).collect();
+  let mut nav = Vec::new();
+  if page > 0 {
+    nav.push(
+      CallbackData::History(HistoryView::Page {
+        item_id: item.id,
+        page: page - 1,
+      })
+      .button(tr!(locale, "history-newer")),
+    );
+  }
+  if page + 1 < pages {
+    nav.push(
+      CallbackData::History(HistoryView::Page {
+        item_id: item.id,
+        page: page + 1,
+      })
+      .button(tr!(locale, "history-older")),
+    );
+  }
+  if !nav.is_empty() {
+    rows.push(nav);
+  }
+  (text, InlineKeyboardMarkup::new(rows))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use chrono::TimeDelta;
+  use chrono::TimeZone;
+  use chrono::Utc;
+
+  use super::format_duration;
+  use super::render_history;
+  use super::summarize_bids;
+  use crate::i18n::Locale;
+  use crate::models::BidRow;
+  use crate::models::BidTotals;
+  use crate::models::ItemRow;
+  use crate::util::Currency;
+
+  /// A lot scheduled two days before it went live.
+  fn item() -> ItemRow {
+    let created_at = Utc.with_ymd_and_hms(2025, 11, 1, 10, 0, 0).unwrap();
+    ItemRow {
+      created_at,
+      published_at: Some(created_at + TimeDelta::days(2)),
+      ..ItemRow::fixture(5, "Clock", 10_000)
+    }
+  }
+
+  fn bid(id: i64, bidder: i64, amount: i64, minutes: i64, retracted: bool) -> BidRow {
+    BidRow {
+      id,
+      item_id: 5,
+      bidder_tg_id: bidder,
+      amount,
+      created_at: item().published_at.unwrap() + TimeDelta::minutes(minutes),
+      retracted_at: retracted.then(Utc::now),
+      retracted_by: retracted.then_some(1),
+      retraction_reason: retracted.then(|| "shill".to_string()),
+    }
+  }
+
+  #[test]
+  fn summarizes_bid_totals() {
+    let totals = BidTotals {
+      active: 3,
+      retracted: 1,
+      unique_bidders: 2,
+      first_bid_at: Some(item().published_at.unwrap() + TimeDelta::minutes(135)),
+      best: Some(15_000),
+    };
+    let stats = summarize_bids(&item(), &totals);
+    assert_eq!(stats.bids, 3);
+    assert_eq!(stats.retracted, 1);
+    assert_eq!(stats.unique_bidders, 2);
+    assert_eq!(stats.time_to_first_bid, Some(TimeDelta::minutes(135)));
+    assert_eq!(stats.uplift, Some((5_000, 50.0)));
+    assert_eq!(stats.pages(), 1);
+  }
+
+  #[test]
+  fn summarizes_items_without_bids() {
+    let stats = summarize_bids(&item(), &BidTotals::default());
+    assert_eq!(stats.bids, 0);
+    assert_eq!(stats.time_to_first_bid, None);
+    assert_eq!(stats.uplift, None);
+    assert_eq!(stats.pages(), 1);
+  }
+
+  #[test]
+  fn formats_durations() {
+    assert_eq!(format_duration(TimeDelta::seconds(20)), "<1m");
+    assert_eq!(format_duration(TimeDelta::minutes(45)), "45m");
+    assert_eq!(format_duration(TimeDelta::minutes(135)), "2h 15m");
+    assert_eq!(format_duration(TimeDelta::hours(76)), "3d 4h");
+  }
+
+  #[test]
+  fn paginates_and_offers_retraction_for_active_bids() {
+    let bids: Vec<BidRow> = (1 ..= 12)
+      .rev()
+      .map(|id| bid(id, 10, 10_000 + id * 100, id, id == 12))
+      .collect();
+    let totals = BidTotals {
+      active: 11,
+      retracted: 1,
+      unique_bidders: 1,
+      first_bid_at: Some(bids[11].created_at),
+      best: Some(11_100),
+    };
+    let stats = summarize_bids(&item(), &totals);
+    let names = HashMap::from([(10, "@alice".to_string())]);
+
+    let (text, keyboard) = render_history(Locale::En, &item(), Currency::AED, &stats, &bids[.. 10], &names, 0);
+    assert!(text.contains("Page 1/2"));
+    assert!(text.contains("#12 AED 112.00 — @alice"));
+    assert!(text.contains("retracted by user 1: shill"));
+    let callbacks: Vec<String> = keyboard
+      .inline_keyboard
+      .iter()
+      .flatten()
+      .map(|button| format!("{:?}", button.kind))
+      .collect();
+    assert!(!callbacks.iter().any(|kind| kind.contains("retract:pick:12")));
+    assert!(callbacks.iter().any(|kind| kind.contains("retract:pick:11")));
+    assert!(callbacks.iter().any(|kind| kind.contains("hist:page:5:1")));
+
+    let (last, _) = render_history(Locale::En, &item(), Currency::AED, &stats, &bids[10 ..], &names, 1);
+    assert!(last.contains("Page 2/2"));
+    assert!(last.contains("#1 AED 101.00"));
+  }
+}
@@ -0,0 +1,732 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::Value;
+use serde_json::json;
+use teloxide::RequestError;
+use teloxide::dispatching::dialogue::Dialogue;
+use teloxide::net::Download;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::Document;
+use teloxide::types::FileId;
+use teloxide::types::InlineKeyboardMarkup;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::DialogueStorage;
+use crate::bot::HandlerResult;
+use crate::bot::audit;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::channel;
+use crate::bot::context::AppContext;
+use crate::bot::handlers::draft_description;
+use crate::bot::handlers::split_category_currency;
+use crate::bot::state::ConversationState;
+use crate::models::AuctionEventRow;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
+use crate::models::CategoryRow;
+use crate::models::ItemSchedule;
+use crate::models::NewItem;
+use crate::models::NewItemCategory;
+use crate::util::Currency;
+
+type SharedContext = Arc<AppContext>;
+type BotDialogue = Dialogue<ConversationState, DialogueStorage>;
+
+/// Largest import file accepted, far above what a few hundred lots take.
+const MAX_IMPORT_BYTES: u32 = 1024 * 1024;
+/// Row errors listed in the dry-run summary. The rest are only counted.
+const MAX_LISTED_ERRORS: usize = 20;
+/// Telegram file ids are far longer than this. Anything shorter is a typo or a file name.
+const MIN_FILE_ID_CHARS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportAction {
+  Confirm,
+  Cancel,
+}
+
+impl ImportAction {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Confirm => "confirm",
+      Self::Cancel => "cancel",
+    }
+  }
+
+  pub fn parse(value: &str) -> Option<Self> {
+    [Self::Confirm, Self::Cancel]
+      .into_iter()
+      .find(|action| action.as_str() == value)
+  }
+}
+
+/// A lot as read from the file, before validation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct RawLot {
+  category: String,
+  title: String,
+  description: Option<String>,
+  start_price: String,
+  photos: Vec<String>,
+  /// Auction event id, with or without a leading `#`.
+  event: String,
+}
+
+/// A file row, numbered the way the admin sees it: CSV rows by line, JSON lots by position.
+type ParsedRow = (usize, Result<RawLot, String>);
+
+fn parse_file(contents: &[u8], json: bool) -> Result<Vec<ParsedRow>, String> {
+  if json {
+    parse_json(contents)
+  } else {
+    parse_csv(contents)
+  }
+}
+
+fn parse_csv(contents: &[u8]) -> Result<Vec<ParsedRow>, String> {
+  let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(contents);
+  let headers: Vec<String> = reader
+    .headers()
+    .map_err(|err| format!("The header row could not be read: {err}"))?
+    .iter()
+    .map(|header| header.trim().to_lowercase().replace(' ', "_"))
+    .collect();
+  let column = |name: &str| headers.iter().position(|header| header == name);
+  let (Some(category), Some(title), Some(start_price)) = (column("category"), column("title"), column("start_price"))
+  else {
+    return Err("The header row needs category, title and start_price columns.".to_string());
+  };
+  let (description, photos, event) = (column("description"), column("photos"), column("event"));
+
+  let mut rows = Vec::new();
+  for record in reader.records() {
+    let record = record.map_err(|err| format!("The file is not valid CSV: {err}"))?;
+    let line = record.position().map_or(0, |position| position.line() as usize);
+    let field = |index: Option<usize>| index.and_then(|index| record.get(index)).unwrap_or_default();
+    if record.iter().all(|value| value.trim().is_empty()) {
+      continue;
+    }
+    rows.push((
+      line,
+      Ok(RawLot {
+        category: field(Some(category)).to_string(),
+        title: field(Some(title)).to_string(),
+        description: Some(field(description).to_string()),
+        start_price: field(Some(start_price)).to_string(),
+        photos: split_photos(field(photos)),
+        event: field(event).to_string(),
+      }),
+    ));
+  }
+  Ok(rows)
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonLot {
+  category: String,
+  title: String,
+  #[serde(default)]
+  description: Option<String>,
+  start_price: Value,
+  #[serde(default)]
+  photos: Value,
+  #[serde(default)]
+  event: Value,
+}
+
+fn parse_json(contents: &[u8]) -> Result<Vec<ParsedRow>, String> {
+  let lots: Vec<Value> =
+    serde_json::from_slice(contents).map_err(|err| format!("The file is not a JSON array of lots: {err}"))?;
+  Ok(
+    lots
+      .into_iter()
+      .enumerate()
+      .map(|(index, lot)| (index + 1, json_lot(lot)))
+      .collect(),
+  )
+}
+
+fn json_lot(value: Value) -> Result<RawLot, String> {
+  let lot: JsonLot = serde_json::from_value(value).map_err(|err| err.to_string())?;
+  let start_price = match lot.start_price {
+    Value::String(text) => text,
+    Value::Number(number) => number.to_string(),
+    _ => return Err("start_price must be a number or text".to_string()),
+  };
+  let photos = match lot.photos {
+    Value::Null => Vec::new(),
+    Value::String(text) => split_photos(&text),
+    Value::Array(values) => values
+      .into_iter()
+      .map(|value| match value {
+        Value::String(text) => Ok(text),
+        _ => Err("photos must be file ids".to_string()),
+      })
+      .collect::<Result<_, _>>()?,
+    _ => return Err("photos must be a list of file ids".to_string()),
+  };
+  let event = match lot.event {
+    Value::Null => String::new(),
+    Value::String(text) => text,
+    Value::Number(number) => number.to_string(),
+    _ => return Err("event must be an event id".to_string()),
+  };
+  Ok(RawLot {
+    category: lot.category,
+    title: lot.title,
+    description: lot.description,
+    start_price,
+    photos,
+    event,
+  })
+}
+
+/// Whether `photo` could be a Telegram file id: URL-safe base64 of a plausible length.
+fn looks_like_file_id(photo: &str) -> bool {
+  photo.len() >= MIN_FILE_ID_CHARS && photo.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Asks Telegram about every distinct well-formed photo of the file once and returns the ids it does not know, so
+/// that the dry run reports them instead of the lots failing to show later.
+async fn unknown_photos(bot: &Bot, rows: &[ParsedRow]) -> Result<HashSet<String>> {
+  let mut checked = HashSet::new();
+  let mut unknown = HashSet::new();
+  let photos = rows
+    .iter()
+    .filter_map(|(_, lot)| lot.as_ref().ok())
+    .flat_map(|lot| &lot.photos);
+  for photo in photos {
+    if !looks_like_file_id(photo) || !checked.insert(photo.as_str()) {
+      continue;
+    }
+    match bot.get_file(FileId::from(photo.clone())).await {
+      Ok(_) => {},
+      Err(RequestError::Api(err)) => {
+        warn!(error = %err, photo, "import photo is unknown to Telegram");
+        unknown.insert(photo.clone());
+      },
+      Err(err) => return Err(err.into()),
+    }
+  }
+  Ok(unknown)
+}
+
+/// File ids never contain separators, so a cell may list them split by `|`, commas or spaces.
+fn split_photos(cell: &str) -> Vec<String> {
+  cell
+    .split(['|', ',', ' '])
+    .filter(|id| !id.is_empty())
+    .map(str::to_string)
+    .collect()
+}
+
+/// The outcome of a dry run.
+#[derive(Debug, Default)]
+struct ImportPlan {
+  items: Vec<NewItem>,
+  new_categories: Vec<String>,
+  errors: Vec<(usize, String)>,
+}
+
+/// Checks every row against the rules of the add-item draft stages. Categories are matched like in the draft flow;
+/// unknown ones are created with the import. Lots naming one of the upcoming `events` are scheduled with it, and
+/// lots with a photo in `unknown_photos` are rejected.
+fn plan(
+  rows: Vec<ParsedRow>,
+  categories: &[CategoryRow],
+  events: &[AuctionEventRow],
+  unknown_photos: &HashSet<String>,
+  default_currency: Currency,
+  now: DateTime<Utc>,
+) -> ImportPlan {
+  let mut plan = ImportPlan::default();
+  let mut new_categories: Vec<(String, Option<Currency>)> = Vec::new();
+  for (row, lot) in rows {
+    let item = lot.and_then(|lot| {
+      let schedule = event_schedule(&lot.event, events, now)?;
+      let item = validate(lot, categories, &mut new_categories, unknown_photos, default_currency)?;
+      Ok(NewItem { schedule, ..item })
+    });
+    match item {
+      Ok(item) => plan.items.push(item),
+      Err(err) => plan.errors.push((row, err)),
+    }
+  }
+  plan.new_categories = new_categories.into_iter().map(|(name, _)| name).collect();
+  plan
+}
+
+fn event_schedule(event: &str, events: &[AuctionEventRow], now: DateTime<Utc>) -> Result<ItemSchedule, String> {
+  let event = event.trim();
+  if event.is_empty() {
+    return Ok(ItemSchedule::default());
+  }
+  let event_id: i64 = event
+    .trim_start_matches('#')
+    .parse()
+    .map_err(|_| format!("\"{event}\" is not an event id"))?;
+  events
+    .iter()
+    .find(|event| event.id == event_id)
+    .map(|event| event.schedule(now))
+    .ok_or_else(|| format!("auction event #{event_id} does not exist or is over"))
+}
+
+fn validate(
+  lot: RawLot,
+  categories: &[CategoryRow],
+  new_categories: &mut Vec<(String, Option<Currency>)>,
+  unknown_photos: &HashSet<String>,
+  default_currency: Currency,
+) -> Result<NewItem, String> {
+  let (name, currency) = split_category_currency(&lot.category)?;
+  if name.is_empty() {
+    return Err("category is missing".to_string());
+  }
+  let title = lot.title.trim();
+  if title.is_empty() {
+    return Err("title is missing".to_string());
+  }
+  if lot.start_price.trim().is_empty() {
+    return Err("start price is missing".to_string());
+  }
+
+  let key = name.to_lowercase();
+  let existing = categories.iter().find(|category| category.name.to_lowercase() == key);
+  let planned = new_categories.iter().find(|(planned, _)| planned.to_lowercase() == key);
+  let (category, price_currency, new_category) = match (existing, planned) {
+    // Like in the draft flow, a currency given for an existing category is ignored.
+    (Some(category), _) => (
+      NewItemCategory::Existing(category.id),
+      category
+        .currency
+        .as_deref()
+        .and_then(Currency::from_code)
+        .unwrap_or(default_currency),
+      None,
+    ),
+    (None, Some((planned, planned_currency))) => {
+      if currency.is_some() && currency != *planned_currency {
+        return Err(format!("category {planned} is given two different currencies"));
+      }
+      (
+        NewItemCategory::New {
+          name: planned.clone(),
+          currency: planned_currency.map(|currency| currency.code.to_string()),
+        },
+        planned_currency.unwrap_or(default_currency),
+        None,
+      )
+    },
+    (None, None) => (
+      NewItemCategory::New {
+        name: name.to_string(),
+        currency: currency.map(|currency| currency.code.to_string()),
+      },
+      currency.unwrap_or(default_currency),
+      Some((name.to_string(), currency)),
+    ),
+  };
+  let start_price = price_currency
+    .parse(&lot.start_price)
+    .map_err(|err| format!("invalid price: {err}"))?;
+
+  let mut image_file_ids: Vec<FileId> = Vec::new();
+  for photo in lot.photos {
+    if !looks_like_file_id(&photo) {
+      return Err(format!("photo \"{photo}\" is not a Telegram file id"));
+    }
+    if unknown_photos.contains(&photo) {
+      return Err(format!("photo {photo} is unknown to Telegram"));
+    }
+    let photo = FileId::from(photo);
+    if !image_file_ids.contains(&photo) {
+      image_file_ids.push(photo);
+    }
+  }
+  new_categories.extend(new_category);
+  Ok(NewItem {
+    category,
+    title: title.to_string(),
+    description: draft_description(lot.description.as_deref()),
+    start_price,
+    image_file_ids,
+    schedule: ItemSchedule::default(),
+  })
+}
+
+fn render_summary(plan: &ImportPlan) -> String {
+  let total = plan.items.len() + plan.errors.len();
+  let mut text = format!(
+    "📥 Dry run: {total} row(s), {} valid, {} with errors.",
+    plan.items.len(),
+    plan.errors.len()
+  );
+  if !plan.new_categories.is_empty() {
+    text.push_str(&format!("\nNew categories: {}.", plan.new_categories.join(", ")));
+  }
+  let scheduled = plan
+    .items
+    .iter()
+    .filter(|item| item.schedule.event_id.is_some())
+    .count();
+  if scheduled > 0 {
+    text.push_str(&format!("\n{scheduled} lot(s) join auction events."));
+  }
+  if plan.errors.is_empty() {
+    text.push_str(&format!("\n\nCreate {} lot(s)?", plan.items.len()));
+    return text;
+  }
+
+  text.push('\n');
+  for (row, err) in plan.errors.iter().take(MAX_LISTED_ERRORS) {
+    text.push_str(&format!("\nRow {row}: {err}"));
+  }
+  if plan.errors.len() > MAX_LISTED_ERRORS {
+    text.push_str(&format!("\n…and {} more.", plan.errors.len() - MAX_LISTED_ERRORS));
+  }
+  text.push_str("\n\nNothing was created. Fix the rows and upload the file again, or type cancel.");
+  text
+}
+
+pub async fn send_prompt(bot: &Bot, chat: ChatId) -> HandlerResult {
+  bot
+    .send_message(
+      chat,
+      "📥 Upload a CSV or JSON file with one lot per row. CSV needs the columns category, title, description, \
+       start_price and optionally photos and event; JSON takes an array of objects with the same fields. Categories \
+       may carry a currency like Watches [USD], description '-' skips it, photos are file ids separated by | and \
+       event is the number of an auction event to schedule the lot with. You will get a dry run before anything is \
+       created. Type cancel to stop.",
+    )
+    .await?;
+  Ok(())
+}
+
+#[instrument(skip(bot, ctx, dialogue, msg))]
+pub async fn handle_import_message(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  msg: Message,
+  (admin_tg_id, _): (i64, Vec<NewItem>),
+) -> HandlerResult {
+  let user = msg.from.as_ref().context("message missing sender")?;
+  if user.id.0 as i64 != admin_tg_id {
+    bot
+      .send_message(msg.chat.id, "Only the admin who started this action can respond.")
+      .await?;
+    return Ok(());
+  }
+  if msg
+    .text()
+    .is_some_and(|text| text.trim().eq_ignore_ascii_case("cancel"))
+  {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, "❌ Import cancelled.").await?;
+    return Ok(());
+  }
+  let Some(document) = msg.document() else {
+    bot
+      .send_message(msg.chat.id, "📥 Upload the CSV or JSON file, or type cancel.")
+      .await?;
+    return Ok(());
+  };
+  if document.file.size > MAX_IMPORT_BYTES {
+    bot
+      .send_message(
+        msg.chat.id,
+        "⚠️ The file is too large. Split it into files of up to 1 MB.",
+      )
+      .await?;
+    return Ok(());
+  }
+
+  let contents = download(&bot, document).await?;
+  let rows = match parse_file(&contents, is_json(document, &contents)) {
+    Ok(rows) => rows,
+    Err(err) => {
+      bot.send_message(msg.chat.id, format!("⚠️ {err}")).await?;
+      return Ok(());
+    },
+  };
+  let categories = ctx.db().list_categories().await?;
+  let events: Vec<AuctionEventRow> = ctx
+    .db()
+    .list_upcoming_auction_events()
+    .await?
+    .into_iter()
+    .map(|(event, _)| event)
+    .collect();
+  let unknown_photos = unknown_photos(&bot, &rows).await?;
+  let plan = plan(
+    rows,
+    &categories,
+    &events,
+    &unknown_photos,
+    ctx.auction().currency,
+    Utc::now(),
+  );
+  info!(
+    admin_tg_id,
+    valid = plan.items.len(),
+    errors = plan.errors.len(),
+    "import dry run"
+  );
+
+  let text = render_summary(&plan);
+  let ready = plan.errors.is_empty() && !plan.items.is_empty();
+  let lots = if ready { plan.items } else { Vec::new() };
+  dialogue.update(ConversationState::Import { admin_tg_id, lots }).await?;
+  let mut request = bot.send_message(msg.chat.id, text);
+  if ready {
+    request = request.reply_markup(InlineKeyboardMarkup::new([[
+      CallbackData::Import(ImportAction::Confirm).button("✅ Create lots"),
+      CallbackData::Import(ImportAction::Cancel).button("❌ Cancel"),
+    ]]));
+  }
+  request.await?;
+  Ok(())
+}
+
+async fn download(bot: &Bot, document: &Document) -> Result<Vec<u8>> {
+  let file = bot.get_file(document.file.id.clone()).await?;
+  let mut contents = Vec::with_capacity(file.size as usize);
+  bot
+    .download_file(&file.path, &mut contents)
+    .await
+    .context("failed to download import file")?;
+  Ok(contents)
+}
+
+fn is_json(document: &Document, contents: &[u8]) -> bool {
+  match document.file_name.as_deref().and_then(|name| name.rsplit_once('.')) {
+    Some((_, extension)) => extension.eq_ignore_ascii_case("json"),
+    None => contents.trim_ascii_start().starts_with(b"["),
+  }
+}
+
+/// Handles the buttons under a clean dry run.
+#[instrument(skip(bot, dialogue, ctx, query))]
+pub async fn handle_callback(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  action: ImportAction,
+) -> HandlerResult {
+  let text = apply_action(&bot, &dialogue, &ctx, &query, action).await?;
+  if let Some(message) = &query.message {
+    bot.edit_message_reply_markup(message.chat().id, message.id()).await?;
+  }
+  callback::answer(&bot, &query, Some(text)).await
+}
+
+async fn apply_action(
+  bot: &Bot,
+  dialogue: &BotDialogue,
+  ctx: &SharedContext,
+  query: &CallbackQuery,
+  action: ImportAction,
+) -> Result<String> {
+  let admin_tg_id = query.from.id.0 as i64;
+  if !ctx.is_admin(admin_tg_id) {
+    return Ok("🛡️ Admins only.".to_string());
+  }
+  let lots = match dialogue.get().await? {
+    Some(ConversationState::Import {
+      admin_tg_id: owner,
+      lots,
+    }) if owner == admin_tg_id && !lots.is_empty() => lots,
+    _ => return Ok("This import is no longer pending. Upload the file again.".to_string()),
+  };
+  dialogue.reset().await?;
+  if action == ImportAction::Cancel {
+    return Ok("❌ Import cancelled.".to_string());
+  }
+
+  let (new_categories, item_ids) = ctx.db().create_imported_items(admin_tg_id, &lots).await?;
+  info!(admin_tg_id, count = item_ids.len(), "imported lots");
+  for category in &new_categories {
+    audit::record(
+      ctx,
+      admin_tg_id,
+      AuditAction::CategoryCreated,
+      AuditTarget::Category(category.id),
+      json!({ "name": category.name, "currency": category.currency, "imported": true }),
+    )
+    .await;
+  }
+  let categories = ctx.db().list_categories().await?;
+  for (lot, item_id) in lots.iter().zip(&item_ids) {
+    let category = categories.iter().find(|category| match &lot.category {
+      NewItemCategory::Existing(id) => category.id == *id,
+      NewItemCategory::New { name, .. } => category.name == *name,
+    });
+    let currency = ctx.currency_for(category.and_then(|category| category.currency.as_deref()));
+    audit::record(
+      ctx,
+      admin_tg_id,
+      AuditAction::ItemCreated,
+      AuditTarget::Item(*item_id),
+      json!({
+        "title": lot.title,
+        "category": category.map(|category| &category.name),
+        "start_price": currency.format(lot.start_price),
+        "imported": true,
+      }),
+    )
+    .await;
+    if let Err(err) = channel::publish_item(bot, ctx, *item_id).await {
+      warn!(error = %err, item_id, "failed to publish imported item to channel");
+    }
+  }
+
+  if let Some(message) = &query.message {
+    bot
+      .send_message(
+        message.chat().id,
+        format!(
+          "📥 Created {} lot(s), #{} to #{}.",
+          item_ids.len(),
+          item_ids[0],
+          item_ids[item_ids.len() - 1]
+        ),
+      )
+      .await?;
+  }
+  Ok("📥 Lots created.".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+
+  use chrono::TimeZone;
+  use chrono::Utc;
+
+  use super::ImportPlan;
+  use super::parse_file;
+  use super::plan;
+  use super::render_summary;
+  use crate::models::AuctionEventRow;
+  use crate::models::CategoryRow;
+  use crate::models::NewItemCategory;
+  use crate::util::Currency;
+
+  fn categories() -> Vec<CategoryRow> {
+    vec![CategoryRow {
+      id: 1,
+      name: "Watches".to_string(),
+      currency: Some("USD".to_string()),
+    }]
+  }
+
+  fn events() -> Vec<AuctionEventRow> {
+    vec![AuctionEventRow {
+      id: 3,
+      name: "Spring sale".to_string(),
+      starts_at: Utc.with_ymd_and_hms(2025, 12, 1, 18, 0, 0).unwrap(),
+      ends_at: Utc.with_ymd_and_hms(2025, 12, 3, 20, 0, 0).unwrap(),
+      created_by: 1,
+      created_at: Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap(),
+    }]
+  }
+
+  /// A well-formed file id that Telegram does not know.
+  const UNKNOWN_PHOTO: &str = "AgACAgQAAxkBAAIUnknownPhoto";
+
+  fn plan_file(contents: &str, json: bool) -> ImportPlan {
+    plan(
+      parse_file(contents.as_bytes(), json).unwrap(),
+      &categories(),
+      &events(),
+      &HashSet::from([UNKNOWN_PHOTO.to_string()]),
+      Currency::EUR,
+      Utc.with_ymd_and_hms(2025, 11, 30, 12, 0, 0).unwrap(),
+    )
+  }
+
+  #[test]
+  fn plans_valid_csv_rows() {
+    let plan = plan_file(
+      "Category,Title,Description,Start \
+       Price,Photos,Event\nwatches,Clock,-,\"1,250\",\
+       AgACAgQAAxkBAAIBa1clock|AgACAgQAAxkBAAIBb2clock|AgACAgQAAxkBAAIBa1clock,#3\nVases \
+       [GBP],Urn,Old,50,,\nvases,Jug,,12.5,\n",
+      false,
+    );
+    assert!(plan.errors.is_empty(), "{:?}", plan.errors);
+    assert_eq!(plan.new_categories, vec!["Vases".to_string()]);
+    assert_eq!(plan.items.len(), 3);
+    let clock = &plan.items[0];
+    assert_eq!(clock.category, NewItemCategory::Existing(1));
+    assert_eq!(clock.description, None);
+    assert_eq!(clock.start_price, 125_000);
+    assert_eq!(clock.image_file_ids.len(), 2);
+    assert_eq!(clock.schedule.event_id, Some(3));
+    assert_eq!(clock.schedule.publish_at, Some(events()[0].starts_at));
+    assert_eq!(plan.items[1].schedule, Default::default());
+    assert_eq!(plan.items[2].category, NewItemCategory::New {
+      name: "Vases".to_string(),
+      currency: Some("GBP".to_string()),
+    });
+    assert_eq!(plan.items[2].start_price, 1250);
+  }
+
+  #[test]
+  fn reports_row_errors() {
+    let plan = plan_file(
+      r#"[
+        {"category": "Watches", "title": "Clock", "start_price": 10},
+        {"category": "Watches", "title": " ", "start_price": "10"},
+        {"category": "Lamps [XYZ]", "title": "Lamp", "start_price": "10"},
+        {"category": "Watches", "title": "Clock", "start_price": "ten"},
+        {"category": "Watches", "start_price": "10"},
+        {"category": "Vases [GBP]", "title": "Urn", "start_price": 5},
+        {"category": "Vases [USD]", "title": "Jug", "start_price": 5},
+        {"category": "Watches", "title": "Lamp", "start_price": 5, "event": 9},
+        {"category": "Watches", "title": "Lamp", "start_price": 5, "photos": ["lamp.jpg"]},
+        {"category": "Watches", "title": "Lamp", "start_price": 5, "photos": ["AgACAgQAAxkBAAIUnknownPhoto"]}
+      ]"#,
+      true,
+    );
+    assert_eq!(plan.items.len(), 2);
+    let rows: Vec<usize> = plan.errors.iter().map(|(row, _)| *row).collect();
+    assert_eq!(rows, vec![2, 3, 4, 5, 7, 8, 9, 10]);
+    assert!(plan.errors[0].1.contains("title is missing"));
+    assert!(plan.errors[1].1.contains("Unknown currency"));
+    assert!(plan.errors[2].1.starts_with("invalid price"));
+    assert!(plan.errors[3].1.contains("title"));
+    assert!(plan.errors[4].1.contains("two different currencies"));
+    assert!(plan.errors[5].1.contains("event #9"));
+    assert!(plan.errors[6].1.contains("\"lamp.jpg\" is not a Telegram file id"));
+    assert!(
+      plan.errors[7]
+        .1
+        .contains(&format!("{UNKNOWN_PHOTO} is unknown to Telegram"))
+    );
+
+    let summary = render_summary(&plan);
+    assert!(summary.starts_with("📥 Dry run: 10 row(s), 2 valid, 8 with errors."));
+    assert!(summary.contains("\nRow 3: Unknown currency"));
+    assert!(summary.contains("Nothing was created."));
+  }
+
+  #[test]
+  fn rejects_files_without_required_columns() {
+    assert!(parse_file(b"category,title\nWatches,Clock\n", false).is_err());
+    assert!(parse_file(b"{\"title\": \"Clock\"}", true).is_err());
+  }
+}
@@ -1,13 +1,34 @@
 use teloxide::dispatching::dialogue::InMemStorage;
 
+pub mod alerts;
+pub mod archive;
+pub mod audit;
+pub mod callback;
+pub mod cards;
+pub mod channel;
 pub mod commands;
 pub mod context;
+pub mod errors;
+pub mod export;
+pub mod group;
 pub mod handlers;
+pub mod history;
+pub mod import;
+pub mod orders;
+pub mod payments;
+pub mod quick_bid;
+pub mod relist;
+pub mod retraction;
+pub mod scheduler;
+pub mod second_chance;
 pub mod state;
+#[cfg(test)]
+pub(crate) mod testing;
 
 pub type HandlerResult = anyhow::Result<()>;
 pub type DialogueStorage = InMemStorage<state::ConversationState>;
 
 pub use commands::Command;
+pub use commands::GroupCommand;
 pub use context::AppContext;
 pub use handlers::build_schema;
@@ -0,0 +1,292 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::json;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardButton;
+use teloxide::types::InlineKeyboardMarkup;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::HandlerResult;
+use crate::bot::audit;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::context::AppContext;
+use crate::bot::handlers::user_locale;
+use crate::i18n::Locale;
+use crate::i18n::tr;
+use crate::metrics;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
+use crate::models::ItemRow;
+use crate::models::OrderRow;
+use crate::models::OrderStatus;
+use crate::util::Currency;
+
+type SharedContext = Arc<AppContext>;
+
+/// How many open orders the admin overview lists at once.
+const ACTIVE_ORDERS_LIMIT: i64 = 20;
+
+/// Opens the checkout order for a freshly closed item. Items that closed without bids get no order.
+#[instrument(skip(ctx, item), fields(item_id = item.id))]
+pub async fn open_for_closed_item(ctx: &AppContext, item: &ItemRow) -> Result<Option<OrderRow>> {
+  let Some((winner_id, amount)) = ctx.db().best_bid_with_bidder(item.id).await? else {
+    return Ok(None);
+  };
+  let order = ctx.db().create_order(item.id, winner_id, amount).await?;
+  info!(order_id = order.id, winner_id, amount, "opened order for closed item");
+  Ok(Some(order))
+}
+
+/// Lists the orders a user has won under "🧾 My wins".
+#[instrument(skip(bot, ctx))]
+pub async fn send_my_wins(bot: &Bot, ctx: &SharedContext, chat: ChatId, user_id: i64) -> HandlerResult {
+  let locale = user_locale(ctx, user_id).await?;
+  let orders = ctx.db().list_user_orders(user_id).await?;
+  if orders.is_empty() {
+    info!(user_id, chat_id = %chat, "no wins to display");
+    bot.send_message(chat, tr!(locale, "wins-empty")).await?;
+    return Ok(());
+  }
+
+  info!(user_id, chat_id = %chat, count = orders.len(), "sending wins list");
+  let mut text = tr!(locale, "wins-header", count = orders.len());
+  for (order, title, currency) in &orders {
+    let currency = ctx.currency_for(currency.as_deref());
+    text.push_str(&format!("\n\n{}", render_order(locale, order, title, currency)));
+  }
+  bot.send_message(chat, text).await?;
+  Ok(())
+}
+
+/// Sends one card per order that still needs attention, each with buttons for its next statuses.
+#[instrument(skip(bot, ctx))]
+pub async fn send_active_orders(bot: &Bot, ctx: &SharedContext, chat: ChatId) -> HandlerResult {
+  let orders = ctx.db().list_active_orders(ACTIVE_ORDERS_LIMIT).await?;
+  if orders.is_empty() {
+    bot.send_message(chat, "🧾 No open orders.").await?;
+    return Ok(());
+  }
+
+  bot
+    .send_message(chat, format!("🧾 Open orders ({}):", orders.len()))
+    .await?;
+  for (order, title, currency) in orders {
+    let buyer = buyer_name(ctx, order.winner_tg_id).await?;
+    let currency = ctx.currency_for(currency.as_deref());
+    bot
+      .send_message(chat, render_admin_order(&order, &title, currency, &buyer))
+      .reply_markup(admin_order_keyboard(&order))
+      .await?;
+  }
+  Ok(())
+}
+
+/// Handles the admin-only order status buttons.
+#[instrument(skip(bot, ctx, query))]
+pub async fn handle_callback(
+  bot: Bot,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  (order_id, next): (i64, OrderStatus),
+) -> HandlerResult {
+  let text = set_status(&bot, &ctx, &query, order_id, next).await?;
+  callback::answer(&bot, &query, text).await
+}
+
+/// Moves an order to its next status and returns the text for the callback answer.
+async fn set_status(
+  bot: &Bot,
+  ctx: &SharedContext,
+  query: &CallbackQuery,
+  order_id: i64,
+  next: OrderStatus,
+) -> Result<Option<String>> {
+  let admin_id = query.from.id.0 as i64;
+  if !ctx.is_admin(admin_id) {
+    return Ok(Some("🛡️ Admins only.".to_string()));
+  }
+
+  let Some(order) = ctx.db().get_order(order_id).await? else {
+    return Ok(Some("❓ Order not found".to_string()));
+  };
+  if !order.status.can_become(next) {
+    return Ok(Some(format!("Order is already {}.", order.status.label())));
+  }
+  let Some(updated) = ctx.db().update_order_status(order.id, order.status, next).await? else {
+    return Ok(Some("Order changed meanwhile, reopen the list.".to_string()));
+  };
+  info!(
+    admin_id,
+    order_id,
+    from = order.status.as_str(),
+    to = next.as_str(),
+    "order status changed"
+  );
+  audit::record(
+    ctx,
+    admin_id,
+    AuditAction::OrderStatusChanged,
+    AuditTarget::Order(order_id),
+    json!({ "item_id": order.item_id, "from": order.status.as_str(), "to": next.as_str() }),
+  )
+  .await;
+
+  let Some(item) = ctx.db().get_item(updated.item_id).await? else {
+    return Ok(Some(next.label().to_string()));
+  };
+  if let Some(message) = query.message.as_ref() {
+    let buyer = buyer_name(ctx, updated.winner_tg_id).await?;
+    let request = bot
+      .edit_message_text(
+        message.chat().id,
+        message.id(),
+        render_admin_order(&updated, &item.title, ctx.item_currency(&item), &buyer),
+      )
+      .reply_markup(admin_order_keyboard(&updated));
+    match request.await {
+      Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => {},
+      Err(err) => return Err(err.into()),
+    }
+  }
+
+  if let Err(err) = notify_winner(bot, ctx, &updated, &item).await {
+    warn!(error = %err, order_id, winner_id = updated.winner_tg_id, "failed to notify winner about order status");
+  }
+  Ok(Some(next.label().to_string()))
+}
+
+async fn notify_winner(bot: &Bot, ctx: &SharedContext, order: &OrderRow, item: &ItemRow) -> Result<()> {
+  let locale = user_locale(ctx, order.winner_tg_id).await?;
+  metrics::notification(
+    "order_status",
+    bot.send_message(
+      ChatId(order.winner_tg_id),
+      tr!(
+        locale,
+        "notify-order-status",
+        order_id = order.id,
+        item_id = item.id,
+        title = item.title.as_str(),
+        status = status_text(locale, order.status)
+      ),
+    ),
+  )
+  .await?;
+  Ok(())
+}
+
+async fn buyer_name(ctx: &SharedContext, user_id: i64) -> Result<String> {
+  Ok(match ctx.db().get_user(user_id).await? {
+    Some(user) => user.display_name(),
+    None => format!("user {user_id}"),
+  })
+}
+
+/// The buyer-facing name of an order status. Admin screens use [`OrderStatus::label`] instead.
+pub(super) fn status_text(locale: Locale, status: OrderStatus) -> String {
+  match status {
+    OrderStatus::AwaitingPayment => tr!(locale, "order-status-awaiting-payment"),
+    OrderStatus::Paid => tr!(locale, "order-status-paid"),
+    OrderStatus::Shipped => tr!(locale, "order-status-shipped"),
+    OrderStatus::Completed => tr!(locale, "order-status-completed"),
+    OrderStatus::Cancelled => tr!(locale, "order-status-cancelled"),
+  }
+}
+
+fn render_order(locale: Locale, order: &OrderRow, title: &str, currency: Currency) -> String {
+  tr!(
+    locale,
+    "wins-order",
+    item_id = order.item_id,
+    title = title,
+    amount = currency.format(order.amount),
+    order_id = order.id,
+    status = status_text(locale, order.status)
+  )
+}
+
+fn render_admin_order(order: &OrderRow, title: &str, currency: Currency, buyer: &str) -> String {
+  format!(
+    "🧾 Order #{}\n📦 Item #{} {}\n👤 Winner: {}\n💰 Amount: {}\n📌 Status: {}\n🕒 Updated: {}",
+    order.id,
+    order.item_id,
+    title,
+    buyer,
+    currency.format(order.amount),
+    order.status.label(),
+    order.updated_at.format("%d %b %H:%M")
+  )
+}
+
+fn admin_order_keyboard(order: &OrderRow) -> InlineKeyboardMarkup {
+  let buttons: Vec<InlineKeyboardButton> = order
+    .status
+    .next_steps()
+    .iter()
+    .map(|next| {
+      CallbackData::SetOrderStatus {
+        order_id: order.id,
+        status: *next,
+      }
+      .button(format!("→ {}", next.label()))
+    })
+    .collect();
+  if buttons.is_empty() {
+    InlineKeyboardMarkup::default()
+  } else {
+    InlineKeyboardMarkup::new(vec![buttons])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::Utc;
+
+  use super::admin_order_keyboard;
+  use super::render_order;
+  use crate::i18n::Locale;
+  use crate::models::OrderRow;
+  use crate::models::OrderStatus;
+  use crate::util::Currency;
+
+  fn order(status: OrderStatus) -> OrderRow {
+    OrderRow {
+      id: 2,
+      item_id: 9,
+      winner_tg_id: 42,
+      amount: 15_000,
+      status,
+      updated_at: Utc::now(),
+    }
+  }
+
+  #[test]
+  fn keyboard_offers_next_steps_only() {
+    let keyboard = admin_order_keyboard(&order(OrderStatus::AwaitingPayment));
+    let labels: Vec<&str> = keyboard.inline_keyboard[0].iter().map(|b| b.text.as_str()).collect();
+    assert_eq!(labels, vec!["→ 💳 Paid", "→ ❌ Cancelled"]);
+
+    assert!(
+      admin_order_keyboard(&order(OrderStatus::Completed))
+        .inline_keyboard
+        .is_empty()
+    );
+  }
+
+  #[test]
+  fn renders_order_for_winner() {
+    let text = render_order(Locale::En, &order(OrderStatus::Shipped), "Clock", Currency::AED);
+    assert!(text.contains("#9 Clock — AED 150.00"));
+    assert!(text.contains("Order #2 · 🚚 Shipped"));
+
+    let text = render_order(Locale::Ru, &order(OrderStatus::Shipped), "Clock", Currency::AED);
+    assert!(text.contains("Заказ #2 · 🚚 Отправлен"));
+  }
+}
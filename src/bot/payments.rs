@@ -0,0 +1,444 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use teloxide::dispatching::UpdateHandler;
+use teloxide::prelude::*;
+use teloxide::types::LabeledPrice;
+use teloxide::types::Message;
+use teloxide::types::PreCheckoutQuery;
+use teloxide::types::SuccessfulPayment;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::HandlerResult;
+use crate::bot::context::AppContext;
+use crate::bot::handlers::user_locale;
+use crate::bot::orders::status_text;
+use crate::i18n::Locale;
+use crate::i18n::tr;
+use crate::metrics;
+use crate::models::ItemRow;
+use crate::models::OrderRow;
+use crate::models::OrderStatus;
+use crate::util::Currency;
+
+type SharedContext = Arc<AppContext>;
+
+/// Telegram rejects invoice titles longer than this.
+const INVOICE_TITLE_MAX_CHARS: usize = 32;
+
+/// Handles the checkout updates of Telegram Payments: the pre-checkout query sent before the winner is charged and
+/// the service message that confirms the payment.
+pub fn payment_branch() -> UpdateHandler<anyhow::Error> {
+  dptree::entry()
+    .branch(Update::filter_pre_checkout_query().endpoint(handle_pre_checkout_query))
+    .branch(
+      Update::filter_message()
+        .filter_map(|msg: Message| msg.successful_payment().cloned())
+        .endpoint(handle_successful_payment),
+    )
+}
+
+/// Sends the winner an invoice for their order, in the currency the item was listed in. Order amounts are already in
+/// minor units, which is what Telegram expects.
+#[instrument(skip(bot, provider_token, order, item), fields(order_id = order.id))]
+pub async fn send_order_invoice(
+  bot: &Bot,
+  provider_token: &str,
+  locale: Locale,
+  currency: Currency,
+  order: &OrderRow,
+  item: &ItemRow,
+) -> Result<()> {
+  let amount = u32::try_from(order.amount).context("order amount does not fit an invoice")?;
+  let title: String = format!("#{} {}", item.id, item.title)
+    .chars()
+    .take(INVOICE_TITLE_MAX_CHARS)
+    .collect();
+  bot
+    .send_invoice(
+      ChatId(order.winner_tg_id),
+      title,
+      tr!(
+        locale,
+        "invoice-description",
+        item_id = item.id,
+        title = item.title.as_str()
+      ),
+      invoice_payload(order.id),
+      currency.code,
+      [LabeledPrice::new(
+        tr!(locale, "invoice-label", item_id = item.id),
+        amount,
+      )],
+    )
+    .provider_token(provider_token)
+    .await?;
+  info!(winner_id = order.winner_tg_id, amount, "sent order invoice");
+  Ok(())
+}
+
+fn invoice_payload(order_id: i64) -> String {
+  format!("order:{order_id}")
+}
+
+fn parse_invoice_payload(payload: &str) -> Option<i64> {
+  payload.strip_prefix("order:")?.parse().ok()
+}
+
+/// Checks that a checkout still matches its order. The error is shown to the payer by Telegram.
+fn check_checkout(
+  locale: Locale,
+  order: Option<&OrderRow>,
+  expected_currency: Currency,
+  payer_id: i64,
+  currency: &str,
+  total_amount: u32,
+) -> Result<(), String> {
+  let Some(order) = order else {
+    return Err(tr!(locale, "checkout-order-missing"));
+  };
+  if order.winner_tg_id != payer_id {
+    return Err(tr!(locale, "checkout-wrong-payer"));
+  }
+  if order.status != OrderStatus::AwaitingPayment {
+    return Err(tr!(
+      locale,
+      "checkout-order-status",
+      status = status_text(locale, order.status)
+    ));
+  }
+  if currency != expected_currency.code || i64::from(total_amount) != order.amount {
+    return Err(tr!(
+      locale,
+      "checkout-amount-changed",
+      amount = expected_currency.format(order.amount)
+    ));
+  }
+  Ok(())
+}
+
+#[instrument(skip(bot, ctx, query), fields(payer_id = query.from.id.0))]
+async fn handle_pre_checkout_query(bot: Bot, ctx: SharedContext, query: PreCheckoutQuery) -> HandlerResult {
+  let (locale, order, currency) = match load_checkout(&ctx, &query).await {
+    Ok(checkout) => checkout,
+    Err(err) => {
+      // Telegram keeps the payer waiting until the query is answered, so it is declined before the error surfaces.
+      let locale = Locale::resolve(query.from.language_code.as_deref());
+      decline_pre_checkout(&bot, &query, tr!(locale, "checkout-unavailable")).await?;
+      return Err(err);
+    },
+  };
+  answer_pre_checkout(&bot, &query, locale, order.as_ref(), currency).await?;
+  Ok(())
+}
+
+/// Looks up the payer's locale and the order a checkout pays for, with the currency its item is priced in.
+async fn load_checkout(
+  ctx: &SharedContext,
+  query: &PreCheckoutQuery,
+) -> Result<(Locale, Option<OrderRow>, Currency)> {
+  let locale = user_locale(ctx, query.from.id.0 as i64).await?;
+  let order = match parse_invoice_payload(&query.invoice_payload) {
+    Some(order_id) => ctx.db().get_order(order_id).await?,
+    None => None,
+  };
+  let item = match order.as_ref() {
+    Some(order) => ctx.db().get_item(order.item_id).await?,
+    None => None,
+  };
+  let currency = ctx.currency_for(item.as_ref().and_then(|item| item.currency.as_deref()));
+  Ok((locale, order, currency))
+}
+
+/// Approves or declines a pre-checkout query. Telegram expects the answer within ten seconds.
+async fn answer_pre_checkout(
+  bot: &Bot,
+  query: &PreCheckoutQuery,
+  locale: Locale,
+  order: Option<&OrderRow>,
+  currency: Currency,
+) -> Result<bool> {
+  let payer_id = query.from.id.0 as i64;
+  match check_checkout(locale, order, currency, payer_id, &query.currency, query.total_amount) {
+    Ok(()) => {
+      bot.answer_pre_checkout_query(query.id.clone(), true).await?;
+      info!(payer_id, payload = query.invoice_payload, "approved pre-checkout");
+      Ok(true)
+    },
+    Err(reason) => {
+      decline_pre_checkout(bot, query, reason).await?;
+      Ok(false)
+    },
+  }
+}
+
+async fn decline_pre_checkout(bot: &Bot, query: &PreCheckoutQuery, reason: String) -> Result<()> {
+  bot
+    .answer_pre_checkout_query(query.id.clone(), false)
+    .error_message(reason.clone())
+    .await?;
+  warn!(
+    payer_id = query.from.id.0,
+    payload = query.invoice_payload,
+    reason,
+    "declined pre-checkout"
+  );
+  Ok(())
+}
+
+#[instrument(skip(bot, ctx, msg, payment), fields(chat_id = %msg.chat.id))]
+async fn handle_successful_payment(
+  bot: Bot,
+  ctx: SharedContext,
+  msg: Message,
+  payment: SuccessfulPayment,
+) -> HandlerResult {
+  let Some(order_id) = parse_invoice_payload(&payment.invoice_payload) else {
+    warn!(payload = payment.invoice_payload, "payment with unknown payload");
+    return Ok(());
+  };
+  let paid = ctx
+    .db()
+    .mark_order_paid(
+      order_id,
+      &payment.telegram_payment_charge_id.0,
+      &payment.provider_payment_charge_id,
+    )
+    .await?;
+  let payer_id = msg.from.as_ref().map_or(msg.chat.id.0, |user| user.id.0 as i64);
+  let locale = user_locale(&ctx, payer_id).await?;
+  let Some(order) = paid else {
+    warn!(
+      order_id,
+      charge_id = %payment.telegram_payment_charge_id,
+      "payment received for an order that was not awaiting payment"
+    );
+    bot
+      .send_message(msg.chat.id, tr!(locale, "payment-under-review", order_id = order_id))
+      .await?;
+    notify_staff(
+      &bot,
+      &ctx,
+      None,
+      &format!(
+        "⚠️ Payment {} arrived for order #{order_id}, which was not awaiting payment. Please review it.",
+        payment.telegram_payment_charge_id
+      ),
+    )
+    .await;
+    return Ok(());
+  };
+  info!(order_id, amount = order.amount, "order paid via invoice");
+
+  let item = ctx.db().get_item(order.item_id).await?;
+  let title = item.as_ref().map(|item| item.title.as_str()).unwrap_or("removed item");
+  let currency = ctx.currency_for(item.as_ref().and_then(|item| item.currency.as_deref()));
+  bot
+    .send_message(
+      msg.chat.id,
+      tr!(
+        locale,
+        "payment-received",
+        amount = currency.format(order.amount),
+        item_id = order.item_id,
+        title = match item.as_ref() {
+          Some(item) => item.title.clone(),
+          None => tr!(locale, "payment-removed-item"),
+        }
+      ),
+    )
+    .await?;
+
+  let buyer = match ctx.db().get_user(order.winner_tg_id).await? {
+    Some(user) => user.display_name(),
+    None => format!("user {}", order.winner_tg_id),
+  };
+  notify_staff(
+    &bot,
+    &ctx,
+    item.as_ref().map(|item| item.seller_tg_id),
+    &format!(
+      "💳 Order #{} paid: item #{} ({}) for {} by {}.",
+      order.id,
+      order.item_id,
+      title,
+      currency.format(order.amount),
+      buyer
+    ),
+  )
+  .await;
+  Ok(())
+}
+
+/// Messages the seller, if given, and every admin once each.
+async fn notify_staff(bot: &Bot, ctx: &SharedContext, seller_id: Option<i64>, text: &str) {
+  let recipients: HashSet<i64> = seller_id.into_iter().chain(ctx.admin_ids()).collect();
+  for user_id in recipients {
+    if let Err(err) = metrics::notification("payment", bot.send_message(ChatId(user_id), text)).await {
+      warn!(error = %err, user_id, "failed to notify about payment");
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+
+  use chrono::Utc;
+  use serde_json::Value;
+  use serde_json::json;
+  use teloxide::types::PreCheckoutQuery;
+
+  use super::answer_pre_checkout;
+  use super::decline_pre_checkout;
+  use super::parse_invoice_payload;
+  use super::send_order_invoice;
+  use crate::bot::testing::Calls;
+  use crate::bot::testing::mock_bot_api;
+  use crate::i18n::Locale;
+  use crate::i18n::tr;
+  use crate::models::ItemRow;
+  use crate::models::OrderRow;
+  use crate::models::OrderStatus;
+  use crate::util::Currency;
+
+  fn order(status: OrderStatus) -> OrderRow {
+    OrderRow {
+      id: 8,
+      item_id: 3,
+      winner_tg_id: 42,
+      amount: 12_550,
+      status,
+      updated_at: Utc::now(),
+    }
+  }
+
+  fn pre_checkout(total_amount: u32) -> PreCheckoutQuery {
+    serde_json::from_value(json!({
+      "id": "pcq-1",
+      "from": { "id": 42, "is_bot": false, "first_name": "Winner" },
+      "currency": "AED",
+      "total_amount": total_amount,
+      "invoice_payload": "order:8"
+    }))
+    .unwrap()
+  }
+
+  fn last_call(calls: &Calls) -> (String, Value) {
+    calls.lock().unwrap().last().cloned().expect("no Bot API call recorded")
+  }
+
+  #[tokio::test]
+  async fn sends_invoice_for_the_winning_amount() {
+    let (bot, calls) = mock_bot_api().await;
+    let item = ItemRow {
+      is_open: false,
+      ..ItemRow::fixture(3, "Antique brass ship clock with chain", 5_000)
+    };
+
+    send_order_invoice(
+      &bot,
+      "provider-token",
+      Locale::En,
+      Currency::AED,
+      &order(OrderStatus::AwaitingPayment),
+      &item,
+    )
+    .await
+    .unwrap();
+
+    let (method, params) = last_call(&calls);
+    assert!(method.eq_ignore_ascii_case("sendInvoice"));
+    assert_eq!(params["chat_id"], 42);
+    assert_eq!(params["currency"], "AED");
+    assert_eq!(params["payload"], "order:8");
+    assert_eq!(params["provider_token"], "provider-token");
+    assert_eq!(params["prices"][0]["amount"], 12_550);
+    assert!(params["title"].as_str().unwrap().chars().count() <= 32);
+  }
+
+  #[tokio::test]
+  async fn approves_matching_checkout() {
+    let (bot, calls) = mock_bot_api().await;
+    let approved = answer_pre_checkout(
+      &bot,
+      &pre_checkout(12_550),
+      Locale::En,
+      Some(&order(OrderStatus::AwaitingPayment)),
+      Currency::AED,
+    )
+    .await
+    .unwrap();
+
+    assert!(approved);
+    let (method, params) = last_call(&calls);
+    assert!(method.eq_ignore_ascii_case("answerPreCheckoutQuery"));
+    assert_eq!(params["pre_checkout_query_id"], "pcq-1");
+    assert_eq!(params["ok"], true);
+  }
+
+  #[tokio::test]
+  async fn declines_stale_checkout() {
+    let (bot, calls) = mock_bot_api().await;
+    let declined = answer_pre_checkout(
+      &bot,
+      &pre_checkout(12_550),
+      Locale::En,
+      Some(&order(OrderStatus::Cancelled)),
+      Currency::AED,
+    )
+    .await
+    .unwrap();
+    assert!(!declined);
+    let (_, params) = last_call(&calls);
+    assert_eq!(params["ok"], false);
+    assert!(params["error_message"].as_str().unwrap().contains("Cancelled"));
+
+    answer_pre_checkout(
+      &bot,
+      &pre_checkout(100),
+      Locale::En,
+      Some(&order(OrderStatus::AwaitingPayment)),
+      Currency::AED,
+    )
+    .await
+    .unwrap();
+    let (_, params) = last_call(&calls);
+    assert!(params["error_message"].as_str().unwrap().contains("AED 125.50"));
+
+    // The item is priced in USD, so an AED checkout for the same number is stale too.
+    answer_pre_checkout(
+      &bot,
+      &pre_checkout(12_550),
+      Locale::En,
+      Some(&order(OrderStatus::AwaitingPayment)),
+      Currency::USD,
+    )
+    .await
+    .unwrap();
+    let (_, params) = last_call(&calls);
+    assert!(params["error_message"].as_str().unwrap().contains("$125.50"));
+  }
+
+  #[tokio::test]
+  async fn declines_checkout_it_cannot_verify() {
+    let (bot, calls) = mock_bot_api().await;
+    decline_pre_checkout(&bot, &pre_checkout(12_550), tr!(Locale::En, "checkout-unavailable"))
+      .await
+      .unwrap();
+
+    let (method, params) = last_call(&calls);
+    assert!(method.eq_ignore_ascii_case("answerPreCheckoutQuery"));
+    assert_eq!(params["ok"], false);
+    assert!(params["error_message"].as_str().unwrap().contains("try again"));
+  }
+
+  #[test]
+  fn parses_invoice_payloads() {
+    assert_eq!(parse_invoice_payload("order:8"), Some(8));
+    assert_eq!(parse_invoice_payload("item:8"), None);
+  }
+}
@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardButton;
+use teloxide::types::InlineKeyboardMarkup;
+use teloxide::types::MessageId;
+use teloxide::types::ReplyParameters;
+use teloxide::types::User;
+use tracing::info;
+use tracing::instrument;
+
+use crate::bot::HandlerResult;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::context::AppContext;
+use crate::bot::group;
+use crate::bot::handlers::BidError;
+use crate::bot::handlers::place_validated_bid;
+use crate::bot::handlers::record_bid_outcome;
+use crate::bot::handlers::user_locale;
+use crate::i18n::Locale;
+use crate::i18n::tr;
+use crate::models::ItemRow;
+use crate::util::Currency;
+
+type SharedContext = Arc<AppContext>;
+
+/// Increments offered as one-tap bids, in major units of the item's currency on top of the current best bid (or the
+/// start price).
+const QUICK_BID_STEPS: [i64; 3] = [10, 50, 100];
+
+/// A step of the quick-bid flow: the one-tap button, then the bidder's confirm or cancel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuickBidAction {
+  Ask { item_id: i64, amount: i64 },
+  Confirm { item_id: i64, amount: i64, user_id: i64 },
+  Cancel { user_id: i64 },
+}
+
+/// Returns `(step, amount)` pairs in minor units for the quick-bid buttons of an item.
+pub fn quick_bid_amounts(currency: Currency, start_price: i64, best: Option<i64>) -> Vec<(i64, i64)> {
+  let base = best.unwrap_or(start_price);
+  QUICK_BID_STEPS
+    .iter()
+    .map(|step| step * currency.minor_per_major())
+    .map(|step| (step, base + step))
+    .collect()
+}
+
+pub fn quick_bid_row(item: &ItemRow, currency: Currency, best: Option<i64>) -> Vec<InlineKeyboardButton> {
+  quick_bid_amounts(currency, item.start_price, best)
+    .into_iter()
+    .map(|(step, amount)| {
+      CallbackData::QuickBid(QuickBidAction::Ask {
+        item_id: item.id,
+        amount,
+      })
+      .button(format!("+{}", currency.format_short(step)))
+    })
+    .collect()
+}
+
+/// Handles every quick-bid callback.
+#[instrument(skip(bot, ctx, query))]
+pub async fn handle_callback(
+  bot: Bot,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  action: QuickBidAction,
+) -> HandlerResult {
+  let text = apply_action(&bot, &ctx, &query, action).await?;
+  callback::answer(&bot, &query, text).await
+}
+
+/// Runs a quick-bid step and returns the text for the callback answer, if any.
+async fn apply_action(
+  bot: &Bot,
+  ctx: &SharedContext,
+  query: &CallbackQuery,
+  action: QuickBidAction,
+) -> Result<Option<String>> {
+  let Some(message) = query.message.as_ref() else {
+    return Ok(None);
+  };
+  let chat_id = message.chat().id;
+  let is_private = message.chat().is_private();
+  let user_id = query.from.id.0 as i64;
+  let locale = user_locale(ctx, user_id).await?;
+
+  match action {
+    QuickBidAction::Ask { item_id, amount } => {
+      let Some(item) = ctx
+        .db()
+        .get_item(item_id)
+        .await?
+        .filter(|item| !item.is_archived() && item.is_published())
+      else {
+        return Ok(Some(tr!(locale, "item-not-found")));
+      };
+      if !item.is_open {
+        return Ok(Some(tr!(locale, "item-closed")));
+      }
+
+      let currency = ctx.item_currency(&item);
+      let prompt = BidPrompt {
+        item: &item,
+        currency,
+        amount,
+        reference: None,
+        locale,
+      };
+      send_confirmation_prompt(bot, chat_id, message.id(), &query.from, prompt).await?;
+      info!(user_id, item_id, amount, "asked to confirm quick bid");
+      Ok(Some(tr!(locale, "bid-confirm-below")))
+    },
+    QuickBidAction::Confirm {
+      item_id,
+      amount,
+      user_id: owner_id,
+    } => {
+      if owner_id != user_id {
+        return Ok(Some(tr!(locale, "bid-confirmation-foreign")));
+      }
+
+      let result = place_validated_bid(bot, ctx, item_id, &query.from, amount).await;
+      record_bid_outcome(&result);
+      let text = match result {
+        Ok(placed) if is_private => {
+          let mut text = tr!(
+            locale,
+            "bid-placed-quick",
+            amount = placed.currency.format(placed.amount_cents),
+            item_id = item_id
+          );
+          if placed.is_highest {
+            text.push_str(&format!("\n\n{}", tr!(locale, "bid-highest")));
+          }
+          text
+        },
+        Ok(placed) => group::group_bid_text(&query.from.full_name(), item_id, &placed),
+        Err(BidError::Internal(err)) => return Err(err.into()),
+        Err(other) => other.user_message(locale),
+      };
+      close_prompt(bot, chat_id, message.id(), text).await?;
+      Ok(None)
+    },
+    QuickBidAction::Cancel { user_id: owner_id } => {
+      if owner_id != user_id {
+        return Ok(Some(tr!(locale, "bid-confirmation-foreign")));
+      }
+      close_prompt(bot, chat_id, message.id(), tr!(locale, "bid-cancelled")).await?;
+      Ok(None)
+    },
+  }
+}
+
+/// Returns the item and its reference price (best bid or start price) when a typed bid of `amount` is far enough
+/// above it to need an explicit confirmation.
+pub async fn requires_confirmation(ctx: &AppContext, item_id: i64, amount: i64) -> Result<Option<(ItemRow, i64)>> {
+  let percent = ctx.auction().bid_confirm_percent;
+  if percent == 0 {
+    return Ok(None);
+  }
+  let Some(item) = ctx.db().get_item(item_id).await? else {
+    return Ok(None);
+  };
+  if !item.is_open {
+    return Ok(None);
+  }
+  let reference = ctx.db().best_bid_for_item(item_id).await?.unwrap_or(item.start_price);
+  Ok(needs_confirmation(reference, amount, percent).then_some((item, reference)))
+}
+
+fn needs_confirmation(reference: i64, amount: i64, percent: u32) -> bool {
+  let threshold = reference.saturating_add(reference.saturating_mul(i64::from(percent)) / 100);
+  amount > threshold
+}
+
+/// A bid waiting for its bidder to confirm it.
+pub struct BidPrompt<'a> {
+  pub item: &'a ItemRow,
+  pub currency: Currency,
+  pub amount: i64,
+  /// The price the amount was compared against, when it looked unusually high.
+  pub reference: Option<i64>,
+  /// The bidder's language, since only they can answer the prompt.
+  pub locale: Locale,
+}
+
+/// Posts a confirm/cancel prompt for a bid. Only `bidder` can answer it, which matters in group chats. When the
+/// prompt has a reference price it warns that the amount is well above it.
+pub async fn send_confirmation_prompt(
+  bot: &Bot,
+  chat_id: ChatId,
+  reply_to: MessageId,
+  bidder: &User,
+  prompt: BidPrompt<'_>,
+) -> Result<()> {
+  let BidPrompt {
+    item,
+    currency,
+    amount,
+    reference,
+    locale,
+  } = prompt;
+  let bidder_id = bidder.id.0 as i64;
+  let mut text = String::new();
+  if let Some(reference) = reference {
+    text.push_str(&tr!(
+      locale,
+      "bid-confirm-warning",
+      amount = currency.format(amount),
+      reference = currency.format(reference)
+    ));
+    text.push('\n');
+  }
+  if chat_id.is_user() {
+    text.push_str(&tr!(
+      locale,
+      "bid-confirm-question",
+      amount = currency.format(amount),
+      item_id = item.id,
+      title = item.title.as_str()
+    ));
+  } else {
+    text.push_str(&tr!(
+      locale,
+      "bid-confirm-question-group",
+      name = bidder.full_name(),
+      amount = currency.format(amount),
+      item_id = item.id
+    ));
+  }
+
+  let keyboard = InlineKeyboardMarkup::new(vec![vec![
+    CallbackData::QuickBid(QuickBidAction::Confirm {
+      item_id: item.id,
+      amount,
+      user_id: bidder_id,
+    })
+    .button(tr!(locale, "bid-confirm-button", amount = currency.format(amount))),
+    CallbackData::QuickBid(QuickBidAction::Cancel { user_id: bidder_id }).button(tr!(locale, "bid-confirm-cancel")),
+  ]]);
+  bot
+    .send_message(chat_id, text)
+    .reply_parameters(ReplyParameters::new(reply_to))
+    .reply_markup(keyboard)
+    .await?;
+  Ok(())
+}
+
+async fn close_prompt(bot: &Bot, chat_id: ChatId, message_id: MessageId, text: String) -> Result<()> {
+  let request = bot
+    .edit_message_text(chat_id, message_id, text)
+    .reply_markup(InlineKeyboardMarkup::default());
+  match request.await {
+    Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(()),
+    Err(err) => Err(err.into()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::needs_confirmation;
+  use super::quick_bid_amounts;
+  use crate::util::Currency;
+
+  #[test]
+  fn steps_build_on_best_bid() {
+    assert_eq!(quick_bid_amounts(Currency::AED, 5_000, Some(12_550)), vec![
+      (1_000, 13_550),
+      (5_000, 17_550),
+      (10_000, 22_550)
+    ]);
+  }
+
+  #[test]
+  fn steps_build_on_start_price_without_bids() {
+    assert_eq!(quick_bid_amounts(Currency::AED, 5_000, None)[0], (1_000, 6_000));
+  }
+
+  #[test]
+  fn steps_follow_the_currency_exponent() {
+    assert_eq!(quick_bid_amounts(Currency::KWD, 5_000, None)[0], (10_000, 15_000));
+  }
+
+  #[test]
+  fn confirms_only_bids_well_above_reference() {
+    assert!(!needs_confirmation(10_000, 15_000, 50));
+    assert!(needs_confirmation(10_000, 15_001, 50));
+    assert!(needs_confirmation(10_000, 100_000, 50));
+  }
+}
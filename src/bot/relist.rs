@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use serde_json::json;
+use teloxide::dispatching::dialogue::Dialogue;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardButton;
+use teloxide::types::Message;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::DialogueStorage;
+use crate::bot::HandlerResult;
+use crate::bot::audit;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::channel;
+use crate::bot::context::AppContext;
+use crate::bot::handlers::send_item;
+use crate::bot::state::ConversationState;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
+
+type SharedContext = Arc<AppContext>;
+type BotDialogue = Dialogue<ConversationState, DialogueStorage>;
+
+pub fn relist_button(item_id: i64) -> InlineKeyboardButton {
+  CallbackData::Relist(item_id).button("♻️ Relist")
+}
+
+/// Handles the admin-only relist callback by asking for the start price of the new listing.
+#[instrument(skip(bot, dialogue, ctx, query))]
+pub async fn handle_callback(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  item_id: i64,
+) -> HandlerResult {
+  let text = start_relist(&bot, &dialogue, &ctx, &query, item_id).await?;
+  callback::answer(&bot, &query, text).await
+}
+
+/// Switches the admin into the relist dialogue and returns the text for the callback answer, if any.
+async fn start_relist(
+  bot: &Bot,
+  dialogue: &BotDialogue,
+  ctx: &SharedContext,
+  query: &CallbackQuery,
+  item_id: i64,
+) -> Result<Option<String>> {
+  let user_id = query.from.id.0 as i64;
+  if !ctx.is_admin(user_id) {
+    return Ok(Some("🛡️ Admins only.".to_string()));
+  }
+  let Some(chat_id) = query.message.as_ref().map(|message| message.chat().id) else {
+    return Ok(None);
+  };
+  let Some(item) = ctx.db().get_item(item_id).await? else {
+    return Ok(Some("❓ Item not found".to_string()));
+  };
+  if item.is_open {
+    return Ok(Some("Only closed items can be relisted.".to_string()));
+  }
+  if item.is_archived() {
+    return Ok(Some("🗃 Archived items cannot be relisted.".to_string()));
+  }
+
+  dialogue.reset().await?;
+  dialogue
+    .update(ConversationState::Relist {
+      admin_tg_id: user_id,
+      item_id,
+    })
+    .await?;
+  bot
+    .send_message(
+      chat_id,
+      format!(
+        "♻️ Relisting item #{} ({}). Send a new start price, '-' to keep {}, or type cancel.",
+        item.id,
+        item.title,
+        ctx.item_currency(&item).format(item.start_price)
+      ),
+    )
+    .await?;
+  Ok(Some("♻️ Waiting for a start price.".to_string()))
+}
+
+#[instrument(skip(bot, ctx, dialogue, msg))]
+pub async fn handle_relist_message(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  msg: Message,
+  (admin_tg_id, item_id): (i64, i64),
+) -> HandlerResult {
+  let user = msg.from.as_ref().context("message missing sender")?;
+  if user.id.0 as i64 != admin_tg_id {
+    bot
+      .send_message(msg.chat.id, "Only the admin who started this action can respond.")
+      .await?;
+    return Ok(());
+  }
+
+  let Some(raw_text) = msg.text().map(str::trim).filter(|t| !t.is_empty()) else {
+    bot
+      .send_message(
+        msg.chat.id,
+        "💰 Send a start price, '-' to keep the old one, or type cancel.",
+      )
+      .await?;
+    return Ok(());
+  };
+
+  if raw_text.eq_ignore_ascii_case("cancel") {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, "❌ Relisting cancelled.").await?;
+    return Ok(());
+  }
+
+  let Some(item) = ctx.db().get_item(item_id).await? else {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, "❓ Item not found.").await?;
+    return Ok(());
+  };
+  let currency = ctx.item_currency(&item);
+  let start_price = if raw_text == "-" {
+    None
+  } else {
+    match currency.parse(raw_text) {
+      Ok(value) => Some(value),
+      Err(err) => {
+        bot
+          .send_message(msg.chat.id, format!("⚠️ Invalid price: {err}"))
+          .await?;
+        return Ok(());
+      },
+    }
+  };
+
+  let Some(new_id) = ctx.db().relist_item(item_id, start_price).await? else {
+    dialogue.reset().await?;
+    bot
+      .send_message(
+        msg.chat.id,
+        format!("♻️ Item #{item_id} is still open, archived, in an archived category or was already relisted."),
+      )
+      .await?;
+    return Ok(());
+  };
+  dialogue.reset().await?;
+  info!(admin_tg_id, item_id, new_id, "relisted item");
+  audit::record(
+    &ctx,
+    admin_tg_id,
+    AuditAction::ItemRelisted,
+    AuditTarget::Item(new_id),
+    json!({ "relisted_from": item_id, "start_price": start_price.map(|price| currency.format(price)) }),
+  )
+  .await;
+  bot
+    .send_message(msg.chat.id, format!("♻️ Item #{item_id} relisted as #{new_id}."))
+    .await?;
+
+  if let Err(err) = channel::publish_item(&bot, &ctx, new_id).await {
+    warn!(error = %err, item_id = new_id, "failed to publish relisted item to channel");
+  }
+  match send_item(&bot, &ctx, msg.chat.id, new_id, Some(admin_tg_id)).await {
+    Ok(true) => {},
+    Ok(false) => warn!(item_id = new_id, "item missing immediately after relisting"),
+    Err(err) => warn!(error = %err, item_id = new_id, "failed to present relisted item"),
+  }
+  Ok(())
+}
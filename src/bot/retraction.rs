@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use serde_json::json;
+use teloxide::dispatching::dialogue::Dialogue;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::Message;
+use tracing::info;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::DialogueStorage;
+use crate::bot::HandlerResult;
+use crate::bot::audit;
+use crate::bot::callback;
+use crate::bot::cards;
+use crate::bot::context::AppContext;
+use crate::bot::handlers::bid_currency;
+use crate::bot::handlers::user_locale;
+use crate::bot::state::ConversationState;
+use crate::i18n::tr;
+use crate::metrics;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
+use crate::models::BidRow;
+use crate::models::ItemRow;
+
+type SharedContext = Arc<AppContext>;
+type BotDialogue = Dialogue<ConversationState, DialogueStorage>;
+
+const CLOSED_ITEM: &str = "🔒 The auction for this bid has closed, so it can no longer be retracted.";
+
+/// Handles the admin-only retraction callback offered by the bid history by asking for the reason.
+#[instrument(skip(bot, dialogue, ctx, query))]
+pub async fn handle_callback(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  bid_id: i64,
+) -> HandlerResult {
+  let text = start_retraction(&bot, &dialogue, &ctx, &query, bid_id).await?;
+  callback::answer(&bot, &query, text).await
+}
+
+/// Switches the admin into the retraction dialogue and returns the text for the callback answer, if any.
+async fn start_retraction(
+  bot: &Bot,
+  dialogue: &BotDialogue,
+  ctx: &SharedContext,
+  query: &CallbackQuery,
+  bid_id: i64,
+) -> Result<Option<String>> {
+  let user_id = query.from.id.0 as i64;
+  if !ctx.is_admin(user_id) {
+    return Ok(Some("🛡️ Admins only.".to_string()));
+  }
+  let Some(chat_id) = query.message.as_ref().map(|message| message.chat().id) else {
+    return Ok(None);
+  };
+
+  let Some(bid) = ctx.db().get_bid(bid_id).await? else {
+    return Ok(Some("❓ Bid not found".to_string()));
+  };
+  if bid.retracted_at.is_some() {
+    return Ok(Some("↩️ Bid already retracted".to_string()));
+  }
+  let Some(item) = ctx.db().get_item(bid.item_id).await? else {
+    return Ok(Some("❓ Item not found".to_string()));
+  };
+  if !accepts_retractions(&item) {
+    return Ok(Some(CLOSED_ITEM.to_string()));
+  }
+
+  dialogue.reset().await?;
+  dialogue
+    .update(ConversationState::RetractBid {
+      admin_tg_id: user_id,
+      bid_id: bid.id,
+    })
+    .await?;
+  bot
+    .send_message(
+      chat_id,
+      format!(
+        "✍️ Send the reason for retracting bid #{} ({} by {} on item #{}), or type cancel.",
+        bid.id,
+        bid_currency(ctx, bid.item_id).await?.format(bid.amount),
+        bidder_name(ctx, bid.bidder_tg_id).await?,
+        bid.item_id
+      ),
+    )
+    .await?;
+  Ok(Some("✍️ Waiting for a reason.".to_string()))
+}
+
+#[instrument(skip(bot, ctx, dialogue, msg))]
+pub async fn handle_retract_bid_message(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  msg: Message,
+  (admin_tg_id, bid_id): (i64, i64),
+) -> HandlerResult {
+  let user = msg.from.as_ref().context("message missing sender")?;
+  if user.id.0 as i64 != admin_tg_id {
+    bot
+      .send_message(msg.chat.id, "Only the admin who started this action can respond.")
+      .await?;
+    return Ok(());
+  }
+
+  let Some(reason) = msg.text().map(str::trim).filter(|t| !t.is_empty()) else {
+    bot
+      .send_message(msg.chat.id, "✍️ Send the retraction reason or type cancel to stop.")
+      .await?;
+    return Ok(());
+  };
+
+  if reason.eq_ignore_ascii_case("cancel") {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, "❌ Bid retraction cancelled.").await?;
+    return Ok(());
+  }
+
+  let Some(bid) = ctx.db().get_bid(bid_id).await? else {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, "❓ Bid not found.").await?;
+    return Ok(());
+  };
+  let Some(item) = ctx.db().get_item(bid.item_id).await? else {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, "❓ Item not found.").await?;
+    return Ok(());
+  };
+  if !accepts_retractions(&item) {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, CLOSED_ITEM).await?;
+    return Ok(());
+  }
+
+  let leader_before = ctx.db().best_bid_with_bidder(item.id).await?;
+  if !ctx.db().retract_bid(bid.id, admin_tg_id, reason).await? {
+    dialogue.reset().await?;
+    bot
+      .send_message(
+        msg.chat.id,
+        format!("↩️ Bid #{bid_id} was already retracted, or its auction has closed."),
+      )
+      .await?;
+    return Ok(());
+  }
+  let leader_after = ctx.db().best_bid_with_bidder(item.id).await?;
+  dialogue.reset().await?;
+  info!(admin_tg_id, bid_id, item_id = item.id, "bid retracted");
+  audit::record(
+    &ctx,
+    admin_tg_id,
+    AuditAction::BidRetracted,
+    AuditTarget::Bid(bid_id),
+    json!({
+      "item_id": item.id,
+      "bidder_id": bid.bidder_tg_id,
+      "amount": ctx.item_currency(&item).format(bid.amount),
+      "reason": reason,
+    }),
+  )
+  .await;
+
+  if let Err(err) = notify_retracted_bidder(&bot, &ctx, &item, &bid, reason).await {
+    warn!(error = %err, bid_id, bidder_id = bid.bidder_tg_id, "failed to notify retracted bidder");
+  }
+  if let Some((leader_id, amount)) = leader_after
+    && leader_before.map(|(id, _)| id) != Some(leader_id)
+    && leader_id != bid.bidder_tg_id
+    && let Err(err) = notify_new_leader(&bot, &ctx, &item, leader_id, amount).await
+  {
+    warn!(error = %err, item_id = item.id, leader_id, "failed to notify new leader after retraction");
+  }
+  cards::schedule_refresh(&bot, &ctx, item.id);
+
+  let summary = match leader_after {
+    Some((leader_id, amount)) => format!(
+      "↩️ Bid #{bid_id} retracted. Leading bid is now {} by {}.",
+      ctx.item_currency(&item).format(amount),
+      bidder_name(&ctx, leader_id).await?
+    ),
+    None => format!(
+      "↩️ Bid #{bid_id} retracted. No active bids remain on item #{}.",
+      item.id
+    ),
+  };
+  bot.send_message(msg.chat.id, summary).await?;
+  Ok(())
+}
+
+async fn notify_retracted_bidder(
+  bot: &Bot,
+  ctx: &SharedContext,
+  item: &ItemRow,
+  bid: &BidRow,
+  reason: &str,
+) -> Result<()> {
+  if ctx.db().notifications_disabled(bid.bidder_tg_id).await? {
+    return Ok(());
+  }
+  let locale = user_locale(ctx, bid.bidder_tg_id).await?;
+  metrics::notification(
+    "bid_retracted",
+    bot.send_message(
+      ChatId(bid.bidder_tg_id),
+      tr!(
+        locale,
+        "notify-bid-retracted",
+        amount = ctx.item_currency(item).format(bid.amount),
+        item_id = item.id,
+        title = item.title.as_str(),
+        reason = reason
+      ),
+    ),
+  )
+  .await?;
+  Ok(())
+}
+
+async fn notify_new_leader(
+  bot: &Bot,
+  ctx: &SharedContext,
+  item: &ItemRow,
+  leader_id: i64,
+  amount: i64,
+) -> Result<()> {
+  if ctx.db().notifications_disabled(leader_id).await? {
+    return Ok(());
+  }
+  let locale = user_locale(ctx, leader_id).await?;
+  metrics::notification(
+    "new_leader",
+    bot.send_message(
+      ChatId(leader_id),
+      tr!(
+        locale,
+        "notify-new-leader",
+        item_id = item.id,
+        title = item.title.as_str(),
+        amount = ctx.item_currency(item).format(amount)
+      ),
+    ),
+  )
+  .await?;
+  Ok(())
+}
+
+/// Bids can only be retracted while their auction runs. Once it closes, the winner and the order are settled.
+fn accepts_retractions(item: &ItemRow) -> bool {
+  item.is_open && !item.is_archived()
+}
+
+async fn bidder_name(ctx: &SharedContext, bidder_id: i64) -> Result<String> {
+  Ok(match ctx.db().get_user(bidder_id).await? {
+    Some(user) => user.display_name(),
+    None => format!("user {bidder_id}"),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::accepts_retractions;
+  use crate::db::testing::create_item;
+  use crate::db::testing::scratch_db;
+
+  #[tokio::test]
+  async fn retracting_the_leading_bid_hands_the_lead_back() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let item_id = create_item(&db, "Vase", None).await;
+    db.place_bid(item_id, 10, 2_000).await.unwrap();
+    let leading = db.place_bid(item_id, 20, 3_000).await.unwrap();
+    assert_eq!(db.best_bid_with_bidder(item_id).await.unwrap(), Some((20, 3_000)));
+
+    assert!(db.retract_bid(leading, 1, "shill bid").await.unwrap());
+    assert_eq!(db.best_bid_with_bidder(item_id).await.unwrap(), Some((10, 2_000)));
+    assert!(!db.retract_bid(leading, 1, "shill bid").await.unwrap());
+  }
+
+  #[tokio::test]
+  async fn rejects_retractions_once_the_auction_closed() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let closed = create_item(&db, "Closed", None).await;
+    let closed_bid = db.place_bid(closed, 10, 2_000).await.unwrap();
+    db.close_item(closed).await.unwrap();
+    let archived = create_item(&db, "Archived", None).await;
+    let archived_bid = db.place_bid(archived, 10, 2_000).await.unwrap();
+    db.archive_item(archived, 1).await.unwrap();
+
+    for (item_id, bid_id) in [(closed, closed_bid), (archived, archived_bid)] {
+      let item = db.get_item(item_id).await.unwrap().unwrap();
+      assert!(!accepts_retractions(&item));
+      assert!(!db.retract_bid(bid_id, 1, "too late").await.unwrap());
+      assert_eq!(db.best_bid_with_bidder(item_id).await.unwrap(), Some((10, 2_000)));
+    }
+  }
+}
@@ -0,0 +1,405 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::Utc;
+use serde_json::json;
+use teloxide::dispatching::dialogue::Dialogue;
+use teloxide::prelude::*;
+use tracing::Instrument;
+use tracing::info;
+use tracing::info_span;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::DialogueStorage;
+use crate::bot::HandlerResult;
+use crate::bot::audit;
+use crate::bot::audit::SYSTEM_ACTOR;
+use crate::bot::cards;
+use crate::bot::channel;
+use crate::bot::context::AppContext;
+use crate::bot::handlers;
+use crate::bot::state::ConversationState;
+use crate::metrics;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
+use crate::models::ItemSchedule;
+
+type SharedContext = Arc<AppContext>;
+type BotDialogue = Dialogue<ConversationState, DialogueStorage>;
+
+/// How often scheduled lots are checked for publishing and closing.
+const TICK_INTERVAL: Duration = Duration::from_secs(30);
+/// How schedule times are typed and shown. They are always UTC.
+pub const TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// A schedule typed in the add item flow, before the event it names is looked up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleRequest {
+  Times {
+    publish_at: Option<DateTime<Utc>>,
+    closes_at: Option<DateTime<Utc>>,
+  },
+  Event(i64),
+}
+
+/// Publishes scheduled lots once their time comes, announcing them to the users, and closes lots whose closing time
+/// has passed.
+pub fn spawn(bot: Bot, ctx: SharedContext) {
+  tokio::spawn(
+    async move {
+      let mut interval = tokio::time::interval(TICK_INTERVAL);
+      loop {
+        interval.tick().await;
+        if let Err(err) = publish_due(&bot, &ctx).await {
+          warn!(error = %err, "failed to publish scheduled items");
+          let alert = format!("🗓 Publishing scheduled lots failed: {err:#}");
+          ctx.alerts().raise(&bot, "scheduler:publish", alert).await;
+        }
+        if let Err(err) = close_due(&bot, &ctx).await {
+          warn!(error = %err, "failed to close scheduled items");
+          let alert = format!("⏰ Closing scheduled lots failed: {err:#}");
+          ctx.alerts().raise(&bot, "scheduler:close", alert).await;
+        }
+      }
+    }
+    .instrument(info_span!("scheduler")),
+  );
+}
+
+/// Makes due lots visible, then posts and announces every lot still pending. A lot that fails stays pending and is
+/// retried on the next tick, without holding back the others.
+async fn publish_due(bot: &Bot, ctx: &SharedContext) -> Result<()> {
+  let due_ids = ctx.db().publish_due_items().await?;
+  if !due_ids.is_empty() {
+    info!(?due_ids, "published scheduled items");
+  }
+  let pending_ids = ctx.db().list_pending_publications().await?;
+  if pending_ids.is_empty() {
+    return Ok(());
+  }
+
+  let mut items = Vec::with_capacity(pending_ids.len());
+  for item_id in pending_ids {
+    let item = match ctx.db().get_item(item_id).await {
+      Ok(Some(item)) => item,
+      Ok(None) => continue,
+      Err(err) => {
+        warn!(error = %err, item_id, "failed to load scheduled item, retrying on the next tick");
+        continue;
+      },
+    };
+    if let Err(err) = channel::publish_item(bot, ctx, item_id).await {
+      warn!(error = %err, item_id, "failed to publish scheduled item to channel, retrying on the next tick");
+      continue;
+    }
+    cards::schedule_refresh(bot, ctx, item_id);
+    items.push(item);
+  }
+  if items.is_empty() {
+    return Ok(());
+  }
+
+  let item_ids: Vec<i64> = items.iter().map(|item| item.id).collect();
+  let count = items.len();
+  items.retain(|item| item.is_new);
+  let text = if items.is_empty() {
+    format!("🗓 Published {count} scheduled lot(s).")
+  } else {
+    match handlers::announce_new_lots(bot, ctx, SYSTEM_ACTOR, &items).await? {
+      Some(delivered) => format!("🗓 Published {count} scheduled lot(s) and announced them to {delivered} user(s)."),
+      None => format!("🗓 Published {count} scheduled lot(s). No users are registered to hear about them."),
+    }
+  };
+  ctx.db().finish_publications(&item_ids).await?;
+  notify_admins(bot, ctx, &text).await;
+  Ok(())
+}
+
+async fn close_due(bot: &Bot, ctx: &SharedContext) -> Result<()> {
+  for item_id in ctx.db().list_items_due_to_close().await? {
+    let Some(item) = ctx.db().get_item(item_id).await? else {
+      continue;
+    };
+    if !handlers::close_item(bot, ctx, SYSTEM_ACTOR, &item).await? {
+      continue;
+    }
+    cards::schedule_refresh(bot, ctx, item_id);
+    let text = format!("⏰ Item #{item_id} \"{}\" closed on schedule.", item.title);
+    notify_admins(bot, ctx, &text).await;
+  }
+  Ok(())
+}
+
+async fn notify_admins(bot: &Bot, ctx: &SharedContext, text: &str) {
+  for admin_id in ctx.admin_ids() {
+    if let Err(err) = metrics::notification("scheduler_admin", bot.send_message(ChatId(admin_id), text)).await {
+      warn!(error = %err, admin_id, "failed to tell admin about scheduled lots");
+    }
+  }
+}
+
+fn parse_time(value: &str) -> Result<DateTime<Utc>, String> {
+  let value = value.trim();
+  NaiveDateTime::parse_from_str(value, TIME_FORMAT)
+    .map(|time| time.and_utc())
+    .map_err(|_| format!("\"{value}\" is not a time like 2025-12-01 18:00"))
+}
+
+fn optional_time(value: &str) -> Result<Option<DateTime<Utc>>, String> {
+  match value.trim() {
+    "" | "-" => Ok(None),
+    value => parse_time(value).map(Some),
+  }
+}
+
+/// Parses the schedule step of the add item flow: `-` publishes right away, `#3` joins auction event 3 and
+/// `2025-12-01 18:00..2025-12-03 20:00` publishes at the first time and closes at the second. Either end of the range
+/// may be `-` or left out.
+pub fn parse_schedule(text: &str, now: DateTime<Utc>) -> Result<ScheduleRequest, String> {
+  let text = text.trim();
+  if let Some(id) = text.strip_prefix('#') {
+    return id
+      .trim()
+      .parse()
+      .map(ScheduleRequest::Event)
+      .map_err(|_| format!("\"{text}\" is not an event id"));
+  }
+  let (start, end) = text.split_once("..").unwrap_or((text, ""));
+  let publish_at = optional_time(start)?;
+  let closes_at = optional_time(end)?;
+  if publish_at.is_some_and(|publish_at| publish_at <= now) {
+    return Err("the publish time has already passed. Send - to publish right away".to_string());
+  }
+  if let Some(closes_at) = closes_at
+    && closes_at <= publish_at.unwrap_or(now)
+  {
+    return Err("the closing time must come after the lot goes live".to_string());
+  }
+  Ok(ScheduleRequest::Times { publish_at, closes_at })
+}
+
+/// Turns a typed schedule into the one stored with the item. `Err` holds a message for the admin.
+pub async fn resolve_schedule(
+  ctx: &AppContext,
+  request: ScheduleRequest,
+  now: DateTime<Utc>,
+) -> Result<Result<ItemSchedule, String>> {
+  let event_id = match request {
+    ScheduleRequest::Times { publish_at, closes_at } => {
+      return Ok(Ok(ItemSchedule {
+        publish_at,
+        closes_at,
+        event_id: None,
+      }));
+    },
+    ScheduleRequest::Event(event_id) => event_id,
+  };
+  Ok(match ctx.db().get_auction_event(event_id).await? {
+    None => Err(format!("auction event #{event_id} does not exist")),
+    Some(event) if event.ends_at <= now => Err(format!("auction event #{event_id} is already over")),
+    Some(event) => Ok(event.schedule(now)),
+  })
+}
+
+/// Parses a new auction event typed as `name | start | end`.
+fn parse_event(text: &str, now: DateTime<Utc>) -> Result<(String, DateTime<Utc>, DateTime<Utc>), String> {
+  let parts: Vec<&str> = text.split('|').map(str::trim).collect();
+  let [name, start, end] = parts[..] else {
+    return Err("send the event as name | start | end".to_string());
+  };
+  if name.is_empty() {
+    return Err("the event needs a name".to_string());
+  }
+  let (starts_at, ends_at) = (parse_time(start)?, parse_time(end)?);
+  if ends_at <= starts_at {
+    return Err("the event must end after it starts".to_string());
+  }
+  if ends_at <= now {
+    return Err("the event would already be over".to_string());
+  }
+  Ok((name.to_string(), starts_at, ends_at))
+}
+
+/// Lists the upcoming auction events and the lots waiting to go live, then asks for a new event.
+pub async fn send_events(bot: &Bot, ctx: &AppContext, chat: ChatId) -> HandlerResult {
+  let events = ctx.db().list_upcoming_auction_events().await?;
+  let scheduled = ctx.db().list_scheduled_items().await?;
+  let mut text = String::from("🗓 Auction events");
+  if events.is_empty() {
+    text.push_str("\n\nNo upcoming events.");
+  }
+  for (event, lot_count) in &events {
+    text.push_str(&format!(
+      "\n\n#{} {}\n{} – {} UTC · {lot_count} lot(s)",
+      event.id,
+      event.name,
+      event.starts_at.format(TIME_FORMAT),
+      event.ends_at.format(TIME_FORMAT)
+    ));
+  }
+  if !scheduled.is_empty() {
+    text.push_str("\n\n⏳ Waiting to go live:");
+    for item in &scheduled {
+      let publish_at = item.publish_at.map(|time| time.format(TIME_FORMAT).to_string());
+      text.push_str(&format!(
+        "\n#{} {} · {} UTC",
+        item.id,
+        item.title,
+        publish_at.unwrap_or_default()
+      ));
+    }
+  }
+  text.push_str(
+    "\n\nTo create an event, send its name, start and end in UTC:\nSpring sale | 2025-12-01 18:00 | 2025-12-03 \
+     20:00\n\nLots join an event when they are added with its number, like #3. Type cancel to stop.",
+  );
+  bot.send_message(chat, text).await?;
+  Ok(())
+}
+
+#[instrument(skip(bot, ctx, dialogue, msg))]
+pub async fn handle_create_event_message(
+  bot: Bot,
+  dialogue: BotDialogue,
+  ctx: SharedContext,
+  msg: Message,
+  admin_tg_id: i64,
+) -> HandlerResult {
+  let user = msg.from.as_ref().context("message missing sender")?;
+  if user.id.0 as i64 != admin_tg_id {
+    bot
+      .send_message(msg.chat.id, "Only the admin who started this action can respond.")
+      .await?;
+    return Ok(());
+  }
+
+  let Some(raw_text) = msg.text().map(str::trim).filter(|t| !t.is_empty()) else {
+    bot
+      .send_message(msg.chat.id, "🗓 Send the event as name | start | end, or type cancel.")
+      .await?;
+    return Ok(());
+  };
+  if raw_text.eq_ignore_ascii_case("cancel") {
+    dialogue.reset().await?;
+    bot.send_message(msg.chat.id, "❌ Event creation cancelled.").await?;
+    return Ok(());
+  }
+
+  let (name, starts_at, ends_at) = match parse_event(raw_text, Utc::now()) {
+    Ok(event) => event,
+    Err(err) => {
+      bot.send_message(msg.chat.id, format!("⚠️ {err}")).await?;
+      return Ok(());
+    },
+  };
+  let event = ctx
+    .db()
+    .create_auction_event(&name, starts_at, ends_at, admin_tg_id)
+    .await?;
+  dialogue.reset().await?;
+  info!(admin_tg_id, event_id = event.id, "created auction event");
+  audit::record(
+    &ctx,
+    admin_tg_id,
+    AuditAction::EventCreated,
+    AuditTarget::Event(event.id),
+    json!({ "name": event.name, "starts_at": event.starts_at, "ends_at": event.ends_at }),
+  )
+  .await;
+  bot
+    .send_message(
+      msg.chat.id,
+      format!(
+        "🗓 Auction event #{} \"{}\" created. Add lots to it by answering #{} when asked for the schedule.",
+        event.id, event.name, event.id
+      ),
+    )
+    .await?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::TimeZone;
+  use chrono::Utc;
+
+  use super::ScheduleRequest;
+  use super::parse_event;
+  use super::parse_schedule;
+  use crate::models::AuctionEventRow;
+
+  #[test]
+  fn parses_schedules() {
+    let now = Utc.with_ymd_and_hms(2025, 11, 30, 12, 0, 0).unwrap();
+    let publish_at = Utc.with_ymd_and_hms(2025, 12, 1, 18, 0, 0).unwrap();
+    let closes_at = Utc.with_ymd_and_hms(2025, 12, 3, 20, 0, 0).unwrap();
+    assert_eq!(
+      parse_schedule("-", now),
+      Ok(ScheduleRequest::Times {
+        publish_at: None,
+        closes_at: None,
+      })
+    );
+    assert_eq!(parse_schedule(" #3 ", now), Ok(ScheduleRequest::Event(3)));
+    assert_eq!(
+      parse_schedule("2025-12-01 18:00", now),
+      Ok(ScheduleRequest::Times {
+        publish_at: Some(publish_at),
+        closes_at: None,
+      })
+    );
+    assert_eq!(
+      parse_schedule("2025-12-01 18:00 .. 2025-12-03 20:00", now),
+      Ok(ScheduleRequest::Times {
+        publish_at: Some(publish_at),
+        closes_at: Some(closes_at),
+      })
+    );
+    assert_eq!(
+      parse_schedule("-..2025-12-03 20:00", now),
+      Ok(ScheduleRequest::Times {
+        publish_at: None,
+        closes_at: Some(closes_at),
+      })
+    );
+  }
+
+  #[test]
+  fn rejects_bad_schedules() {
+    let now = Utc.with_ymd_and_hms(2025, 12, 2, 12, 0, 0).unwrap();
+    assert!(parse_schedule("tomorrow", now).unwrap_err().contains("tomorrow"));
+    assert!(parse_schedule("#three", now).is_err());
+    assert!(parse_schedule("2025-12-01 18:00", now).unwrap_err().contains("passed"));
+    assert!(parse_schedule("2025-12-04 18:00..2025-12-03 20:00", now).is_err());
+    assert!(parse_schedule("-..2025-12-01 20:00", now).is_err());
+  }
+
+  #[test]
+  fn parses_events_and_schedules_their_lots() {
+    let now = Utc.with_ymd_and_hms(2025, 11, 30, 12, 0, 0).unwrap();
+    let (name, starts_at, ends_at) = parse_event("Spring sale | 2025-12-01 18:00 | 2025-12-03 20:00", now).unwrap();
+    assert_eq!(name, "Spring sale");
+    assert!(parse_event("Spring sale | 2025-12-03 20:00 | 2025-12-01 18:00", now).is_err());
+    assert!(parse_event(" | 2025-12-01 18:00 | 2025-12-03 20:00", now).is_err());
+    assert!(parse_event("Spring sale | 2025-12-01 18:00", now).is_err());
+
+    let event = AuctionEventRow {
+      id: 3,
+      name,
+      starts_at,
+      ends_at,
+      created_by: 1,
+      created_at: now,
+    };
+    let schedule = event.schedule(now);
+    assert_eq!(schedule.publish_at, Some(starts_at));
+    assert_eq!(schedule.closes_at, Some(ends_at));
+    assert_eq!(schedule.event_id, Some(3));
+    assert_eq!(event.schedule(starts_at).publish_at, None);
+  }
+}
@@ -0,0 +1,474 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::TimeDelta;
+use chrono::Utc;
+use serde_json::json;
+use teloxide::ApiError;
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::types::CallbackQuery;
+use teloxide::types::InlineKeyboardButton;
+use teloxide::types::InlineKeyboardMarkup;
+use tracing::Instrument;
+use tracing::info;
+use tracing::info_span;
+use tracing::instrument;
+use tracing::warn;
+
+use crate::bot::HandlerResult;
+use crate::bot::audit;
+use crate::bot::callback;
+use crate::bot::callback::CallbackData;
+use crate::bot::context::AppContext;
+use crate::bot::handlers::user_locale;
+use crate::bot::payments;
+use crate::db::Db;
+use crate::i18n::Locale;
+use crate::i18n::tr;
+use crate::metrics;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
+use crate::models::ItemRow;
+use crate::models::OrderStatus;
+use crate::models::SecondChanceOfferRow;
+
+type SharedContext = Arc<AppContext>;
+
+/// How long a runner-up has to answer before the lot moves on to the next bidder.
+const OFFER_TTL: TimeDelta = TimeDelta::hours(24);
+/// How often pending offers are checked for expiry.
+const EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The admin's offer of a closed lot to the runner-up, and the runner-up's answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecondChanceAction {
+  Offer { item_id: i64 },
+  Accept { offer_id: i64 },
+  Decline { offer_id: i64 },
+}
+
+enum OfferOutcome {
+  Offered(SecondChanceOfferRow),
+  AlreadyPending,
+  NoCandidates,
+}
+
+/// Picks the best-ranked bidder that has not been offered the lot yet. `ranked` holds each bidder's highest bid,
+/// best first.
+fn next_candidate(ranked: &[(i64, i64)], excluded: &HashSet<i64>) -> Option<(i64, i64)> {
+  ranked
+    .iter()
+    .find(|(bidder_id, _)| !excluded.contains(bidder_id))
+    .copied()
+}
+
+pub fn offer_button(item_id: i64) -> InlineKeyboardButton {
+  CallbackData::SecondChance(SecondChanceAction::Offer { item_id }).button("🥈 Second chance")
+}
+
+/// Handles every second-chance callback: the admin action on a closed item and the runner-up's answer.
+#[instrument(skip(bot, ctx, query))]
+pub async fn handle_callback(
+  bot: Bot,
+  ctx: SharedContext,
+  query: CallbackQuery,
+  action: SecondChanceAction,
+) -> HandlerResult {
+  let text = apply_action(&bot, &ctx, &query, action).await?;
+  callback::answer(&bot, &query, text).await
+}
+
+/// Runs a second-chance action and returns the text for the callback answer, if any.
+async fn apply_action(
+  bot: &Bot,
+  ctx: &SharedContext,
+  query: &CallbackQuery,
+  action: SecondChanceAction,
+) -> Result<Option<String>> {
+  let user_id = query.from.id.0 as i64;
+  let locale = user_locale(ctx, user_id).await?;
+
+  match action {
+    SecondChanceAction::Offer { item_id } => {
+      if !ctx.is_admin(user_id) {
+        return Ok(Some("🛡️ Admins only.".to_string()));
+      }
+      let Some(item) = ctx.db().get_item(item_id).await? else {
+        return Ok(Some("❓ Item not found".to_string()));
+      };
+      if item.is_open {
+        return Ok(Some("Close the auction first.".to_string()));
+      }
+      if let Some(order) = ctx.db().get_item_order(item_id).await?
+        && order.status != OrderStatus::Cancelled
+      {
+        return Ok(Some(format!("Cancel order #{} first.", order.id)));
+      }
+
+      Ok(Some(match offer_next(bot, ctx, &item, user_id).await? {
+        OfferOutcome::Offered(offer) => {
+          audit::record(
+            ctx,
+            user_id,
+            AuditAction::SecondChanceOffered,
+            AuditTarget::Item(item.id),
+            json!({ "offer_id": offer.id, "bidder_id": offer.bidder_tg_id, "amount": ctx.item_currency(&item).format(offer.amount) }),
+          )
+          .await;
+          format!("🥈 Offered at {}.", ctx.item_currency(&item).format(offer.amount))
+        },
+        OfferOutcome::AlreadyPending => "An offer for this lot is still pending.".to_string(),
+        OfferOutcome::NoCandidates => "No other bidders left to offer this lot to.".to_string(),
+      }))
+    },
+    SecondChanceAction::Accept { offer_id } => {
+      let Some(offer) = ctx.db().answer_second_chance_offer(offer_id, user_id, true).await? else {
+        close_offer_prompt(bot, query, &tr!(locale, "second-chance-unavailable")).await?;
+        return Ok(None);
+      };
+      info!(
+        offer_id,
+        user_id,
+        item_id = offer.item_id,
+        "second-chance offer accepted"
+      );
+      let Some(item) = ctx.db().get_item(offer.item_id).await? else {
+        return Ok(Some("❓ Item not found".to_string()));
+      };
+
+      let Some(order) = ctx.db().reassign_order(item.id, user_id, offer.amount).await? else {
+        warn!(
+          offer_id,
+          item_id = item.id,
+          "accepted offer but the item already has an active order"
+        );
+        close_offer_prompt(bot, query, &tr!(locale, "second-chance-sold-meanwhile")).await?;
+        notify_admins(
+          bot,
+          ctx,
+          &format!(
+            "⚠️ Offer #{offer_id} for item #{} was accepted, but the item already has an active order.",
+            item.id
+          ),
+        )
+        .await;
+        return Ok(None);
+      };
+
+      close_offer_prompt(
+        bot,
+        query,
+        &tr!(
+          locale,
+          "second-chance-bought",
+          item_id = item.id,
+          title = item.title.as_str(),
+          amount = ctx.item_currency(&item).format(order.amount)
+        ),
+      )
+      .await?;
+      notify_admins(
+        bot,
+        ctx,
+        &format!(
+          "🥈 Second-chance offer accepted: item #{} ({}) goes to {} for {}. Order #{}.",
+          item.id,
+          item.title,
+          query.from.full_name(),
+          ctx.item_currency(&item).format(order.amount),
+          order.id
+        ),
+      )
+      .await;
+      if let Some(provider_token) = ctx.payment_provider_token()
+        && let Err(err) =
+          payments::send_order_invoice(bot, provider_token, locale, ctx.item_currency(&item), &order, &item).await
+      {
+        warn!(error = %err, order_id = order.id, "failed to send second-chance invoice");
+      }
+      Ok(None)
+    },
+    SecondChanceAction::Decline { offer_id } => {
+      let Some(offer) = ctx.db().answer_second_chance_offer(offer_id, user_id, false).await? else {
+        close_offer_prompt(bot, query, &tr!(locale, "second-chance-unavailable")).await?;
+        return Ok(None);
+      };
+      info!(
+        offer_id,
+        user_id,
+        item_id = offer.item_id,
+        "second-chance offer declined"
+      );
+      close_offer_prompt(bot, query, &tr!(locale, "second-chance-declined")).await?;
+      cascade(bot, ctx, &offer, "declined").await?;
+      Ok(None)
+    },
+  }
+}
+
+/// Expires overdue offers and moves each lot on to its next bidder. Runs in the background for the lifetime of the
+/// bot.
+pub fn spawn_expiry_sweeper(bot: Bot, ctx: SharedContext) {
+  tokio::spawn(
+    async move {
+      let mut interval = tokio::time::interval(EXPIRY_SWEEP_INTERVAL);
+      loop {
+        interval.tick().await;
+        if let Err(err) = expire_offers(&bot, &ctx).await {
+          warn!(error = %err, "failed to expire second-chance offers");
+        }
+      }
+    }
+    .instrument(info_span!("second_chance_expiry")),
+  );
+}
+
+/// Expired offers are no longer pending, so a later sweep will not pick them up again. A failing offer is therefore
+/// alerted on and skipped rather than stopping the lots after it from moving on.
+async fn expire_offers(bot: &Bot, ctx: &SharedContext) -> Result<()> {
+  for offer in ctx.db().expire_second_chance_offers().await? {
+    info!(
+      offer_id = offer.id,
+      item_id = offer.item_id,
+      "second-chance offer expired"
+    );
+    if let Err(err) = expire_offer(bot, ctx, &offer).await {
+      let alert = format!(
+        "🥈 Offer #{} for item #{} expired, but the lot could not be offered to the next bidder: {err:#}",
+        offer.id, offer.item_id
+      );
+      ctx
+        .alerts()
+        .raise(bot, &format!("second_chance:{}", offer.item_id), alert)
+        .await;
+    }
+  }
+  Ok(())
+}
+
+async fn expire_offer(bot: &Bot, ctx: &SharedContext, offer: &SecondChanceOfferRow) -> Result<()> {
+  let locale = user_locale(ctx, offer.bidder_tg_id).await?;
+  if let Err(err) = bot
+    .send_message(
+      ChatId(offer.bidder_tg_id),
+      tr!(locale, "second-chance-expired", item_id = offer.item_id),
+    )
+    .await
+  {
+    warn!(error = %err, offer_id = offer.id, "failed to tell bidder about expired offer");
+  }
+  cascade(bot, ctx, offer, "expired").await
+}
+
+/// Offers the lot of a declined or expired offer to the next bidder and tells the admins how it went.
+async fn cascade(bot: &Bot, ctx: &SharedContext, previous: &SecondChanceOfferRow, reason: &str) -> Result<()> {
+  let Some(item) = ctx.db().get_item(previous.item_id).await? else {
+    return Ok(());
+  };
+  let text = match offer_next(bot, ctx, &item, previous.offered_by).await? {
+    OfferOutcome::Offered(offer) => format!(
+      "🥈 Offer #{} for item #{} was {reason}. Offered to the next bidder at {}.",
+      previous.id,
+      item.id,
+      ctx.item_currency(&item).format(offer.amount)
+    ),
+    OfferOutcome::AlreadyPending => return Ok(()),
+    OfferOutcome::NoCandidates => format!(
+      "🥈 Offer #{} for item #{} was {reason}, and no bidders are left. Consider relisting it.",
+      previous.id, item.id
+    ),
+  };
+  notify_admins(bot, ctx, &text).await;
+  Ok(())
+}
+
+/// Returns the bidders a lot must not be offered to again: everyone who already had an offer or won it.
+async fn excluded_bidders(db: &Db, item_id: i64) -> Result<HashSet<i64>> {
+  let mut excluded: HashSet<i64> = db.list_second_chance_bidders(item_id).await?.into_iter().collect();
+  excluded.extend(db.list_item_winners(item_id).await?);
+  Ok(excluded)
+}
+
+async fn offer_next(bot: &Bot, ctx: &SharedContext, item: &ItemRow, offered_by: i64) -> Result<OfferOutcome> {
+  let ranked = ctx.db().list_bidder_best_bids(item.id).await?;
+  let excluded = excluded_bidders(ctx.db(), item.id).await?;
+  let Some((bidder_id, amount)) = next_candidate(&ranked, &excluded) else {
+    return Ok(OfferOutcome::NoCandidates);
+  };
+
+  let Some(offer) = ctx
+    .db()
+    .create_second_chance_offer(item.id, bidder_id, amount, offered_by, Utc::now() + OFFER_TTL)
+    .await?
+  else {
+    return Ok(OfferOutcome::AlreadyPending);
+  };
+  info!(
+    offer_id = offer.id,
+    item_id = item.id,
+    bidder_id,
+    amount,
+    "sent second-chance offer"
+  );
+
+  let locale = user_locale(ctx, bidder_id).await?;
+  let keyboard = offer_keyboard(locale, offer.id);
+  let text = tr!(
+    locale,
+    "second-chance-offer",
+    item_id = item.id,
+    title = item.title.as_str(),
+    amount = ctx.item_currency(item).format(amount),
+    expires = offer.expires_at.format("%d %b %H:%M").to_string()
+  );
+  let request = bot.send_message(ChatId(bidder_id), text).reply_markup(keyboard);
+  if let Err(err) = metrics::notification("second_chance_offer", request).await {
+    warn!(error = %err, offer_id = offer.id, bidder_id, "failed to deliver second-chance offer");
+  }
+  Ok(OfferOutcome::Offered(offer))
+}
+
+fn offer_keyboard(locale: Locale, offer_id: i64) -> InlineKeyboardMarkup {
+  InlineKeyboardMarkup::new(vec![vec![
+    CallbackData::SecondChance(SecondChanceAction::Accept { offer_id }).button(tr!(locale, "second-chance-buy")),
+    CallbackData::SecondChance(SecondChanceAction::Decline { offer_id })
+      .button(tr!(locale, "second-chance-no-thanks")),
+  ]])
+}
+
+async fn close_offer_prompt(bot: &Bot, query: &CallbackQuery, text: &str) -> Result<()> {
+  let Some(message) = query.message.as_ref() else {
+    return Ok(());
+  };
+  let request = bot
+    .edit_message_text(message.chat().id, message.id(), text)
+    .reply_markup(InlineKeyboardMarkup::default());
+  match request.await {
+    Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => Ok(()),
+    Err(err) => Err(err.into()),
+  }
+}
+
+async fn notify_admins(bot: &Bot, ctx: &SharedContext, text: &str) {
+  for admin_id in ctx.admin_ids() {
+    if let Err(err) = metrics::notification("second_chance_admin", bot.send_message(ChatId(admin_id), text)).await {
+      warn!(error = %err, admin_id, "failed to notify admin about second-chance offer");
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+
+  use chrono::TimeDelta;
+  use chrono::Utc;
+
+  use super::OFFER_TTL;
+  use super::excluded_bidders;
+  use super::expire_offers;
+  use super::next_candidate;
+  use crate::bot::testing::mock_bot_api;
+  use crate::bot::testing::test_context;
+  use crate::db::testing::create_item;
+  use crate::db::testing::scratch_db;
+  use crate::models::OrderStatus;
+
+  #[test]
+  fn cascades_down_the_bidder_list() {
+    let ranked = [(10, 30_000), (20, 25_000), (30, 20_000)];
+    let mut excluded = HashSet::from([10]);
+    assert_eq!(next_candidate(&ranked, &excluded), Some((20, 25_000)));
+
+    excluded.insert(20);
+    assert_eq!(next_candidate(&ranked, &excluded), Some((30, 20_000)));
+
+    excluded.insert(30);
+    assert_eq!(next_candidate(&ranked, &excluded), None);
+  }
+
+  #[tokio::test]
+  async fn never_offers_a_lot_back_to_an_earlier_winner() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let item_id = create_item(&db, "Lamp", None).await;
+    for (bidder_id, amount) in [(10, 30_000), (20, 25_000), (30, 20_000)] {
+      db.place_bid(item_id, bidder_id, amount).await.unwrap();
+    }
+    db.close_item(item_id).await.unwrap();
+    let order = db.create_order(item_id, 10, 30_000).await.unwrap();
+    db.update_order_status(order.id, OrderStatus::AwaitingPayment, OrderStatus::Cancelled)
+      .await
+      .unwrap()
+      .unwrap();
+
+    let offer = db
+      .create_second_chance_offer(item_id, 20, 25_000, 1, Utc::now() + OFFER_TTL)
+      .await
+      .unwrap()
+      .unwrap();
+    db.answer_second_chance_offer(offer.id, 20, true)
+      .await
+      .unwrap()
+      .unwrap();
+    let order = db.reassign_order(item_id, 20, 25_000).await.unwrap().unwrap();
+    db.update_order_status(order.id, OrderStatus::AwaitingPayment, OrderStatus::Cancelled)
+      .await
+      .unwrap()
+      .unwrap();
+
+    let ranked = db.list_bidder_best_bids(item_id).await.unwrap();
+    let excluded = excluded_bidders(&db, item_id).await.unwrap();
+    assert_eq!(excluded, HashSet::from([10, 20]));
+    assert_eq!(next_candidate(&ranked, &excluded), Some((30, 20_000)));
+  }
+
+  #[tokio::test]
+  async fn keeps_cascading_expired_offers_after_one_fails() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let mut items = Vec::new();
+    for (title, expired_for) in [("Broken", 20), ("Fine", 10)] {
+      let item_id = create_item(&db, title, None).await;
+      db.place_bid(item_id, 10, 30_000).await.unwrap();
+      db.place_bid(item_id, 20, 25_000).await.unwrap();
+      db.close_item(item_id).await.unwrap();
+      let expires_at = Utc::now() - TimeDelta::minutes(expired_for);
+      db.create_second_chance_offer(item_id, 10, 30_000, 1, expires_at)
+        .await
+        .unwrap()
+        .unwrap();
+      items.push(item_id);
+    }
+    let (broken, fine) = (items[0], items[1]);
+    // The lot whose offer expired first cannot be offered on, so it fails before the other one is handled.
+    sqlx::raw_sql(&format!(
+      r#"
+      CREATE FUNCTION reject_offer() RETURNS trigger AS $$
+      BEGIN
+        RAISE EXCEPTION 'offers for item {broken} are broken';
+      END
+      $$ LANGUAGE plpgsql;
+      CREATE TRIGGER reject_offer BEFORE INSERT ON second_chance_offers
+      FOR EACH ROW WHEN (NEW.item_id = {broken}) EXECUTE FUNCTION reject_offer();
+      "#
+    ))
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let (bot, _) = mock_bot_api().await;
+    let ctx = test_context(db, &[1]);
+    expire_offers(&bot, &ctx).await.unwrap();
+
+    let mut offered = ctx.db().list_second_chance_bidders(fine).await.unwrap();
+    offered.sort_unstable();
+    assert_eq!(offered, vec![10, 20]);
+    assert_eq!(ctx.db().list_second_chance_bidders(broken).await.unwrap(), vec![10]);
+  }
+}
@@ -2,6 +2,8 @@ use serde::Deserialize;
 use serde::Serialize;
 use teloxide::types::FileId;
 
+use crate::models::NewItem;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case", tag = "kind", content = "data")]
 pub enum ConversationState {
@@ -24,6 +26,25 @@ pub enum ConversationState {
   Broadcast {
     admin_tg_id: i64,
   },
+  Export {
+    admin_tg_id: i64,
+  },
+  CreateEvent {
+    admin_tg_id: i64,
+  },
+  /// Waiting for an import file. `lots` holds the rows of a clean dry run until they are confirmed.
+  Import {
+    admin_tg_id: i64,
+    lots: Vec<NewItem>,
+  },
+  RetractBid {
+    admin_tg_id: i64,
+    bid_id: i64,
+  },
+  Relist {
+    admin_tg_id: i64,
+    item_id: i64,
+  },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -59,6 +80,8 @@ pub enum DraftStage {
   Title,
   Description,
   StartPrice,
+  /// When the lot goes live and closes, or the auction event it joins.
+  Schedule,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
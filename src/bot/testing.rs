@@ -0,0 +1,86 @@
+//! Stand-ins for Telegram and the bot context in tests.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use serde_json::Value;
+use serde_json::json;
+use teloxide::Bot;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::net::TcpListener;
+
+use crate::bot::context::AppContext;
+use crate::config::Config;
+use crate::db::Db;
+
+pub type Calls = Arc<Mutex<Vec<(String, Value)>>>;
+
+/// A minimal stand-in for the Bot API that records every method call and answers with a canned result.
+pub async fn mock_bot_api() -> (Bot, Calls) {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let url = format!("http://{}/", listener.local_addr().unwrap());
+  let calls = Calls::default();
+  let recorded = calls.clone();
+  tokio::spawn(async move {
+    while let Ok((socket, _)) = listener.accept().await {
+      let recorded = recorded.clone();
+      tokio::spawn(async move {
+        let mut socket = BufReader::new(socket);
+        loop {
+          let mut request_line = String::new();
+          if socket.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+            return;
+          }
+          let mut content_length = 0;
+          loop {
+            let mut header = String::new();
+            socket.read_line(&mut header).await.unwrap();
+            if header.trim().is_empty() {
+              break;
+            }
+            if let Some((name, value)) = header.split_once(':')
+              && name.eq_ignore_ascii_case("content-length")
+            {
+              content_length = value.trim().parse().unwrap();
+            }
+          }
+          let mut body = vec![0; content_length];
+          socket.read_exact(&mut body).await.unwrap();
+
+          let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+          let method = path.rsplit('/').next().unwrap_or_default().to_string();
+          let params = serde_json::from_slice(&body).unwrap_or(Value::Null);
+          let result = match method.as_str() {
+            "AnswerPreCheckoutQuery" | "answerPreCheckoutQuery" => json!(true),
+            _ => json!({
+              "message_id": 1,
+              "date": 0,
+              "chat": { "id": 42, "type": "private", "first_name": "Winner" },
+              "text": "ok"
+            }),
+          };
+          recorded.lock().unwrap().push((method, params));
+
+          let response = json!({ "ok": true, "result": result }).to_string();
+          let head = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n",
+            response.len()
+          );
+          let stream = socket.get_mut();
+          stream.write_all(head.as_bytes()).await.unwrap();
+          stream.write_all(response.as_bytes()).await.unwrap();
+        }
+      });
+    }
+  });
+  let bot = Bot::new("123:TEST").set_api_url(url::Url::parse(&url).unwrap());
+  (bot, calls)
+}
+
+/// A context over `db` with the given admins and otherwise default settings.
+pub fn test_context(db: Db, admins: &[i64]) -> Arc<AppContext> {
+  Arc::new(AppContext::new(db, &Config::for_tests(admins)))
+}
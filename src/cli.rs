@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use clap::Args;
+use clap::Parser;
+use clap::Subcommand;
+
+use crate::bot::export::Dataset;
+use crate::bot::export::ExportFormat;
+
+/// Telegram auction bot.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+  /// TOML config file. Environment variables override it, `--set` overrides both.
+  #[arg(long, short, env = "CONFIG_FILE", value_name = "PATH", global = true)]
+  pub config: Option<PathBuf>,
+  /// Overrides one setting, like `--set auction.currency=USD`. May be repeated.
+  #[arg(long = "set", value_name = "KEY=VALUE", global = true)]
+  pub overrides: Vec<String>,
+  /// Prints the effective configuration with secrets redacted, then exits.
+  #[arg(long)]
+  pub print_config: bool,
+  /// Runs one maintenance task instead of the bot.
+  #[command(subcommand)]
+  pub command: Option<Command>,
+}
+
+/// Maintenance tasks for operators, for when the Telegram UI is unusable or no admin can reach it.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+  /// Applies pending database migrations.
+  Migrate,
+  /// Closes an open item, opens the winner's order and notifies everyone involved.
+  CloseItem {
+    /// Item id.
+    item_id: i64,
+  },
+  /// Exports items with their results, the bid ledger or the user list for accounting.
+  Export(ExportArgs),
+  /// Sends the text of a file to every user who has notifications on.
+  Broadcast {
+    /// File with the announcement text.
+    #[arg(long, value_name = "PATH")]
+    file: PathBuf,
+  },
+  /// Grants admin rights to a Telegram user. The running bot picks the grant up when it restarts.
+  GrantAdmin {
+    /// Telegram user id.
+    tg_id: i64,
+  },
+  /// Lists open items with their best bids.
+  ListOpen,
+}
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+  /// What to export.
+  #[arg(value_enum, default_value = "items")]
+  pub dataset: Dataset,
+  #[arg(long, value_enum, default_value = "csv")]
+  pub format: ExportFormat,
+  /// First day to include, like 2025-11-01.
+  #[arg(long, value_name = "DATE")]
+  pub from: Option<NaiveDate>,
+  /// Last day to include.
+  #[arg(long, value_name = "DATE")]
+  pub to: Option<NaiveDate>,
+  /// Only records of items in this category.
+  #[arg(long, value_name = "ID")]
+  pub category: Option<i64>,
+  /// File to write to instead of stdout.
+  #[arg(long, short, value_name = "PATH", conflicts_with = "send_to")]
+  pub output: Option<PathBuf>,
+  /// Sends the export as a Telegram document to this chat instead.
+  #[arg(long, value_name = "CHAT_ID")]
+  pub send_to: Option<i64>,
+}
@@ -0,0 +1,147 @@
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use serde_json::json;
+use teloxide::prelude::*;
+use tracing::info;
+
+use crate::bot::AppContext;
+use crate::bot::audit;
+use crate::bot::audit::SYSTEM_ACTOR;
+use crate::bot::cards;
+use crate::bot::export;
+use crate::bot::export::ExportRequest;
+use crate::bot::handlers;
+use crate::bot::scheduler;
+use crate::cli::Command;
+use crate::cli::ExportArgs;
+use crate::config::Config;
+use crate::db::Db;
+use crate::db::MIGRATOR;
+use crate::models::AuditAction;
+use crate::models::AuditTarget;
+
+/// Runs an operator subcommand against the same database and helpers as the bot. Results go to stdout, logs to
+/// stderr.
+pub async fn run(command: Command, bot: Bot, db: Db, config: &Config) -> Result<()> {
+  let ctx = Arc::new(AppContext::new(db, config));
+  match command {
+    // Connecting already applied them.
+    Command::Migrate => println!(
+      "Database schema is up to date ({} migrations).",
+      MIGRATOR.migrations.len()
+    ),
+    Command::CloseItem { item_id } => close_item(&bot, &ctx, item_id).await?,
+    Command::Export(args) => export(&bot, &ctx, args).await?,
+    Command::Broadcast { file } => broadcast(&bot, &ctx, &file).await?,
+    Command::GrantAdmin { tg_id } => grant_admin(&ctx, tg_id).await?,
+    Command::ListOpen => list_open(&ctx).await?,
+  }
+  Ok(())
+}
+
+async fn close_item(bot: &Bot, ctx: &Arc<AppContext>, item_id: i64) -> Result<()> {
+  let Some(item) = ctx.db().get_item(item_id).await? else {
+    bail!("item #{item_id} not found");
+  };
+  if !item.is_open {
+    println!("Item #{item_id} is already closed.");
+    return Ok(());
+  }
+  if !handlers::close_item(bot, ctx, SYSTEM_ACTOR, &item).await? {
+    println!("Item #{item_id} is already closed.");
+    return Ok(());
+  }
+  cards::refresh_item(bot, ctx, item_id).await;
+  println!("Item #{item_id} \"{}\" closed.", item.title);
+  Ok(())
+}
+
+async fn export(bot: &Bot, ctx: &AppContext, args: ExportArgs) -> Result<()> {
+  let request = ExportRequest {
+    dataset: args.dataset,
+    format: args.format,
+    filter: export::date_filter(args.from, args.to, args.category)?,
+  };
+  let file = export::build(ctx.db(), &request).await?;
+  let rows = file.rows;
+  match (args.output, args.send_to) {
+    (Some(path), _) => {
+      std::fs::write(&path, &file.contents).with_context(|| format!("failed to write {}", path.display()))?;
+      println!("Exported {rows} row(s) to {}.", path.display());
+    },
+    (None, Some(chat_id)) => {
+      export::send(bot, ChatId(chat_id), file).await?;
+      println!("Sent {rows} row(s) to chat {chat_id}.");
+    },
+    (None, None) => std::io::stdout().write_all(&file.contents)?,
+  }
+  Ok(())
+}
+
+async fn broadcast(bot: &Bot, ctx: &Arc<AppContext>, file: &Path) -> Result<()> {
+  let text = std::fs::read_to_string(file).with_context(|| format!("failed to read {}", file.display()))?;
+  let text = text.trim();
+  if text.is_empty() {
+    bail!("{} is empty", file.display());
+  }
+
+  let recipients = ctx.db().list_user_ids().await?;
+  info!(recipient_count = recipients.len(), "broadcasting from the console");
+  let delivered = handlers::broadcast_text(bot, ctx, &recipients, text, None).await;
+  audit::record(
+    ctx,
+    SYSTEM_ACTOR,
+    AuditAction::Broadcast,
+    AuditTarget::Users,
+    json!({ "text": text, "recipients": recipients.len(), "delivered": delivered }),
+  )
+  .await;
+  println!("Broadcast sent to {delivered} of {} user(s).", recipients.len());
+  Ok(())
+}
+
+async fn grant_admin(ctx: &AppContext, tg_id: i64) -> Result<()> {
+  if ctx.is_admin(tg_id) {
+    println!("User {tg_id} is already an admin.");
+    return Ok(());
+  }
+  ctx.db().grant_admin(tg_id, SYSTEM_ACTOR).await?;
+  audit::record(
+    ctx,
+    SYSTEM_ACTOR,
+    AuditAction::AdminGranted,
+    AuditTarget::User(tg_id),
+    json!({}),
+  )
+  .await;
+  println!("User {tg_id} is now an admin. Restart the bot for the grant to take effect.");
+  Ok(())
+}
+
+async fn list_open(ctx: &AppContext) -> Result<()> {
+  let items = ctx.db().list_open_items_with_best_bid().await?;
+  for (item, best_bid) in &items {
+    let currency = ctx.item_currency(item);
+    let price = match best_bid {
+      Some(amount) => format!("best bid {}", currency.format(*amount)),
+      None => format!("no bids, starts at {}", currency.format(item.start_price)),
+    };
+    let schedule = match item.publish_at {
+      Some(publish_at) => format!(" · goes live {}", publish_at.format(scheduler::TIME_FORMAT)),
+      None => String::new(),
+    };
+    println!(
+      "#{} {} · {price} · listed {}{schedule}",
+      item.id,
+      item.title,
+      item.created_at.format("%Y-%m-%d %H:%M")
+    );
+  }
+  println!("{} open item(s).", items.len());
+  Ok(())
+}
@@ -1,6 +1,26 @@
+use crate::config::DbConfig;
+use crate::models::AuctionEventRow;
+use crate::models::AuditAction;
+use crate::models::AuditEntryRow;
+use crate::models::AuditTarget;
+use crate::models::BidLedgerRow;
+use crate::models::BidRow;
+use crate::models::BidTotals;
 use crate::models::CategoryRow;
+use crate::models::ExportFilter;
+use crate::models::ItemCardRow;
+use crate::models::ItemResultRow;
 use crate::models::ItemRow;
+use crate::models::NewItem;
+use crate::models::NewItemCategory;
+use crate::models::OrderRow;
+use crate::models::OrderStatus;
+use crate::models::SecondChanceOfferRow;
+use crate::models::UserRow;
+use anyhow::Context;
 use anyhow::Result;
+use chrono::DateTime;
+use chrono::Utc;
 use sqlx::Pool;
 use sqlx::Postgres;
 use sqlx::Row;
@@ -17,9 +37,13 @@ pub struct Db {
 }
 
 impl Db {
-  pub async fn connect(database_url: &str) -> Result<Self> {
-    let pool = PgPoolOptions::new().max_connections(10).connect(database_url).await?;
-    MIGRATOR.run(&pool).await?;
+  pub async fn connect(config: &DbConfig) -> Result<Self> {
+    let pool = PgPoolOptions::new()
+      .max_connections(config.max_connections)
+      .connect(&config.url)
+      .await
+      .context("failed to connect to the database")?;
+    MIGRATOR.run(&pool).await.context("failed to run database migrations")?;
     Ok(Self { pool })
   }
 
@@ -28,6 +52,13 @@ impl Db {
     &self.pool
   }
 
+  /// Round-trips a trivial query, proving the pool can still hand out a working connection.
+  #[instrument(skip(self))]
+  pub async fn ping(&self) -> Result<()> {
+    sqlx::query!("SELECT 1 AS alive").fetch_one(&self.pool).await?;
+    Ok(())
+  }
+
   #[allow(dead_code)]
   #[instrument(skip(self))]
   pub async fn upsert_user(
@@ -36,26 +67,87 @@ impl Db {
     username: Option<String>,
     first_name: Option<String>,
     last_name: Option<String>,
+    language_code: Option<String>,
   ) -> Result<()> {
     sqlx::query!(
       r#"
-      INSERT INTO users (id, username, first_name, last_name)
-      VALUES ($1, $2, $3, $4)
+      INSERT INTO users (id, username, first_name, last_name, language_code)
+      VALUES ($1, $2, $3, $4, $5)
       ON CONFLICT (id) DO UPDATE SET
         username = EXCLUDED.username,
         first_name = EXCLUDED.first_name,
-        last_name = EXCLUDED.last_name
+        last_name = EXCLUDED.last_name,
+        language_code = EXCLUDED.language_code
       "#,
       id,
       username,
       first_name,
-      last_name
+      last_name,
+      language_code
     )
     .execute(&self.pool)
     .await?;
     Ok(())
   }
 
+  #[instrument(skip(self))]
+  pub async fn get_user(&self, id: i64) -> Result<Option<UserRow>> {
+    let row = sqlx::query!(
+      r#"
+      SELECT id, username, first_name, last_name, notifications_disabled, language_code, locale, created_at
+      FROM users
+      WHERE id = $1
+      "#,
+      id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    Ok(row.map(|row| UserRow {
+      id: row.id,
+      username: row.username,
+      first_name: row.first_name,
+      last_name: row.last_name,
+      notifications_disabled: row.notifications_disabled,
+      language_code: row.language_code,
+      locale: row.locale,
+      created_at: row.created_at,
+    }))
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_users(&self, ids: &[i64]) -> Result<Vec<UserRow>> {
+    if ids.is_empty() {
+      return Ok(Vec::new());
+    }
+
+    let ids: Vec<i64> = ids.to_vec();
+    let rows = sqlx::query!(
+      r#"
+      SELECT id, username, first_name, last_name, notifications_disabled, language_code, locale, created_at
+      FROM users
+      WHERE id = ANY($1)
+      "#,
+      &ids
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| UserRow {
+          id: row.id,
+          username: row.username,
+          first_name: row.first_name,
+          last_name: row.last_name,
+          notifications_disabled: row.notifications_disabled,
+          language_code: row.language_code,
+          locale: row.locale,
+          created_at: row.created_at,
+        })
+        .collect(),
+    )
+  }
+
   #[instrument(skip(self))]
   pub async fn set_notifications_disabled(&self, user_id: i64, disabled: bool) -> Result<()> {
     sqlx::query!(
@@ -73,6 +165,24 @@ impl Db {
     Ok(())
   }
 
+  /// Stores the user's language choice; `None` goes back to following Telegram's language.
+  #[instrument(skip(self))]
+  pub async fn set_user_locale(&self, user_id: i64, locale: Option<&str>) -> Result<()> {
+    sqlx::query!(
+      r#"
+      INSERT INTO users (id, locale)
+      VALUES ($1, $2)
+      ON CONFLICT (id) DO UPDATE SET
+        locale = EXCLUDED.locale
+      "#,
+      user_id,
+      locale,
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
   #[instrument(skip(self))]
   pub async fn notifications_disabled(&self, user_id: i64) -> Result<bool> {
     let disabled = sqlx::query_scalar!(
@@ -111,15 +221,17 @@ impl Db {
 
   #[instrument(skip(self))]
   pub async fn list_categories(&self) -> Result<Vec<CategoryRow>> {
-    let rows = sqlx::query!(r#"SELECT id, name FROM categories ORDER BY name COLLATE "C""#)
-      .fetch_all(&self.pool)
-      .await?;
+    let rows =
+      sqlx::query!(r#"SELECT id, name, currency FROM categories WHERE deleted_at IS NULL ORDER BY name COLLATE "C""#)
+        .fetch_all(&self.pool)
+        .await?;
     Ok(
       rows
         .into_iter()
         .map(|row| CategoryRow {
           id: row.id,
           name: row.name,
+          currency: row.currency,
         })
         .collect(),
     )
@@ -128,7 +240,7 @@ impl Db {
   #[instrument(skip(self))]
   pub async fn find_category_by_name(&self, name: &str) -> Result<Option<CategoryRow>> {
     let row = sqlx::query!(
-      r#"SELECT id, name FROM categories WHERE LOWER(name) = LOWER($1) LIMIT 1"#,
+      r#"SELECT id, name, currency FROM categories WHERE LOWER(name) = LOWER($1) AND deleted_at IS NULL LIMIT 1"#,
       name
     )
     .fetch_optional(&self.pool)
@@ -136,60 +248,175 @@ impl Db {
     Ok(row.map(|row| CategoryRow {
       id: row.id,
       name: row.name,
+      currency: row.currency,
     }))
   }
 
   #[instrument(skip(self))]
-  pub async fn create_category(&self, name: &str) -> Result<i64> {
-    let id = sqlx::query_scalar!(r#"INSERT INTO categories (name) VALUES ($1) RETURNING id"#, name)
-      .fetch_one(&self.pool)
-      .await?;
+  pub async fn create_category(&self, name: &str, currency: Option<&str>) -> Result<i64> {
+    let id = sqlx::query_scalar!(
+      r#"INSERT INTO categories (name, currency) VALUES ($1, $2) RETURNING id"#,
+      name,
+      currency
+    )
+    .fetch_one(&self.pool)
+    .await?;
     Ok(id)
   }
 
-  #[instrument(skip(self))]
-  pub async fn create_item(
+  /// Creates an item in an existing category with its images, returning its id.
+  #[instrument(skip(self, item), fields(title = %item.title))]
+  pub async fn create_item(&self, seller_tg_id: i64, item: &NewItem) -> Result<i64> {
+    let (_, item_ids) = self
+      .create_imported_items(seller_tg_id, std::slice::from_ref(item))
+      .await?;
+    item_ids.into_iter().next().context("item insert returned no id")
+  }
+
+  /// Creates imported items, and the new categories they name, in one transaction. Returns the created categories
+  /// and the item ids in input order.
+  #[instrument(skip(self, items), fields(count = items.len()))]
+  pub async fn create_imported_items(
     &self,
     seller_tg_id: i64,
-    category_id: i64,
-    title: &str,
-    description: Option<&str>,
-    start_price: i64,
-    image_file_ids: &[String],
-  ) -> Result<i64> {
-    let cover_image = image_file_ids.first().map(|id| id.as_str());
-    let id = sqlx::query_scalar!(
-      r#"
-      INSERT INTO items (seller_tg_id, category_id, title, description, start_price, image_file_id, is_new)
-      VALUES ($1, $2, $3, $4, $5, $6, TRUE)
-      RETURNING id
-      "#,
-      seller_tg_id,
-      category_id,
-      title,
-      description,
-      start_price,
-      cover_image
-    )
-    .fetch_one(&self.pool)
-    .await?;
+    items: &[NewItem],
+  ) -> Result<(Vec<CategoryRow>, Vec<i64>)> {
+    let mut tx = self.pool.begin().await?;
+    let mut categories: Vec<CategoryRow> = Vec::new();
+    let mut item_ids = Vec::with_capacity(items.len());
+    for item in items {
+      let category_id = match &item.category {
+        NewItemCategory::Existing(id) => *id,
+        NewItemCategory::New { name, currency } => match categories.iter().find(|category| category.name == *name) {
+          Some(category) => category.id,
+          None => {
+            let id = sqlx::query_scalar!(
+              r#"INSERT INTO categories (name, currency) VALUES ($1, $2) RETURNING id"#,
+              name,
+              currency.as_deref()
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            categories.push(CategoryRow {
+              id,
+              name: name.clone(),
+              currency: currency.clone(),
+            });
+            id
+          },
+        },
+      };
 
-    if !image_file_ids.is_empty() {
-      for (position, file_id) in image_file_ids.iter().enumerate() {
+      let cover_image = item.image_file_ids.first().map(|id| id.to_string());
+      let item_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO items (
+          seller_tg_id, category_id, title, description, start_price, image_file_id, is_new, currency, publish_at,
+          published_at, closes_at, event_id
+        )
+        VALUES (
+          $1, $2, $3, $4, $5, $6, TRUE, (SELECT currency FROM categories WHERE id = $2), $7,
+          CASE WHEN $7::TIMESTAMPTZ IS NULL THEN now() END, $8, $9
+        )
+        RETURNING id
+        "#,
+        seller_tg_id,
+        category_id,
+        item.title,
+        item.description.as_deref(),
+        item.start_price,
+        cover_image,
+        item.schedule.publish_at,
+        item.schedule.closes_at,
+        item.schedule.event_id
+      )
+      .fetch_one(&mut *tx)
+      .await?;
+      for (position, file_id) in item.image_file_ids.iter().enumerate() {
         sqlx::query!(
           r#"
           INSERT INTO item_images (item_id, file_id, position)
           VALUES ($1, $2, $3)
           "#,
-          id,
-          file_id,
+          item_id,
+          file_id.to_string(),
           position as i32,
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
       }
+      item_ids.push(item_id);
     }
-    Ok(id)
+    tx.commit().await?;
+    Ok((categories, item_ids))
+  }
+
+  /// Clones an item with its images into a new open listing that links back to the original. The original keeps its
+  /// bids. Returns `None` if the item does not exist, is still open, was relisted before, or it or its category is
+  /// archived.
+  #[instrument(skip(self))]
+  pub async fn relist_item(&self, item_id: i64, start_price: Option<i64>) -> Result<Option<i64>> {
+    let mut tx = self.pool.begin().await?;
+    // Locking the original makes a concurrent relisting wait here and then see this one's copy. The category is
+    // locked so it cannot be archived while the copy is added to it.
+    let closed = sqlx::query_scalar!(
+      r#"
+      SELECT i.id
+      FROM items i
+      JOIN categories c ON c.id = i.category_id
+      WHERE i.id = $1 AND NOT i.is_open AND i.deleted_at IS NULL AND c.deleted_at IS NULL
+      FOR UPDATE OF i
+      FOR SHARE OF c
+      "#,
+      item_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+    let relisted = sqlx::query_scalar!(
+      r#"SELECT EXISTS (SELECT 1 FROM items WHERE relisted_from_id = $1) AS "exists!""#,
+      item_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    if closed.is_none() || relisted {
+      return Ok(None);
+    }
+
+    let new_id = sqlx::query_scalar!(
+      r#"
+      INSERT INTO items (
+        seller_tg_id, category_id, title, description, start_price, image_file_id, is_new, relisted_from_id, currency,
+        published_at
+      )
+      SELECT
+        seller_tg_id, category_id, title, description, COALESCE($2, start_price), image_file_id, TRUE, id, currency, now()
+      FROM items
+      WHERE id = $1
+      RETURNING id
+      "#,
+      item_id,
+      start_price
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some(new_id) = new_id else {
+      return Ok(None);
+    };
+
+    sqlx::query!(
+      r#"
+      INSERT INTO item_images (item_id, file_id, position)
+      SELECT $2, file_id, position
+      FROM item_images
+      WHERE item_id = $1
+      "#,
+      item_id,
+      new_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(Some(new_id))
   }
 
   #[instrument(skip(self))]
@@ -206,9 +433,16 @@ impl Db {
         image_file_id,
         is_open,
         is_new,
-        created_at
+        created_at,
+        relisted_from_id,
+        currency,
+        deleted_at,
+        publish_at,
+        published_at,
+        closes_at,
+        event_id
       FROM items
-      WHERE category_id = $1
+      WHERE category_id = $1 AND deleted_at IS NULL
       ORDER BY created_at DESC
       "#,
       category_id
@@ -229,6 +463,75 @@ impl Db {
           is_open: row.is_open,
           is_new: row.is_new,
           created_at: row.created_at,
+          relisted_from_id: row.relisted_from_id,
+          currency: row.currency,
+          deleted_at: row.deleted_at,
+          publish_at: row.publish_at,
+          published_at: row.published_at,
+          closes_at: row.closes_at,
+          event_id: row.event_id,
+        })
+        .collect(),
+    )
+  }
+
+  /// Returns open items with their best standing bid, oldest first.
+  #[instrument(skip(self))]
+  pub async fn list_open_items_with_best_bid(&self) -> Result<Vec<(ItemRow, Option<i64>)>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT
+        i.id,
+        i.seller_tg_id,
+        i.category_id,
+        i.title,
+        i.description,
+        i.start_price,
+        i.image_file_id,
+        i.is_open,
+        i.is_new,
+        i.created_at,
+        i.relisted_from_id,
+        i.currency,
+        i.deleted_at,
+        i.publish_at,
+        i.published_at,
+        i.closes_at,
+        i.event_id,
+        (SELECT MAX(b.amount) FROM bids b WHERE b.item_id = i.id AND b.retracted_at IS NULL) AS best_bid
+      FROM items i
+      WHERE i.is_open AND i.deleted_at IS NULL
+      ORDER BY i.created_at ASC, i.id ASC
+      "#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          (
+            ItemRow {
+              id: row.id,
+              seller_tg_id: row.seller_tg_id,
+              category_id: row.category_id,
+              title: row.title,
+              description: row.description,
+              start_price: row.start_price,
+              image_file_id: row.image_file_id.map(|i| i.into()),
+              is_open: row.is_open,
+              is_new: row.is_new,
+              created_at: row.created_at,
+              relisted_from_id: row.relisted_from_id,
+              currency: row.currency,
+              deleted_at: row.deleted_at,
+              publish_at: row.publish_at,
+              published_at: row.published_at,
+              closes_at: row.closes_at,
+              event_id: row.event_id,
+            },
+            row.best_bid,
+          )
         })
         .collect(),
     )
@@ -248,7 +551,14 @@ impl Db {
         image_file_id,
         is_open,
         is_new,
-        created_at
+        created_at,
+        relisted_from_id,
+        currency,
+        deleted_at,
+        publish_at,
+        published_at,
+        closes_at,
+        event_id
       FROM items
       WHERE id = $1
       "#,
@@ -267,6 +577,13 @@ impl Db {
       is_open: row.is_open,
       is_new: row.is_new,
       created_at: row.created_at,
+      relisted_from_id: row.relisted_from_id,
+      currency: row.currency,
+      deleted_at: row.deleted_at,
+      publish_at: row.publish_at,
+      published_at: row.published_at,
+      closes_at: row.closes_at,
+      event_id: row.event_id,
     }))
   }
 
@@ -290,7 +607,7 @@ impl Db {
   #[instrument(skip(self))]
   pub async fn best_bid_for_item(&self, item_id: i64) -> Result<Option<i64>> {
     let value = sqlx::query_scalar!(
-      r#"SELECT amount FROM bids WHERE item_id = $1 ORDER BY amount DESC LIMIT 1"#,
+      r#"SELECT amount FROM bids WHERE item_id = $1 AND retracted_at IS NULL ORDER BY amount DESC LIMIT 1"#,
       item_id
     )
     .fetch_optional(&self.pool)
@@ -305,6 +622,7 @@ impl Db {
       SELECT bidder_tg_id, amount
       FROM bids
       WHERE item_id = $1
+        AND retracted_at IS NULL
       ORDER BY amount DESC, created_at ASC
       LIMIT 1
       "#,
@@ -316,10 +634,32 @@ impl Db {
     Ok(row.map(|row| (row.bidder_tg_id, row.amount)))
   }
 
+  #[instrument(skip(self))]
+  pub async fn count_bids_for_item(&self, item_id: i64) -> Result<i64> {
+    let count = sqlx::query_scalar!(
+      r#"SELECT COUNT(*) AS "count!" FROM bids WHERE item_id = $1 AND retracted_at IS NULL"#,
+      item_id
+    )
+    .fetch_one(&self.pool)
+    .await?;
+    Ok(count)
+  }
+
+  #[instrument(skip(self))]
+  pub async fn count_open_items(&self) -> Result<i64> {
+    let count = sqlx::query_scalar!(
+      r#"SELECT COUNT(*) AS "count!" FROM items WHERE is_open AND deleted_at IS NULL AND publish_at IS NULL"#
+    )
+    .fetch_one(&self.pool)
+    .await?;
+    Ok(count)
+  }
+
   #[instrument(skip(self))]
   pub async fn user_best_bid_for_item(&self, item_id: i64, user_id: i64) -> Result<Option<i64>> {
     let value = sqlx::query_scalar::<_, i64>(
-      "SELECT amount FROM bids WHERE item_id = $1 AND bidder_tg_id = $2 ORDER BY amount DESC LIMIT 1",
+      "SELECT amount FROM bids WHERE item_id = $1 AND bidder_tg_id = $2 AND retracted_at IS NULL ORDER BY amount \
+       DESC LIMIT 1",
     )
     .bind(item_id)
     .bind(user_id)
@@ -345,6 +685,116 @@ impl Db {
     Ok(id)
   }
 
+  #[instrument(skip(self))]
+  pub async fn get_bid(&self, bid_id: i64) -> Result<Option<BidRow>> {
+    let row = sqlx::query!(
+      r#"
+      SELECT id, item_id, bidder_tg_id, amount, created_at, retracted_at, retracted_by, retraction_reason
+      FROM bids
+      WHERE id = $1
+      "#,
+      bid_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    Ok(row.map(|row| BidRow {
+      id: row.id,
+      item_id: row.item_id,
+      bidder_tg_id: row.bidder_tg_id,
+      amount: row.amount,
+      created_at: row.created_at,
+      retracted_at: row.retracted_at,
+      retracted_by: row.retracted_by,
+      retraction_reason: row.retraction_reason,
+    }))
+  }
+
+  /// Returns one page of an item's bids, retracted ones included, newest first.
+  #[instrument(skip(self))]
+  pub async fn list_item_bids_page(&self, item_id: i64, limit: i64, offset: i64) -> Result<Vec<BidRow>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT id, item_id, bidder_tg_id, amount, created_at, retracted_at, retracted_by, retraction_reason
+      FROM bids
+      WHERE item_id = $1
+      ORDER BY created_at DESC, id DESC
+      LIMIT $2 OFFSET $3
+      "#,
+      item_id,
+      limit,
+      offset
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| BidRow {
+          id: row.id,
+          item_id: row.item_id,
+          bidder_tg_id: row.bidder_tg_id,
+          amount: row.amount,
+          created_at: row.created_at,
+          retracted_at: row.retracted_at,
+          retracted_by: row.retracted_by,
+          retraction_reason: row.retraction_reason,
+        })
+        .collect(),
+    )
+  }
+
+  #[instrument(skip(self))]
+  pub async fn item_bid_totals(&self, item_id: i64) -> Result<BidTotals> {
+    let row = sqlx::query!(
+      r#"
+      SELECT
+        COUNT(*) FILTER (WHERE retracted_at IS NULL) AS "active!",
+        COUNT(*) FILTER (WHERE retracted_at IS NOT NULL) AS "retracted!",
+        COUNT(DISTINCT bidder_tg_id) FILTER (WHERE retracted_at IS NULL) AS "unique_bidders!",
+        MIN(created_at) FILTER (WHERE retracted_at IS NULL) AS first_bid_at,
+        MAX(amount) FILTER (WHERE retracted_at IS NULL) AS best
+      FROM bids
+      WHERE item_id = $1
+      "#,
+      item_id
+    )
+    .fetch_one(&self.pool)
+    .await?;
+    Ok(BidTotals {
+      active: row.active,
+      retracted: row.retracted,
+      unique_bidders: row.unique_bidders,
+      first_bid_at: row.first_bid_at,
+      best: row.best,
+    })
+  }
+
+  /// Marks a bid as retracted. The row is kept so the bid history stays complete. Bids on closed or archived items
+  /// are left alone, since their winner has already been settled.
+  #[instrument(skip(self))]
+  pub async fn retract_bid(&self, bid_id: i64, retracted_by: i64, reason: &str) -> Result<bool> {
+    let result = sqlx::query!(
+      r#"
+      UPDATE bids
+      SET retracted_at = now(),
+          retracted_by = $2,
+          retraction_reason = $3
+      FROM items
+      WHERE bids.id = $1
+        AND bids.retracted_at IS NULL
+        AND items.id = bids.item_id
+        AND items.is_open
+        AND items.deleted_at IS NULL
+      "#,
+      bid_id,
+      retracted_by,
+      reason
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+  }
+
   #[instrument(skip(self))]
   pub async fn list_user_bid_items(&self, user_id: i64) -> Result<Vec<(ItemRow, i64)>> {
     let rows = sqlx::query(
@@ -360,10 +810,19 @@ impl Db {
         i.is_open,
         i.is_new,
         i.created_at,
+        i.relisted_from_id,
+        i.currency,
+        i.deleted_at,
+        i.publish_at,
+        i.published_at,
+        i.closes_at,
+        i.event_id,
         b.amount
       FROM bids b
       INNER JOIN items i ON i.id = b.item_id
       WHERE b.bidder_tg_id = $1
+        AND b.retracted_at IS NULL
+        AND i.deleted_at IS NULL
       ORDER BY b.item_id, b.amount DESC
       "#,
     )
@@ -385,6 +844,13 @@ impl Db {
           is_open: row.get("is_open"),
           is_new: row.get("is_new"),
           created_at: row.get("created_at"),
+          relisted_from_id: row.get("relisted_from_id"),
+          currency: row.get("currency"),
+          deleted_at: row.get("deleted_at"),
+          publish_at: row.get("publish_at"),
+          published_at: row.get("published_at"),
+          closes_at: row.get("closes_at"),
+          event_id: row.get("event_id"),
         };
         let amount = row.get("amount");
         (item, amount)
@@ -393,175 +859,1367 @@ impl Db {
     Ok(items)
   }
 
+  /// Closes an open item. Returns `false` if it was already closed, so that racing closures act only once. A closed
+  /// item is neither new nor waiting to be published any more, so it is never announced.
   #[instrument(skip(self))]
-  pub async fn close_item(&self, item_id: i64) -> Result<()> {
-    sqlx::query!(r#"UPDATE items SET is_open = FALSE WHERE id = $1"#, item_id)
-      .execute(&self.pool)
-      .await?;
-    Ok(())
-  }
-
-  #[instrument(skip(self))]
-  pub async fn list_item_bidder_ids(&self, item_id: i64) -> Result<Vec<i64>> {
-    let bidders = sqlx::query_scalar!(r#"SELECT DISTINCT bidder_tg_id FROM bids WHERE item_id = $1"#, item_id)
-      .fetch_all(&self.pool)
-      .await?;
-    Ok(bidders)
+  pub async fn close_item(&self, item_id: i64) -> Result<bool> {
+    let result = sqlx::query!(
+      r#"
+      UPDATE items
+      SET is_open = FALSE, is_new = FALSE, publish_at = NULL, publish_pending = FALSE
+      WHERE id = $1 AND is_open
+      "#,
+      item_id
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
   }
 
+  /// Makes the scheduled items whose publish time has come visible and returns their ids. They stay pending until
+  /// [`Db::finish_publications`] records that they were posted and announced.
   #[instrument(skip(self))]
-  pub async fn list_item_favorite_user_ids(&self, item_id: i64) -> Result<Vec<i64>> {
-    let favorites = sqlx::query_scalar!(r#"SELECT DISTINCT user_id FROM favorites WHERE item_id = $1"#, item_id)
-      .fetch_all(&self.pool)
-      .await?;
-    Ok(favorites)
+  pub async fn publish_due_items(&self) -> Result<Vec<i64>> {
+    let ids = sqlx::query_scalar!(
+      r#"
+      UPDATE items
+      SET publish_at = NULL, published_at = now(), publish_pending = TRUE
+      WHERE publish_at <= now() AND is_open AND deleted_at IS NULL
+      RETURNING id
+      "#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(ids)
   }
 
+  /// Returns the open items that went live but were not posted and announced yet, oldest first.
   #[instrument(skip(self))]
-  pub async fn delete_item(&self, item_id: i64) -> Result<bool> {
-    let result = sqlx::query!(r#"DELETE FROM items WHERE id = $1"#, item_id)
-      .execute(&self.pool)
-      .await?;
-    Ok(result.rows_affected() > 0)
+  pub async fn list_pending_publications(&self) -> Result<Vec<i64>> {
+    let ids = sqlx::query_scalar!(
+      r#"SELECT id FROM items WHERE publish_pending AND is_open AND deleted_at IS NULL ORDER BY id"#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(ids)
   }
 
+  /// Records that published items were posted and announced, so the scheduler stops retrying them.
   #[instrument(skip(self))]
-  pub async fn delete_category(&self, category_id: i64) -> Result<bool> {
-    let result = sqlx::query!(r#"DELETE FROM categories WHERE id = $1"#, category_id)
-      .execute(&self.pool)
-      .await?;
-    Ok(result.rows_affected() > 0)
+  pub async fn finish_publications(&self, item_ids: &[i64]) -> Result<()> {
+    sqlx::query!(
+      r#"UPDATE items SET publish_pending = FALSE WHERE id = ANY($1)"#,
+      item_ids
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(())
   }
 
+  /// Returns the published open items whose closing time has come, earliest first.
   #[instrument(skip(self))]
-  pub async fn add_favorite(&self, user_id: i64, item_id: i64) -> Result<()> {
-    sqlx::query(
+  pub async fn list_items_due_to_close(&self) -> Result<Vec<i64>> {
+    let ids = sqlx::query_scalar!(
+      r#"
+      SELECT id
+      FROM items
+      WHERE is_open
+        AND closes_at <= now()
+        AND publish_at IS NULL
+        AND deleted_at IS NULL
+      ORDER BY closes_at ASC, id ASC
+      "#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(ids)
+  }
+
+  /// Returns the items waiting to be published, soonest first.
+  #[instrument(skip(self))]
+  pub async fn list_scheduled_items(&self) -> Result<Vec<ItemRow>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT
+        id,
+        seller_tg_id,
+        category_id,
+        title,
+        description,
+        start_price,
+        image_file_id,
+        is_open,
+        is_new,
+        created_at,
+        relisted_from_id,
+        currency,
+        deleted_at,
+        publish_at,
+        published_at,
+        closes_at,
+        event_id
+      FROM items
+      WHERE publish_at IS NOT NULL AND deleted_at IS NULL
+      ORDER BY publish_at ASC, id ASC
+      "#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| ItemRow {
+          id: row.id,
+          seller_tg_id: row.seller_tg_id,
+          category_id: row.category_id,
+          title: row.title,
+          description: row.description,
+          start_price: row.start_price,
+          image_file_id: row.image_file_id.map(|i| i.into()),
+          is_open: row.is_open,
+          is_new: row.is_new,
+          created_at: row.created_at,
+          relisted_from_id: row.relisted_from_id,
+          currency: row.currency,
+          deleted_at: row.deleted_at,
+          publish_at: row.publish_at,
+          published_at: row.published_at,
+          closes_at: row.closes_at,
+          event_id: row.event_id,
+        })
+        .collect(),
+    )
+  }
+
+  #[instrument(skip(self))]
+  pub async fn record_channel_post(&self, item_id: i64, chat_id: i64, message_id: i32) -> Result<()> {
+    sqlx::query!(
+      r#"
+      INSERT INTO channel_posts (item_id, chat_id, message_id)
+      VALUES ($1, $2, $3)
+      ON CONFLICT (item_id, chat_id) DO UPDATE SET
+        message_id = EXCLUDED.message_id,
+        updated_at = now()
+      "#,
+      item_id,
+      chat_id,
+      message_id,
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  #[instrument(skip(self))]
+  pub async fn channel_post_message_id(&self, item_id: i64, chat_id: i64) -> Result<Option<i32>> {
+    let message_id = sqlx::query_scalar!(
+      r#"SELECT message_id FROM channel_posts WHERE item_id = $1 AND chat_id = $2"#,
+      item_id,
+      chat_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    Ok(message_id)
+  }
+
+  #[instrument(skip(self))]
+  pub async fn record_item_card(
+    &self,
+    chat_id: i64,
+    message_id: i32,
+    item_id: i64,
+    viewer_tg_id: Option<i64>,
+  ) -> Result<()> {
+    sqlx::query!(
+      r#"
+      INSERT INTO item_cards (chat_id, message_id, item_id, viewer_tg_id)
+      VALUES ($1, $2, $3, $4)
+      ON CONFLICT (chat_id, message_id) DO NOTHING
+      "#,
+      chat_id,
+      message_id,
+      item_id,
+      viewer_tg_id,
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_item_cards(&self, item_id: i64, limit: i64) -> Result<Vec<ItemCardRow>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT chat_id, message_id, viewer_tg_id
+      FROM item_cards
+      WHERE item_id = $1
+      ORDER BY created_at DESC
+      LIMIT $2
+      "#,
+      item_id,
+      limit
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| ItemCardRow {
+          chat_id: row.chat_id,
+          message_id: row.message_id,
+          viewer_tg_id: row.viewer_tg_id,
+        })
+        .collect(),
+    )
+  }
+
+  #[instrument(skip(self))]
+  pub async fn forget_item_card(&self, chat_id: i64, message_id: i32) -> Result<()> {
+    sqlx::query!(
+      r#"DELETE FROM item_cards WHERE chat_id = $1 AND message_id = $2"#,
+      chat_id,
+      message_id
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  #[instrument(skip(self))]
+  pub async fn record_group_lot(&self, chat_id: i64, message_id: i32, item_id: i64, posted_by: i64) -> Result<()> {
+    sqlx::query!(
+      r#"
+      INSERT INTO group_lots (chat_id, message_id, item_id, posted_by)
+      VALUES ($1, $2, $3, $4)
+      ON CONFLICT (chat_id, message_id) DO NOTHING
+      "#,
+      chat_id,
+      message_id,
+      item_id,
+      posted_by,
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  #[instrument(skip(self))]
+  pub async fn find_group_lot_item(&self, chat_id: i64, message_id: i32) -> Result<Option<i64>> {
+    let item_id = sqlx::query_scalar!(
+      r#"SELECT item_id FROM group_lots WHERE chat_id = $1 AND message_id = $2"#,
+      chat_id,
+      message_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    Ok(item_id)
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_group_lots(&self, item_id: i64) -> Result<Vec<(i64, i32)>> {
+    let rows = sqlx::query!(
+      r#"SELECT chat_id, message_id FROM group_lots WHERE item_id = $1 ORDER BY created_at ASC"#,
+      item_id
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(rows.into_iter().map(|row| (row.chat_id, row.message_id)).collect())
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_item_bidder_ids(&self, item_id: i64) -> Result<Vec<i64>> {
+    let bidders = sqlx::query_scalar!(
+      r#"SELECT DISTINCT bidder_tg_id FROM bids WHERE item_id = $1 AND retracted_at IS NULL"#,
+      item_id
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(bidders)
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_item_favorite_user_ids(&self, item_id: i64) -> Result<Vec<i64>> {
+    let favorites = sqlx::query_scalar!(r#"SELECT DISTINCT user_id FROM favorites WHERE item_id = $1"#, item_id)
+      .fetch_all(&self.pool)
+      .await?;
+    Ok(favorites)
+  }
+
+  /// Archives an item. Returns `false` if it does not exist or is already archived.
+  #[instrument(skip(self))]
+  pub async fn archive_item(&self, item_id: i64, deleted_by: i64) -> Result<bool> {
+    let result = sqlx::query!(
+      r#"UPDATE items SET deleted_at = now(), deleted_by = $2 WHERE id = $1 AND deleted_at IS NULL"#,
+      item_id,
+      deleted_by
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+  }
+
+  /// Brings an archived item back. Returns `false` if it is not archived or its category is still archived.
+  #[instrument(skip(self))]
+  pub async fn restore_item(&self, item_id: i64) -> Result<bool> {
+    let result = sqlx::query!(
+      r#"
+      UPDATE items i
+      SET deleted_at = NULL, deleted_by = NULL
+      FROM categories c
+      WHERE i.id = $1 AND i.deleted_at IS NOT NULL AND c.id = i.category_id AND c.deleted_at IS NULL
+      "#,
+      item_id
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+  }
+
+  #[instrument(skip(self))]
+  pub async fn get_category(&self, category_id: i64) -> Result<Option<CategoryRow>> {
+    let row = sqlx::query!(
+      r#"SELECT id, name, currency FROM categories WHERE id = $1"#,
+      category_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    Ok(row.map(|row| CategoryRow {
+      id: row.id,
+      name: row.name,
+      currency: row.currency,
+    }))
+  }
+
+  /// Archives a category after moving its active items into another active category, and returns how many moved.
+  /// Returns `None` without changing anything if the category is already archived or the target is not active.
+  #[instrument(skip(self))]
+  pub async fn archive_category_moving_items(
+    &self,
+    from_category_id: i64,
+    to_category_id: i64,
+    deleted_by: i64,
+  ) -> Result<Option<u64>> {
+    let mut tx = self.pool.begin().await?;
+    let target = sqlx::query_scalar!(
+      r#"SELECT id FROM categories WHERE id = $1 AND deleted_at IS NULL FOR SHARE"#,
+      to_category_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+    if target.is_none() || from_category_id == to_category_id {
+      return Ok(None);
+    }
+    let archived = sqlx::query!(
+      r#"UPDATE categories SET deleted_at = now(), deleted_by = $2 WHERE id = $1 AND deleted_at IS NULL"#,
+      from_category_id,
+      deleted_by
+    )
+    .execute(&mut *tx)
+    .await?;
+    if archived.rows_affected() == 0 {
+      return Ok(None);
+    }
+    let moved = sqlx::query!(
+      r#"UPDATE items SET category_id = $2 WHERE category_id = $1 AND deleted_at IS NULL"#,
+      from_category_id,
+      to_category_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(Some(moved.rows_affected()))
+  }
+
+  /// Archives a category together with the items still in it. Returns `false` if it does not exist or is already
+  /// archived.
+  #[instrument(skip(self))]
+  pub async fn archive_category(&self, category_id: i64, deleted_by: i64) -> Result<bool> {
+    let mut tx = self.pool.begin().await?;
+    let result = sqlx::query!(
+      r#"UPDATE categories SET deleted_at = now(), deleted_by = $2 WHERE id = $1 AND deleted_at IS NULL"#,
+      category_id,
+      deleted_by
+    )
+    .execute(&mut *tx)
+    .await?;
+    if result.rows_affected() == 0 {
+      return Ok(false);
+    }
+    sqlx::query!(
+      r#"UPDATE items SET deleted_at = now(), deleted_by = $2 WHERE category_id = $1 AND deleted_at IS NULL"#,
+      category_id,
+      deleted_by
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(true)
+  }
+
+  /// Brings an archived category back on its own. Its items stay archived until restored one by one. Returns `false`
+  /// if it is not archived or an active category already uses its name.
+  #[instrument(skip(self))]
+  pub async fn restore_category(&self, category_id: i64) -> Result<bool> {
+    let result = sqlx::query!(
+      r#"
+      UPDATE categories c
+      SET deleted_at = NULL, deleted_by = NULL
+      WHERE c.id = $1
+        AND c.deleted_at IS NOT NULL
+        AND NOT EXISTS (
+          SELECT 1 FROM categories other
+          WHERE other.name = c.name AND other.deleted_at IS NULL
+        )
+      "#,
+      category_id
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+  }
+
+  /// Returns archived items with the admin who archived them, most recent first.
+  #[instrument(skip(self))]
+  pub async fn list_archived_items(&self, limit: i64) -> Result<Vec<(ItemRow, Option<i64>)>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT
+        id,
+        seller_tg_id,
+        category_id,
+        title,
+        description,
+        start_price,
+        image_file_id,
+        is_open,
+        is_new,
+        created_at,
+        relisted_from_id,
+        currency,
+        deleted_at,
+        deleted_by,
+        publish_at,
+        published_at,
+        closes_at,
+        event_id
+      FROM items
+      WHERE deleted_at IS NOT NULL
+      ORDER BY deleted_at DESC
+      LIMIT $1
+      "#,
+      limit
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          (
+            ItemRow {
+              id: row.id,
+              seller_tg_id: row.seller_tg_id,
+              category_id: row.category_id,
+              title: row.title,
+              description: row.description,
+              start_price: row.start_price,
+              image_file_id: row.image_file_id.map(|i| i.into()),
+              is_open: row.is_open,
+              is_new: row.is_new,
+              created_at: row.created_at,
+              relisted_from_id: row.relisted_from_id,
+              currency: row.currency,
+              deleted_at: row.deleted_at,
+              publish_at: row.publish_at,
+              published_at: row.published_at,
+              closes_at: row.closes_at,
+              event_id: row.event_id,
+            },
+            row.deleted_by,
+          )
+        })
+        .collect(),
+    )
+  }
+
+  /// Returns archived categories with the admin who archived them, most recent first.
+  #[instrument(skip(self))]
+  pub async fn list_archived_categories(&self, limit: i64) -> Result<Vec<(CategoryRow, Option<i64>)>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT id, name, currency, deleted_by
+      FROM categories
+      WHERE deleted_at IS NOT NULL
+      ORDER BY deleted_at DESC
+      LIMIT $1
+      "#,
+      limit
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          (
+            CategoryRow {
+              id: row.id,
+              name: row.name,
+              currency: row.currency,
+            },
+            row.deleted_by,
+          )
+        })
+        .collect(),
+    )
+  }
+
+  #[instrument(skip(self))]
+  pub async fn add_favorite(&self, user_id: i64, item_id: i64) -> Result<()> {
+    sqlx::query(
+      r#"
+      INSERT INTO favorites (user_id, item_id)
+      VALUES ($1, $2)
+      ON CONFLICT (user_id, item_id) DO NOTHING
+      "#,
+    )
+    .bind(user_id)
+    .bind(item_id)
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  #[instrument(skip(self))]
+  pub async fn remove_favorite(&self, user_id: i64, item_id: i64) -> Result<()> {
+    sqlx::query("DELETE FROM favorites WHERE user_id = $1 AND item_id = $2")
+      .bind(user_id)
+      .bind(item_id)
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+
+  #[instrument(skip(self))]
+  pub async fn is_favorite(&self, user_id: i64, item_id: i64) -> Result<bool> {
+    let exists =
+      sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM favorites WHERE user_id = $1 AND item_id = $2)")
+        .bind(user_id)
+        .bind(item_id)
+        .fetch_one(&self.pool)
+        .await?;
+    Ok(exists)
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_favorites(&self, user_id: i64) -> Result<Vec<ItemRow>> {
+    let rows = sqlx::query(
+      r#"
+      SELECT i.id,
+             i.seller_tg_id,
+             i.category_id,
+             i.title,
+             i.description,
+             i.start_price,
+             i.image_file_id,
+             i.is_open,
+             i.is_new,
+             i.created_at,
+             i.relisted_from_id,
+             i.currency,
+             i.deleted_at,
+             i.publish_at,
+             i.published_at,
+             i.closes_at,
+             i.event_id
+      FROM favorites f
+      INNER JOIN items i ON i.id = f.item_id
+      WHERE f.user_id = $1
+        AND i.deleted_at IS NULL
+      ORDER BY f.created_at DESC
+      "#,
+    )
+    .bind(user_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    let items = rows
+      .into_iter()
+      .map(|row| ItemRow {
+        id: row.get("id"),
+        seller_tg_id: row.get("seller_tg_id"),
+        category_id: row.get("category_id"),
+        title: row.get("title"),
+        description: row.get("description"),
+        start_price: row.get("start_price"),
+        image_file_id: row.get::<Option<String>, _>("image_file_id").map(Into::into),
+        is_open: row.get("is_open"),
+        is_new: row.get("is_new"),
+        created_at: row.get("created_at"),
+        relisted_from_id: row.get("relisted_from_id"),
+        currency: row.get("currency"),
+        deleted_at: row.get("deleted_at"),
+        publish_at: row.get("publish_at"),
+        published_at: row.get("published_at"),
+        closes_at: row.get("closes_at"),
+        event_id: row.get("event_id"),
+      })
+      .collect();
+    Ok(items)
+  }
+
+  /// Grants admin rights to `tg_id`. Granting twice keeps the first grant.
+  #[instrument(skip(self))]
+  pub async fn grant_admin(&self, tg_id: i64, granted_by: i64) -> Result<()> {
+    sqlx::query!(
+      r#"
+      INSERT INTO admin_grants (tg_id, granted_by)
+      VALUES ($1, $2)
+      ON CONFLICT (tg_id) DO NOTHING
+      "#,
+      tg_id,
+      granted_by
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_granted_admins(&self) -> Result<Vec<i64>> {
+    let ids = sqlx::query_scalar!(r#"SELECT tg_id FROM admin_grants ORDER BY tg_id"#)
+      .fetch_all(&self.pool)
+      .await?;
+    Ok(ids)
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_user_ids(&self) -> Result<Vec<i64>> {
+    let ids = sqlx::query_scalar!(
+      r#"
+      SELECT id
+      FROM users
+      WHERE notifications_disabled = FALSE
+      "#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(ids)
+  }
+
+  #[instrument(skip(self))]
+  pub async fn list_new_items(&self) -> Result<Vec<ItemRow>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT
+        id,
+        seller_tg_id,
+        category_id,
+        title,
+        description,
+        start_price,
+        image_file_id,
+        is_open,
+        is_new,
+        created_at,
+        relisted_from_id,
+        currency,
+        deleted_at,
+        publish_at,
+        published_at,
+        closes_at,
+        event_id
+      FROM items
+      WHERE is_new = TRUE AND deleted_at IS NULL AND publish_at IS NULL
+      ORDER BY created_at DESC
+      "#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| ItemRow {
+          id: row.id,
+          seller_tg_id: row.seller_tg_id,
+          category_id: row.category_id,
+          title: row.title,
+          description: row.description,
+          start_price: row.start_price,
+          image_file_id: row.image_file_id.map(|i| i.into()),
+          is_open: row.is_open,
+          is_new: row.is_new,
+          created_at: row.created_at,
+          relisted_from_id: row.relisted_from_id,
+          currency: row.currency,
+          deleted_at: row.deleted_at,
+          publish_at: row.publish_at,
+          published_at: row.published_at,
+          closes_at: row.closes_at,
+          event_id: row.event_id,
+        })
+        .collect(),
+    )
+  }
+
+  #[instrument(skip(self))]
+  pub async fn clear_new_item_flags(&self, item_ids: &[i64]) -> Result<()> {
+    if item_ids.is_empty() {
+      return Ok(());
+    }
+
+    let ids: Vec<i64> = item_ids.to_vec();
+    sqlx::query!("UPDATE items SET is_new = FALSE WHERE id = ANY($1)", &ids)
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+
+  /// Opens the checkout order of a closed item. Closing an item twice keeps the original order.
+  #[instrument(skip(self))]
+  pub async fn create_order(&self, item_id: i64, winner_tg_id: i64, amount: i64) -> Result<OrderRow> {
+    let row = sqlx::query!(
+      r#"
+      INSERT INTO orders (item_id, winner_tg_id, amount)
+      VALUES ($1, $2, $3)
+      ON CONFLICT (item_id) DO UPDATE SET item_id = EXCLUDED.item_id
+      RETURNING id, item_id, winner_tg_id, amount, status, updated_at
+      "#,
+      item_id,
+      winner_tg_id,
+      amount
+    )
+    .fetch_one(&self.pool)
+    .await?;
+    Ok(OrderRow {
+      id: row.id,
+      item_id: row.item_id,
+      winner_tg_id: row.winner_tg_id,
+      amount: row.amount,
+      status: parse_order_status(&row.status)?,
+      updated_at: row.updated_at,
+    })
+  }
+
+  #[instrument(skip(self))]
+  pub async fn get_order(&self, order_id: i64) -> Result<Option<OrderRow>> {
+    let row = sqlx::query!(
+      r#"
+      SELECT id, item_id, winner_tg_id, amount, status, updated_at
+      FROM orders
+      WHERE id = $1
+      "#,
+      order_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    row
+      .map(|row| {
+        Ok(OrderRow {
+          id: row.id,
+          item_id: row.item_id,
+          winner_tg_id: row.winner_tg_id,
+          amount: row.amount,
+          status: parse_order_status(&row.status)?,
+          updated_at: row.updated_at,
+        })
+      })
+      .transpose()
+  }
+
+  /// Returns the orders won by a user together with the item titles and currencies, newest first.
+  #[instrument(skip(self))]
+  pub async fn list_user_orders(&self, winner_tg_id: i64) -> Result<Vec<(OrderRow, String, Option<String>)>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT o.id, o.item_id, o.winner_tg_id, o.amount, o.status, o.updated_at, i.title, i.currency
+      FROM orders o
+      JOIN items i ON i.id = o.item_id
+      WHERE o.winner_tg_id = $1
+      ORDER BY o.created_at DESC
+      "#,
+      winner_tg_id
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    rows
+      .into_iter()
+      .map(|row| {
+        Ok((
+          OrderRow {
+            id: row.id,
+            item_id: row.item_id,
+            winner_tg_id: row.winner_tg_id,
+            amount: row.amount,
+            status: parse_order_status(&row.status)?,
+            updated_at: row.updated_at,
+          },
+          row.title,
+          row.currency,
+        ))
+      })
+      .collect()
+  }
+
+  /// Returns orders that still need admin attention together with the item titles and currencies, oldest first.
+  #[instrument(skip(self))]
+  pub async fn list_active_orders(&self, limit: i64) -> Result<Vec<(OrderRow, String, Option<String>)>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT o.id, o.item_id, o.winner_tg_id, o.amount, o.status, o.updated_at, i.title, i.currency
+      FROM orders o
+      JOIN items i ON i.id = o.item_id
+      WHERE o.status NOT IN ('completed', 'cancelled')
+      ORDER BY o.created_at
+      LIMIT $1
+      "#,
+      limit
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    rows
+      .into_iter()
+      .map(|row| {
+        Ok((
+          OrderRow {
+            id: row.id,
+            item_id: row.item_id,
+            winner_tg_id: row.winner_tg_id,
+            amount: row.amount,
+            status: parse_order_status(&row.status)?,
+            updated_at: row.updated_at,
+          },
+          row.title,
+          row.currency,
+        ))
+      })
+      .collect()
+  }
+
+  /// Moves an order from `from` to `to`. Returns `None` when the order is no longer in `from`, e.g. because another
+  /// admin changed it first.
+  #[instrument(skip(self))]
+  pub async fn update_order_status(
+    &self,
+    order_id: i64,
+    from: OrderStatus,
+    to: OrderStatus,
+  ) -> Result<Option<OrderRow>> {
+    let row = sqlx::query!(
+      r#"
+      UPDATE orders
+      SET status = $3, updated_at = now()
+      WHERE id = $1 AND status = $2
+      RETURNING id, item_id, winner_tg_id, amount, status, updated_at
+      "#,
+      order_id,
+      from.as_str(),
+      to.as_str()
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    row
+      .map(|row| {
+        Ok(OrderRow {
+          id: row.id,
+          item_id: row.item_id,
+          winner_tg_id: row.winner_tg_id,
+          amount: row.amount,
+          status: parse_order_status(&row.status)?,
+          updated_at: row.updated_at,
+        })
+      })
+      .transpose()
+  }
+
+  /// Marks an order paid through a Telegram invoice. Returns `None` unless the order was still awaiting payment, so
+  /// a repeated update cannot move a shipped or cancelled order back.
+  #[instrument(skip(self))]
+  pub async fn mark_order_paid(
+    &self,
+    order_id: i64,
+    telegram_payment_charge_id: &str,
+    provider_payment_charge_id: &str,
+  ) -> Result<Option<OrderRow>> {
+    let row = sqlx::query!(
+      r#"
+      UPDATE orders
+      SET status = 'paid',
+          paid_at = now(),
+          updated_at = now(),
+          telegram_payment_charge_id = $2,
+          provider_payment_charge_id = $3
+      WHERE id = $1 AND status = 'awaiting_payment'
+      RETURNING id, item_id, winner_tg_id, amount, status, updated_at
+      "#,
+      order_id,
+      telegram_payment_charge_id,
+      provider_payment_charge_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    row
+      .map(|row| {
+        Ok(OrderRow {
+          id: row.id,
+          item_id: row.item_id,
+          winner_tg_id: row.winner_tg_id,
+          amount: row.amount,
+          status: parse_order_status(&row.status)?,
+          updated_at: row.updated_at,
+        })
+      })
+      .transpose()
+  }
+
+  #[instrument(skip(self))]
+  pub async fn get_item_order(&self, item_id: i64) -> Result<Option<OrderRow>> {
+    let row = sqlx::query!(
+      r#"
+      SELECT id, item_id, winner_tg_id, amount, status, updated_at
+      FROM orders
+      WHERE item_id = $1
+      "#,
+      item_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    row
+      .map(|row| {
+        Ok(OrderRow {
+          id: row.id,
+          item_id: row.item_id,
+          winner_tg_id: row.winner_tg_id,
+          amount: row.amount,
+          status: parse_order_status(&row.status)?,
+          updated_at: row.updated_at,
+        })
+      })
+      .transpose()
+  }
+
+  /// Hands the order of an item to a new winner. Only a missing or cancelled order is replaced, so an order that is
+  /// still being paid or shipped is never overwritten. The replaced winner is kept in `previous_winner_ids`.
+  #[instrument(skip(self))]
+  pub async fn reassign_order(&self, item_id: i64, winner_tg_id: i64, amount: i64) -> Result<Option<OrderRow>> {
+    let row = sqlx::query!(
+      r#"
+      INSERT INTO orders (item_id, winner_tg_id, amount)
+      VALUES ($1, $2, $3)
+      ON CONFLICT (item_id) DO UPDATE
+      SET winner_tg_id = EXCLUDED.winner_tg_id,
+          previous_winner_ids = array_append(orders.previous_winner_ids, orders.winner_tg_id),
+          amount = EXCLUDED.amount,
+          status = 'awaiting_payment',
+          paid_at = NULL,
+          telegram_payment_charge_id = NULL,
+          provider_payment_charge_id = NULL,
+          created_at = now(),
+          updated_at = now()
+      WHERE orders.status = 'cancelled'
+      RETURNING id, item_id, winner_tg_id, amount, status, updated_at
+      "#,
+      item_id,
+      winner_tg_id,
+      amount
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    row
+      .map(|row| {
+        Ok(OrderRow {
+          id: row.id,
+          item_id: row.item_id,
+          winner_tg_id: row.winner_tg_id,
+          amount: row.amount,
+          status: parse_order_status(&row.status)?,
+          updated_at: row.updated_at,
+        })
+      })
+      .transpose()
+  }
+
+  /// Returns everyone who has won an item so far: the current winner of its order and every winner it was reassigned
+  /// from.
+  #[instrument(skip(self))]
+  pub async fn list_item_winners(&self, item_id: i64) -> Result<Vec<i64>> {
+    let winners = sqlx::query_scalar!(
+      r#"
+      SELECT array_append(previous_winner_ids, winner_tg_id) AS "winners!"
+      FROM orders
+      WHERE item_id = $1
+      "#,
+      item_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    Ok(winners.unwrap_or_default())
+  }
+
+  /// Returns every bidder's highest active bid on an item, best first. Ties go to the earlier bid.
+  #[instrument(skip(self))]
+  pub async fn list_bidder_best_bids(&self, item_id: i64) -> Result<Vec<(i64, i64)>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT DISTINCT ON (bidder_tg_id) bidder_tg_id, amount, created_at
+      FROM bids
+      WHERE item_id = $1 AND retracted_at IS NULL
+      ORDER BY bidder_tg_id, amount DESC, created_at
+      "#,
+      item_id
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    let mut ranked: Vec<_> = rows
+      .into_iter()
+      .map(|row| (row.bidder_tg_id, row.amount, row.created_at))
+      .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+    Ok(ranked.into_iter().map(|(bidder, amount, _)| (bidder, amount)).collect())
+  }
+
+  /// Returns everyone who already received a second-chance offer for an item, whatever their answer.
+  #[instrument(skip(self))]
+  pub async fn list_second_chance_bidders(&self, item_id: i64) -> Result<Vec<i64>> {
+    let rows = sqlx::query!(
+      r#"SELECT bidder_tg_id FROM second_chance_offers WHERE item_id = $1"#,
+      item_id
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(rows.into_iter().map(|row| row.bidder_tg_id).collect())
+  }
+
+  /// Records a pending offer. Returns `None` when the item already has one pending.
+  #[instrument(skip(self))]
+  pub async fn create_second_chance_offer(
+    &self,
+    item_id: i64,
+    bidder_tg_id: i64,
+    amount: i64,
+    offered_by: i64,
+    expires_at: DateTime<Utc>,
+  ) -> Result<Option<SecondChanceOfferRow>> {
+    let row = sqlx::query!(
       r#"
-      INSERT INTO favorites (user_id, item_id)
-      VALUES ($1, $2)
-      ON CONFLICT (user_id, item_id) DO NOTHING
+      INSERT INTO second_chance_offers (item_id, bidder_tg_id, amount, offered_by, expires_at)
+      VALUES ($1, $2, $3, $4, $5)
+      ON CONFLICT (item_id) WHERE status = 'pending' DO NOTHING
+      RETURNING id, item_id, bidder_tg_id, amount, offered_by, expires_at
       "#,
+      item_id,
+      bidder_tg_id,
+      amount,
+      offered_by,
+      expires_at
     )
-    .bind(user_id)
-    .bind(item_id)
-    .execute(&self.pool)
+    .fetch_optional(&self.pool)
     .await?;
-    Ok(())
+    Ok(row.map(|row| SecondChanceOfferRow {
+      id: row.id,
+      item_id: row.item_id,
+      bidder_tg_id: row.bidder_tg_id,
+      amount: row.amount,
+      offered_by: row.offered_by,
+      expires_at: row.expires_at,
+    }))
   }
 
+  /// Records the bidder's answer to a pending, unexpired offer. Returns `None` if the offer was already answered or
+  /// has expired.
   #[instrument(skip(self))]
-  pub async fn remove_favorite(&self, user_id: i64, item_id: i64) -> Result<()> {
-    sqlx::query("DELETE FROM favorites WHERE user_id = $1 AND item_id = $2")
-      .bind(user_id)
-      .bind(item_id)
-      .execute(&self.pool)
-      .await?;
-    Ok(())
+  pub async fn answer_second_chance_offer(
+    &self,
+    offer_id: i64,
+    bidder_tg_id: i64,
+    accepted: bool,
+  ) -> Result<Option<SecondChanceOfferRow>> {
+    let status = if accepted { "accepted" } else { "declined" };
+    let row = sqlx::query!(
+      r#"
+      UPDATE second_chance_offers
+      SET status = $3, responded_at = now()
+      WHERE id = $1 AND bidder_tg_id = $2 AND status = 'pending' AND expires_at > now()
+      RETURNING id, item_id, bidder_tg_id, amount, offered_by, expires_at
+      "#,
+      offer_id,
+      bidder_tg_id,
+      status
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    Ok(row.map(|row| SecondChanceOfferRow {
+      id: row.id,
+      item_id: row.item_id,
+      bidder_tg_id: row.bidder_tg_id,
+      amount: row.amount,
+      offered_by: row.offered_by,
+      expires_at: row.expires_at,
+    }))
   }
 
+  /// Marks every pending offer past its deadline as expired and returns them, oldest deadline first.
   #[instrument(skip(self))]
-  pub async fn is_favorite(&self, user_id: i64, item_id: i64) -> Result<bool> {
-    let exists =
-      sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM favorites WHERE user_id = $1 AND item_id = $2)")
-        .bind(user_id)
-        .bind(item_id)
-        .fetch_one(&self.pool)
-        .await?;
-    Ok(exists)
+  pub async fn expire_second_chance_offers(&self) -> Result<Vec<SecondChanceOfferRow>> {
+    let rows = sqlx::query!(
+      r#"
+      WITH expired AS (
+        UPDATE second_chance_offers
+        SET status = 'expired', responded_at = now()
+        WHERE status = 'pending' AND expires_at <= now()
+        RETURNING id, item_id, bidder_tg_id, amount, offered_by, expires_at
+      )
+      SELECT id AS "id!", item_id AS "item_id!", bidder_tg_id AS "bidder_tg_id!", amount AS "amount!",
+        offered_by AS "offered_by!", expires_at AS "expires_at!"
+      FROM expired
+      ORDER BY expires_at, id
+      "#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| SecondChanceOfferRow {
+          id: row.id,
+          item_id: row.item_id,
+          bidder_tg_id: row.bidder_tg_id,
+          amount: row.amount,
+          offered_by: row.offered_by,
+          expires_at: row.expires_at,
+        })
+        .collect(),
+    )
+  }
+
+  #[instrument(skip(self, payload))]
+  pub async fn record_audit(
+    &self,
+    actor_tg_id: i64,
+    action: AuditAction,
+    target: AuditTarget,
+    payload: &serde_json::Value,
+  ) -> Result<()> {
+    sqlx::query!(
+      r#"
+      INSERT INTO audit_log (actor_tg_id, action, target_type, target_id, payload)
+      VALUES ($1, $2, $3, $4, $5)
+      "#,
+      actor_tg_id,
+      action.as_str(),
+      target.kind(),
+      target.id(),
+      payload
+    )
+    .execute(&self.pool)
+    .await?;
+    Ok(())
   }
 
+  /// Lists audit entries newest first, optionally only those of one action.
   #[instrument(skip(self))]
-  pub async fn list_favorites(&self, user_id: i64) -> Result<Vec<ItemRow>> {
-    let rows = sqlx::query(
+  pub async fn list_audit_log(
+    &self,
+    action: Option<AuditAction>,
+    limit: i64,
+    offset: i64,
+  ) -> Result<Vec<AuditEntryRow>> {
+    let rows = sqlx::query!(
       r#"
-      SELECT i.id,
-             i.seller_tg_id,
-             i.category_id,
-             i.title,
-             i.description,
-             i.start_price,
-             i.image_file_id,
-             i.is_open,
-             i.is_new,
-             i.created_at
-      FROM favorites f
-      INNER JOIN items i ON i.id = f.item_id
-      WHERE f.user_id = $1
-      ORDER BY f.created_at DESC
+      SELECT id, actor_tg_id, action, target_type, target_id, payload, created_at
+      FROM audit_log
+      WHERE $1::text IS NULL OR action = $1
+      ORDER BY created_at DESC, id DESC
+      LIMIT $2 OFFSET $3
       "#,
+      action.map(AuditAction::as_str),
+      limit,
+      offset
     )
-    .bind(user_id)
     .fetch_all(&self.pool)
     .await?;
-
-    let items = rows
+    rows
       .into_iter()
-      .map(|row| ItemRow {
-        id: row.get("id"),
-        seller_tg_id: row.get("seller_tg_id"),
-        category_id: row.get("category_id"),
-        title: row.get("title"),
-        description: row.get("description"),
-        start_price: row.get("start_price"),
-        image_file_id: row.get::<Option<String>, _>("image_file_id").map(Into::into),
-        is_open: row.get("is_open"),
-        is_new: row.get("is_new"),
-        created_at: row.get("created_at"),
+      .map(|row| {
+        Ok(AuditEntryRow {
+          id: row.id,
+          actor_tg_id: row.actor_tg_id,
+          action: AuditAction::parse(&row.action)
+            .with_context(|| format!("unknown audit action {:?}", row.action))?,
+          target: row
+            .target_type
+            .as_deref()
+            .and_then(|kind| AuditTarget::from_parts(kind, row.target_id)),
+          payload: row.payload,
+          created_at: row.created_at,
+        })
       })
-      .collect();
-    Ok(items)
+      .collect()
   }
 
+  /// Items listed within the filter, archived ones included, with their best bid and, once closed, the order of
+  /// their winner. Oldest first.
   #[instrument(skip(self))]
-  pub async fn list_user_ids(&self) -> Result<Vec<i64>> {
-    let ids = sqlx::query_scalar!(
+  pub async fn export_item_results(&self, filter: &ExportFilter) -> Result<Vec<ItemResultRow>> {
+    let rows = sqlx::query!(
       r#"
-      SELECT id
-      FROM users
-      WHERE notifications_disabled = FALSE
-      "#
+      SELECT
+        i.id,
+        i.seller_tg_id,
+        i.category_id,
+        i.title,
+        i.description,
+        i.start_price,
+        i.image_file_id,
+        i.is_open,
+        i.is_new,
+        i.created_at,
+        i.relisted_from_id,
+        i.currency,
+        i.deleted_at,
+        i.publish_at,
+        i.published_at,
+        i.closes_at,
+        i.event_id,
+        c.name AS category_name,
+        (SELECT MAX(b.amount) FROM bids b WHERE b.item_id = i.id AND b.retracted_at IS NULL) AS best_bid,
+        o.amount AS "final_price?",
+        o.winner_tg_id AS "winner_tg_id?",
+        o.status AS "order_status?"
+      FROM items i
+      JOIN categories c ON c.id = i.category_id
+      LEFT JOIN orders o ON o.item_id = i.id
+      WHERE ($1::timestamptz IS NULL OR i.created_at >= $1)
+        AND ($2::timestamptz IS NULL OR i.created_at < $2)
+        AND ($3::bigint IS NULL OR i.category_id = $3)
+      ORDER BY i.created_at ASC, i.id ASC
+      "#,
+      filter.since,
+      filter.until,
+      filter.category_id
     )
     .fetch_all(&self.pool)
     .await?;
-    Ok(ids)
+    rows
+      .into_iter()
+      .map(|row| {
+        Ok(ItemResultRow {
+          item: ItemRow {
+            id: row.id,
+            seller_tg_id: row.seller_tg_id,
+            category_id: row.category_id,
+            title: row.title,
+            description: row.description,
+            start_price: row.start_price,
+            image_file_id: row.image_file_id.map(|i| i.into()),
+            is_open: row.is_open,
+            is_new: row.is_new,
+            created_at: row.created_at,
+            relisted_from_id: row.relisted_from_id,
+            currency: row.currency,
+            deleted_at: row.deleted_at,
+            publish_at: row.publish_at,
+            published_at: row.published_at,
+            closes_at: row.closes_at,
+            event_id: row.event_id,
+          },
+          category_name: row.category_name,
+          best_bid: row.best_bid,
+          final_price: row.final_price,
+          winner_tg_id: row.winner_tg_id,
+          order_status: row.order_status.as_deref().map(parse_order_status).transpose()?,
+        })
+      })
+      .collect()
   }
 
+  /// Every bid placed within the filter, retracted ones included, in the order they were placed.
   #[instrument(skip(self))]
-  pub async fn list_new_items(&self) -> Result<Vec<ItemRow>> {
+  pub async fn export_bid_ledger(&self, filter: &ExportFilter) -> Result<Vec<BidLedgerRow>> {
     let rows = sqlx::query!(
       r#"
       SELECT
-        id,
-        seller_tg_id,
-        category_id,
-        title,
-        description,
-        start_price,
-        image_file_id,
-        is_open,
-        is_new,
-        created_at
-      FROM items
-      WHERE is_new = TRUE
-      ORDER BY created_at DESC
-      "#
+        b.id,
+        b.item_id,
+        b.bidder_tg_id,
+        b.amount,
+        b.created_at,
+        b.retracted_at,
+        b.retracted_by,
+        b.retraction_reason,
+        i.title AS item_title
+      FROM bids b
+      JOIN items i ON i.id = b.item_id
+      WHERE ($1::timestamptz IS NULL OR b.created_at >= $1)
+        AND ($2::timestamptz IS NULL OR b.created_at < $2)
+        AND ($3::bigint IS NULL OR i.category_id = $3)
+      ORDER BY b.created_at ASC, b.id ASC
+      "#,
+      filter.since,
+      filter.until,
+      filter.category_id
     )
     .fetch_all(&self.pool)
     .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| BidLedgerRow {
+          bid: BidRow {
+            id: row.id,
+            item_id: row.item_id,
+            bidder_tg_id: row.bidder_tg_id,
+            amount: row.amount,
+            created_at: row.created_at,
+            retracted_at: row.retracted_at,
+            retracted_by: row.retracted_by,
+            retraction_reason: row.retraction_reason,
+          },
+          item_title: row.item_title,
+        })
+        .collect(),
+    )
+  }
 
+  /// Users who joined within the filter. With a category, only those who bid on one of its items.
+  #[instrument(skip(self))]
+  pub async fn export_users(&self, filter: &ExportFilter) -> Result<Vec<UserRow>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT u.id, u.username, u.first_name, u.last_name, u.notifications_disabled, u.language_code, u.locale,
+        u.created_at
+      FROM users u
+      WHERE ($1::timestamptz IS NULL OR u.created_at >= $1)
+        AND ($2::timestamptz IS NULL OR u.created_at < $2)
+        AND (
+          $3::bigint IS NULL
+          OR EXISTS (
+            SELECT 1
+            FROM bids b
+            JOIN items i ON i.id = b.item_id
+            WHERE b.bidder_tg_id = u.id AND i.category_id = $3
+          )
+        )
+      ORDER BY u.created_at ASC, u.id ASC
+      "#,
+      filter.since,
+      filter.until,
+      filter.category_id
+    )
+    .fetch_all(&self.pool)
+    .await?;
     Ok(
       rows
         .into_iter()
-        .map(|row| ItemRow {
+        .map(|row| UserRow {
           id: row.id,
-          seller_tg_id: row.seller_tg_id,
-          category_id: row.category_id,
-          title: row.title,
-          description: row.description,
-          start_price: row.start_price,
-          image_file_id: row.image_file_id.map(|i| i.into()),
-          is_open: row.is_open,
-          is_new: row.is_new,
+          username: row.username,
+          first_name: row.first_name,
+          last_name: row.last_name,
+          notifications_disabled: row.notifications_disabled,
+          language_code: row.language_code,
+          locale: row.locale,
           created_at: row.created_at,
         })
         .collect(),
@@ -569,15 +2227,339 @@ impl Db {
   }
 
   #[instrument(skip(self))]
-  pub async fn clear_new_item_flags(&self, item_ids: &[i64]) -> Result<()> {
-    if item_ids.is_empty() {
-      return Ok(());
+  pub async fn create_auction_event(
+    &self,
+    name: &str,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    created_by: i64,
+  ) -> Result<AuctionEventRow> {
+    let row = sqlx::query!(
+      r#"
+      INSERT INTO auction_events (name, starts_at, ends_at, created_by)
+      VALUES ($1, $2, $3, $4)
+      RETURNING id, name, starts_at, ends_at, created_by, created_at
+      "#,
+      name,
+      starts_at,
+      ends_at,
+      created_by
+    )
+    .fetch_one(&self.pool)
+    .await?;
+    Ok(AuctionEventRow {
+      id: row.id,
+      name: row.name,
+      starts_at: row.starts_at,
+      ends_at: row.ends_at,
+      created_by: row.created_by,
+      created_at: row.created_at,
+    })
+  }
+
+  #[instrument(skip(self))]
+  pub async fn get_auction_event(&self, event_id: i64) -> Result<Option<AuctionEventRow>> {
+    let row = sqlx::query!(
+      r#"
+      SELECT id, name, starts_at, ends_at, created_by, created_at
+      FROM auction_events
+      WHERE id = $1
+      "#,
+      event_id
+    )
+    .fetch_optional(&self.pool)
+    .await?;
+    Ok(row.map(|row| AuctionEventRow {
+      id: row.id,
+      name: row.name,
+      starts_at: row.starts_at,
+      ends_at: row.ends_at,
+      created_by: row.created_by,
+      created_at: row.created_at,
+    }))
+  }
+
+  /// Returns the events that have not ended yet with how many lots they hold, soonest first.
+  #[instrument(skip(self))]
+  pub async fn list_upcoming_auction_events(&self) -> Result<Vec<(AuctionEventRow, i64)>> {
+    let rows = sqlx::query!(
+      r#"
+      SELECT
+        e.id,
+        e.name,
+        e.starts_at,
+        e.ends_at,
+        e.created_by,
+        e.created_at,
+        COUNT(i.id) AS "lot_count!"
+      FROM auction_events e
+      LEFT JOIN items i ON i.event_id = e.id AND i.deleted_at IS NULL
+      WHERE e.ends_at > now()
+      GROUP BY e.id
+      ORDER BY e.starts_at ASC, e.id ASC
+      "#
+    )
+    .fetch_all(&self.pool)
+    .await?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          (
+            AuctionEventRow {
+              id: row.id,
+              name: row.name,
+              starts_at: row.starts_at,
+              ends_at: row.ends_at,
+              created_by: row.created_by,
+              created_at: row.created_at,
+            },
+            row.lot_count,
+          )
+        })
+        .collect(),
+    )
+  }
+}
+
+fn parse_order_status(value: &str) -> Result<OrderStatus> {
+  OrderStatus::parse(value).with_context(|| format!("unknown order status {value:?}"))
+}
+
+/// Database tests run against the server in `TEST_DATABASE_URL`, each in a fresh database. They are skipped when the
+/// variable is unset.
+#[cfg(test)]
+pub(crate) mod testing {
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+
+  use chrono::DateTime;
+  use chrono::Utc;
+  use sqlx::postgres::PgConnectOptions;
+  use sqlx::postgres::PgPoolOptions;
+
+  use super::Db;
+  use super::MIGRATOR;
+  use crate::models::ItemSchedule;
+  use crate::models::NewItem;
+  use crate::models::NewItemCategory;
+
+  static NEXT_DATABASE: AtomicUsize = AtomicUsize::new(0);
+
+  pub async fn scratch_db() -> Option<Db> {
+    let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+      eprintln!("TEST_DATABASE_URL is not set, skipping database test");
+      return None;
+    };
+    let options: PgConnectOptions = url.parse().expect("invalid TEST_DATABASE_URL");
+    let server = PgPoolOptions::new()
+      .max_connections(1)
+      .connect_with(options.clone())
+      .await
+      .expect("failed to connect to the test database server");
+    // Databases left behind by earlier test runs are dropped here, since nothing drops them when a test ends.
+    let prefix = format!("auction_test_{}_", std::process::id());
+    let stale: Vec<String> = sqlx::query_scalar(
+      "SELECT datname FROM pg_database WHERE datname LIKE 'auction\\_test\\_%' AND NOT starts_with(datname, $1)",
+    )
+    .bind(&prefix)
+    .fetch_all(&server)
+    .await
+    .expect("failed to list stale test databases");
+    for name in stale {
+      sqlx::query(&format!(r#"DROP DATABASE IF EXISTS "{name}" WITH (FORCE)"#))
+        .execute(&server)
+        .await
+        .expect("failed to drop stale test database");
     }
+    let name = format!("{prefix}{}", NEXT_DATABASE.fetch_add(1, Ordering::Relaxed));
+    sqlx::query(&format!(r#"CREATE DATABASE "{name}""#))
+      .execute(&server)
+      .await
+      .expect("failed to create test database");
+    let pool = PgPoolOptions::new()
+      .max_connections(2)
+      .connect_with(options.database(&name))
+      .await
+      .expect("failed to connect to test database");
+    MIGRATOR.run(&pool).await.expect("failed to migrate test database");
+    Some(Db { pool })
+  }
 
-    let ids: Vec<i64> = item_ids.to_vec();
-    sqlx::query!("UPDATE items SET is_new = FALSE WHERE id = ANY($1)", &ids)
-      .execute(&self.pool)
-      .await?;
-    Ok(())
+  /// Creates an open item in a new category and returns its id.
+  pub async fn create_item(db: &Db, title: &str, publish_at: Option<DateTime<Utc>>) -> i64 {
+    let category_id = match db.find_category_by_name("Test").await.unwrap() {
+      Some(category) => category.id,
+      None => db.create_category("Test", None).await.unwrap(),
+    };
+    let item = NewItem {
+      category: NewItemCategory::Existing(category_id),
+      title: title.to_string(),
+      description: None,
+      start_price: 1_000,
+      image_file_ids: Vec::new(),
+      schedule: ItemSchedule {
+        publish_at,
+        ..ItemSchedule::default()
+      },
+    };
+    db.create_item(1, &item).await.unwrap()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::TimeDelta;
+  use chrono::Utc;
+
+  use super::testing::create_item;
+  use super::testing::scratch_db;
+  use crate::models::BidRow;
+  use crate::models::CategoryRow;
+
+  #[tokio::test]
+  async fn never_publishes_lots_closed_before_going_live() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let an_hour_ago = Utc::now() - TimeDelta::hours(1);
+    let closed = create_item(&db, "Closed early", Some(an_hour_ago)).await;
+    let due = create_item(&db, "Due", Some(an_hour_ago)).await;
+
+    assert!(db.close_item(closed).await.unwrap());
+    let item = db.get_item(closed).await.unwrap().unwrap();
+    assert!(!item.is_new);
+    assert!(item.publish_at.is_none());
+
+    assert_eq!(db.publish_due_items().await.unwrap(), vec![due]);
+    let new_ids: Vec<i64> = db.list_new_items().await.unwrap().iter().map(|item| item.id).collect();
+    assert_eq!(new_ids, vec![due]);
+    assert!(!db.close_item(closed).await.unwrap());
+  }
+
+  #[tokio::test]
+  async fn keeps_published_lots_pending_until_announced() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let an_hour_ago = Utc::now() - TimeDelta::hours(1);
+    let first = create_item(&db, "First", Some(an_hour_ago)).await;
+    let second = create_item(&db, "Second", Some(an_hour_ago)).await;
+    let closed = create_item(&db, "Closed", Some(an_hour_ago)).await;
+    create_item(&db, "Later", Some(Utc::now() + TimeDelta::hours(1))).await;
+    let live = create_item(&db, "Live", None).await;
+    let published_at = async |item_id| db.get_item(item_id).await.unwrap().unwrap().published_at;
+    assert!(published_at(live).await.is_some());
+    assert_eq!(published_at(first).await, None);
+
+    let mut published = db.publish_due_items().await.unwrap();
+    published.sort_unstable();
+    assert_eq!(published, vec![first, second, closed]);
+    assert!(published_at(first).await.unwrap() > an_hour_ago);
+    assert!(db.publish_due_items().await.unwrap().is_empty());
+    db.close_item(closed).await.unwrap();
+    assert_eq!(db.list_pending_publications().await.unwrap(), vec![first, second]);
+
+    db.finish_publications(&[first]).await.unwrap();
+    assert_eq!(db.list_pending_publications().await.unwrap(), vec![second]);
+  }
+
+  #[tokio::test]
+  async fn relists_a_closed_item_only_once() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let item_id = create_item(&db, "Rug", None).await;
+    assert_eq!(db.relist_item(item_id, None).await.unwrap(), None);
+
+    db.close_item(item_id).await.unwrap();
+    let (first, second) = tokio::join!(db.relist_item(item_id, None), db.relist_item(item_id, Some(500)));
+    let relisted: Vec<i64> = [first.unwrap(), second.unwrap()].into_iter().flatten().collect();
+    assert_eq!(relisted.len(), 1);
+    assert_eq!(db.relist_item(item_id, None).await.unwrap(), None);
+    let copy = db.get_item(relisted[0]).await.unwrap().unwrap();
+    assert_eq!(copy.relisted_from_id, Some(item_id));
+    assert!(copy.is_open);
+  }
+
+  #[tokio::test]
+  async fn never_relists_archived_items() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let archived = create_item(&db, "Vase", None).await;
+    db.close_item(archived).await.unwrap();
+    assert!(db.archive_item(archived, 1).await.unwrap());
+    assert_eq!(db.relist_item(archived, None).await.unwrap(), None);
+
+    let in_archived_category = create_item(&db, "Bowl", None).await;
+    db.close_item(in_archived_category).await.unwrap();
+    let category_id = db.get_item(in_archived_category).await.unwrap().unwrap().category_id;
+    assert!(db.archive_category(category_id, 1).await.unwrap());
+    // Only the category stays archived, which the bot itself never leaves behind.
+    sqlx::query("UPDATE items SET deleted_at = NULL, deleted_by = NULL WHERE id = $1")
+      .bind(in_archived_category)
+      .execute(db.pool())
+      .await
+      .unwrap();
+    assert_eq!(db.relist_item(in_archived_category, None).await.unwrap(), None);
+  }
+
+  #[tokio::test]
+  async fn archives_a_category_only_if_its_items_can_move() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let item_id = create_item(&db, "Chair", None).await;
+    let source = db.get_item(item_id).await.unwrap().unwrap().category_id;
+    let inactive = db.create_category("Inactive", None).await.unwrap();
+    db.archive_category(inactive, 1).await.unwrap();
+
+    assert_eq!(
+      db.archive_category_moving_items(source, inactive, 1).await.unwrap(),
+      None
+    );
+    let item = db.get_item(item_id).await.unwrap().unwrap();
+    assert_eq!(item.category_id, source);
+    assert!(!item.is_archived());
+    let is_active = |categories: Vec<CategoryRow>| categories.iter().any(|category| category.id == source);
+    assert!(is_active(db.list_categories().await.unwrap()));
+
+    let target = db.create_category("Target", None).await.unwrap();
+    assert_eq!(
+      db.archive_category_moving_items(source, target, 1).await.unwrap(),
+      Some(1)
+    );
+    let item = db.get_item(item_id).await.unwrap().unwrap();
+    assert_eq!(item.category_id, target);
+    assert!(!item.is_archived());
+    assert!(!is_active(db.list_categories().await.unwrap()));
+  }
+
+  #[tokio::test]
+  async fn pages_bids_and_totals_only_active_ones() {
+    let Some(db) = scratch_db().await else {
+      return;
+    };
+    let item_id = create_item(&db, "Lamp", None).await;
+    let first = db.place_bid(item_id, 10, 1_100).await.unwrap();
+    db.place_bid(item_id, 20, 1_200).await.unwrap();
+    db.place_bid(item_id, 10, 1_300).await.unwrap();
+    let retracted = db.place_bid(item_id, 30, 1_400).await.unwrap();
+    assert!(db.retract_bid(retracted, 1, "shill").await.unwrap());
+
+    let totals = db.item_bid_totals(item_id).await.unwrap();
+    assert_eq!(totals.active, 3);
+    assert_eq!(totals.retracted, 1);
+    assert_eq!(totals.unique_bidders, 2);
+    assert_eq!(totals.best, Some(1_300));
+    let first_bid = db.get_bid(first).await.unwrap().unwrap();
+    assert_eq!(totals.first_bid_at, Some(first_bid.created_at));
+
+    let ids = |bids: Vec<BidRow>| bids.iter().map(|bid| bid.id).collect::<Vec<_>>();
+    let newest = ids(db.list_item_bids_page(item_id, 3, 0).await.unwrap());
+    assert_eq!(newest.first(), Some(&retracted));
+    assert_eq!(newest.len(), 3);
+    assert_eq!(ids(db.list_item_bids_page(item_id, 3, 3).await.unwrap()), vec![first]);
   }
 }
@@ -0,0 +1,203 @@
+use fluent_bundle::FluentArgs;
+use fluent_bundle::FluentResource;
+use fluent_bundle::concurrent::FluentBundle;
+use once_cell::sync::Lazy;
+use tracing::warn;
+use unic_langid::LanguageIdentifier;
+
+/// Formats a message from the catalog: `tr!(locale, "bid-placed", amount = text, item_id = id)`.
+macro_rules! tr {
+  ($locale:expr, $key:literal) => {
+    $locale.text($key, None)
+  };
+  ($locale:expr, $key:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+    let mut args = ::fluent_bundle::FluentArgs::new();
+    $(args.set(stringify!($name), $value);)+
+    $locale.text($key, Some(&args))
+  }};
+}
+pub(crate) use tr;
+
+/// A language the bot speaks. Users get the one matching their Telegram language unless they pick another in
+/// settings; anything unsupported falls back to English.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+  #[default]
+  En,
+  Ar,
+  Ru,
+}
+
+impl Locale {
+  pub const ALL: [Locale; 3] = [Self::En, Self::Ar, Self::Ru];
+
+  pub fn code(self) -> &'static str {
+    match self {
+      Self::En => "en",
+      Self::Ar => "ar",
+      Self::Ru => "ru",
+    }
+  }
+
+  /// The language's own name, shown in the language picker.
+  pub fn native_name(self) -> &'static str {
+    match self {
+      Self::En => "English",
+      Self::Ar => "العربية",
+      Self::Ru => "Русский",
+    }
+  }
+
+  /// Matches an IETF tag such as Telegram's `language_code` (`ru`, `ar-AE`, `en-US`) by its primary language.
+  pub fn from_code(code: &str) -> Option<Self> {
+    let language = code.trim().split(['-', '_']).next()?;
+    Self::ALL
+      .into_iter()
+      .find(|locale| locale.code().eq_ignore_ascii_case(language))
+  }
+
+  /// Picks the locale for a stored or reported language code, defaulting to English.
+  pub fn resolve(code: Option<&str>) -> Self {
+    code.and_then(Self::from_code).unwrap_or_default()
+  }
+
+  fn source(self) -> &'static str {
+    match self {
+      Self::En => include_str!("../locales/en.ftl"),
+      Self::Ar => include_str!("../locales/ar.ftl"),
+      Self::Ru => include_str!("../locales/ru.ftl"),
+    }
+  }
+
+  /// Formats `key` in this locale, falling back to English and finally to the key itself when a message is missing.
+  /// Prefer the [`tr!`] macro, which builds the arguments.
+  pub fn text(self, key: &str, args: Option<&FluentArgs>) -> String {
+    BUNDLES[self as usize]
+      .format(key, args)
+      .or_else(|| BUNDLES[Locale::En as usize].format(key, args))
+      .unwrap_or_else(|| {
+        warn!(key, locale = self.code(), "missing translation");
+        key.to_string()
+      })
+  }
+}
+
+struct Catalog {
+  locale: Locale,
+  bundle: FluentBundle<FluentResource>,
+}
+
+impl Catalog {
+  fn load(locale: Locale) -> Self {
+    let resource = FluentResource::try_new(locale.source().to_string())
+      .unwrap_or_else(|(_, errors)| panic!("invalid {} catalog: {errors:?}", locale.code()));
+    let langid: LanguageIdentifier = locale.code().parse().expect("valid language identifier");
+    let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+    // Isolation marks around placeables show up as stray characters in some Telegram clients.
+    bundle.set_use_isolating(false);
+    bundle
+      .add_resource(resource)
+      .unwrap_or_else(|errors| panic!("duplicate messages in {} catalog: {errors:?}", locale.code()));
+    Self { locale, bundle }
+  }
+
+  fn format(&self, key: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let pattern = self.bundle.get_message(key)?.value()?;
+    let mut errors = Vec::new();
+    let text = self.bundle.format_pattern(pattern, args, &mut errors).into_owned();
+    if !errors.is_empty() {
+      warn!(
+        key,
+        locale = self.locale.code(),
+        ?errors,
+        "failed to format translation"
+      );
+    }
+    Some(text)
+  }
+}
+
+/// One catalog per locale, indexed by the `Locale` discriminant.
+static BUNDLES: Lazy<Vec<Catalog>> = Lazy::new(|| Locale::ALL.into_iter().map(Catalog::load).collect());
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeSet;
+  use std::fs;
+  use std::path::Path;
+
+  use regex::Regex;
+
+  use super::BUNDLES;
+  use super::Locale;
+
+  fn message_ids(locale: Locale) -> BTreeSet<String> {
+    let definition = Regex::new(r"(?m)^([a-zA-Z][a-zA-Z0-9_-]*)\s*=").unwrap();
+    definition
+      .captures_iter(locale.source())
+      .map(|captures| captures[1].to_string())
+      .collect()
+  }
+
+  #[test]
+  fn every_key_exists_in_every_locale() {
+    let english = message_ids(Locale::En);
+    for locale in Locale::ALL {
+      let keys = message_ids(locale);
+      let missing: Vec<_> = english.difference(&keys).collect();
+      let extra: Vec<_> = keys.difference(&english).collect();
+      assert!(missing.is_empty(), "{} is missing {missing:?}", locale.code());
+      assert!(extra.is_empty(), "{} has unknown keys {extra:?}", locale.code());
+      let catalog = &BUNDLES[locale as usize];
+      assert_eq!(catalog.locale, locale);
+      for key in &english {
+        assert!(
+          catalog.bundle.has_message(key),
+          "{} failed to load {key}",
+          locale.code()
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn every_key_used_in_code_is_defined() {
+    let english = message_ids(Locale::En);
+    let usage = Regex::new(r#"tr!\(\s*[^,]+,\s*"([a-z0-9-]+)""#).unwrap();
+    let mut pending = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("src")];
+    while let Some(dir) = pending.pop() {
+      for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+          pending.push(path);
+          continue;
+        }
+        let source = fs::read_to_string(&path).unwrap();
+        for key in usage.captures_iter(&source).map(|captures| captures[1].to_string()) {
+          assert!(english.contains(&key), "{} uses undefined key {key}", path.display());
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn formats_with_arguments_and_plurals() {
+    assert_eq!(
+      tr!(Locale::En, "bid-error-too-low", amount = "AED 10.00"),
+      "Your bid must exceed AED 10.00."
+    );
+    assert_eq!(tr!(Locale::En, "bids-header", count = 1), "🪙 Active bids (1 item):");
+    assert_eq!(tr!(Locale::En, "bids-header", count = 3), "🪙 Active bids (3 items):");
+    assert_eq!(tr!(Locale::Ru, "item-closed"), "🔒 Аукцион закрыт");
+    assert_eq!(Locale::Ar.text("no-such-key", None), "no-such-key");
+  }
+
+  #[test]
+  fn resolves_telegram_language_codes() {
+    assert_eq!(Locale::resolve(Some("ru")), Locale::Ru);
+    assert_eq!(Locale::resolve(Some("ar-AE")), Locale::Ar);
+    assert_eq!(Locale::resolve(Some("EN_us")), Locale::En);
+    assert_eq!(Locale::resolve(Some("de")), Locale::En);
+    assert_eq!(Locale::resolve(None), Locale::En);
+  }
+}
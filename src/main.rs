@@ -1,24 +1,69 @@
 mod app;
 mod bot;
+mod cli;
 mod config;
+mod console;
 mod db;
+mod i18n;
+mod metrics;
 mod models;
+mod server;
 mod telemetry;
 mod util;
 
 use anyhow::Result;
+use clap::Parser;
 use teloxide::prelude::Bot;
 use tracing::info;
 
+use crate::bot::alerts::Alerts;
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::config::LayeredConfig;
+
 #[tokio::main]
 async fn main() -> Result<()> {
-  telemetry::init()?;
-  let config = config::Config::from_env()?;
-  let admin_count = config.admins.len();
-  info!(admin_count = admin_count, "starting bot");
+  let cli = Cli::parse();
+  let layers = LayeredConfig::load(cli.config.as_deref(), &cli.overrides)?;
+  // Printed before validation, so that a configuration that fails to resolve can still be inspected.
+  if cli.print_config {
+    print!("{}", layers.redacted()?);
+    return Ok(());
+  }
+  let mut config = layers.resolve()?;
+
+  let telemetry = telemetry::init(&config.telemetry)?;
+  let bot = Bot::new(config.telegram.bot_token.clone());
+  let result = match cli.command {
+    Some(command) => {
+      let db = db::Db::connect(&config.db).await?;
+      config.telegram.add_admins(db.list_granted_admins().await?);
+      console::run(command, bot, db, &config).await
+    },
+    None => serve(bot, config).await,
+  };
+  telemetry.shutdown();
+  result
+}
 
-  let bot = Bot::new(config.bot_token.clone());
-  let db = db::Db::connect(&config.database_url).await?;
-  let app = app::App::new(bot, db, config.admins);
-  app.run().await
+async fn serve(bot: Bot, mut config: Config) -> Result<()> {
+  let db = match db::Db::connect(&config.db).await {
+    Ok(db) => db,
+    Err(err) => {
+      let alert = format!("🚨 Startup failed, the bot is not running.\n\n{err:#}");
+      Alerts::new(&config).raise(&bot, "startup:database", alert).await;
+      return Err(err);
+    },
+  };
+  // Admins granted from the console join the configured ones.
+  config.telegram.add_admins(db.list_granted_admins().await?);
+  info!(
+    admin_count = config.telegram.admins.len(),
+    channel_configured = config.telegram.channel_id.is_some(),
+    payments_configured = config.telegram.payment_provider_token.is_some(),
+    currency = config.auction.currency.code,
+    webhook = matches!(config.telegram.updates, config::UpdateMode::Webhook { .. }),
+    "starting bot"
+  );
+  app::App::new(bot, db, &config).run().await
 }
@@ -0,0 +1,181 @@
+use std::future::IntoFuture;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use prometheus::HistogramOpts;
+use prometheus::HistogramVec;
+use prometheus::IntCounterVec;
+use prometheus::IntGauge;
+use prometheus::Opts;
+use prometheus::Registry;
+use prometheus::TextEncoder;
+use teloxide::RequestError;
+
+/// Every metric the bot exports. They live in a registry of their own, so `/metrics` shows nothing else.
+struct Metrics {
+  registry: Registry,
+  updates: IntCounterVec,
+  bids: IntCounterVec,
+  notifications: IntCounterVec,
+  telegram_seconds: HistogramVec,
+  telegram_errors: IntCounterVec,
+  db_query_seconds: HistogramVec,
+  open_items: IntGauge,
+}
+
+impl Metrics {
+  fn new() -> prometheus::Result<Self> {
+    let registry = Registry::new();
+    let updates = IntCounterVec::new(
+      Opts::new("bot_updates_total", "Updates received, by update kind and route"),
+      &["kind", "route"],
+    )?;
+    let bids = IntCounterVec::new(Opts::new("auction_bids_total", "Bids submitted, by outcome"), &[
+      "outcome",
+    ])?;
+    let notifications = IntCounterVec::new(
+      Opts::new(
+        "bot_notifications_total",
+        "Notifications sent to users, by kind and result",
+      ),
+      &["kind", "result"],
+    )?;
+    let telegram_seconds = HistogramVec::new(
+      HistogramOpts::new(
+        "telegram_request_duration_seconds",
+        "Latency of tracked Telegram Bot API calls",
+      ),
+      &["method"],
+    )?;
+    let telegram_errors = IntCounterVec::new(
+      Opts::new(
+        "telegram_request_errors_total",
+        "Failed Telegram Bot API calls, by error kind",
+      ),
+      &["method", "error"],
+    )?;
+    let db_query_seconds = HistogramVec::new(
+      HistogramOpts::new("db_query_duration_seconds", "Latency of database calls, by Db method")
+        .buckets(vec![0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5]),
+      &["query"],
+    )?;
+    let open_items = IntGauge::new("auction_open_items", "Items currently open for bidding")?;
+
+    registry.register(Box::new(updates.clone()))?;
+    registry.register(Box::new(bids.clone()))?;
+    registry.register(Box::new(notifications.clone()))?;
+    registry.register(Box::new(telegram_seconds.clone()))?;
+    registry.register(Box::new(telegram_errors.clone()))?;
+    registry.register(Box::new(db_query_seconds.clone()))?;
+    registry.register(Box::new(open_items.clone()))?;
+    Ok(Self {
+      registry,
+      updates,
+      bids,
+      notifications,
+      telegram_seconds,
+      telegram_errors,
+      db_query_seconds,
+      open_items,
+    })
+  }
+}
+
+static METRICS: Lazy<Metrics> = Lazy::new(|| Metrics::new().expect("metric definitions are valid"));
+
+pub fn record_update(kind: &str, route: &str) {
+  METRICS.updates.with_label_values(&[kind, route]).inc();
+}
+
+/// Counts a submitted bid. `outcome` is `accepted` or the kind of error that rejected it.
+pub fn record_bid(outcome: &str) {
+  METRICS.bids.with_label_values(&[outcome]).inc();
+}
+
+pub fn record_db_query(query: &str, elapsed: Duration) {
+  METRICS
+    .db_query_seconds
+    .with_label_values(&[query])
+    .observe(elapsed.as_secs_f64());
+}
+
+/// Times a Telegram Bot API call and counts its failures by kind.
+pub async fn telegram<T>(
+  method: &str,
+  request: impl IntoFuture<Output = Result<T, RequestError>>,
+) -> Result<T, RequestError> {
+  let started = Instant::now();
+  let result = request.await;
+  METRICS
+    .telegram_seconds
+    .with_label_values(&[method])
+    .observe(started.elapsed().as_secs_f64());
+  if let Err(err) = &result {
+    METRICS
+      .telegram_errors
+      .with_label_values(&[method, request_error_kind(err)])
+      .inc();
+  }
+  result
+}
+
+/// Sends a notification message, counting whether it reached the user on top of the call's latency.
+pub async fn notification<T>(
+  kind: &str,
+  request: impl IntoFuture<Output = Result<T, RequestError>>,
+) -> Result<T, RequestError> {
+  let result = telegram("sendMessage", request).await;
+  let outcome = if result.is_ok() { "sent" } else { "failed" };
+  METRICS.notifications.with_label_values(&[kind, outcome]).inc();
+  result
+}
+
+fn request_error_kind(err: &RequestError) -> &'static str {
+  match err {
+    RequestError::Api(_) => "api",
+    RequestError::MigrateToChatId(_) => "migrate_to_chat",
+    RequestError::RetryAfter(_) => "retry_after",
+    RequestError::Network(_) => "network",
+    RequestError::InvalidJson { .. } => "invalid_json",
+    RequestError::Io(_) => "io",
+  }
+}
+
+/// Renders every metric in the Prometheus text format. Gauges that mirror the database are refreshed by the caller
+/// right before, so a scrape always sees current values.
+pub fn render(open_items: Option<i64>) -> Result<String> {
+  if let Some(count) = open_items {
+    METRICS.open_items.set(count);
+  }
+  Ok(TextEncoder::new().encode_to_string(&METRICS.registry.gather())?)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use teloxide::ApiError;
+  use teloxide::RequestError;
+
+  use super::notification;
+  use super::record_bid;
+  use super::record_db_query;
+  use super::render;
+
+  #[tokio::test]
+  async fn renders_recorded_metrics() {
+    record_bid("too_low");
+    record_db_query("get_item", Duration::from_millis(3));
+    let failed = notification::<()>("outbid", async { Err(RequestError::Api(ApiError::BotBlocked)) }).await;
+    assert!(failed.is_err());
+
+    let text = render(Some(4)).unwrap();
+    assert!(text.contains(r#"auction_bids_total{outcome="too_low"}"#));
+    assert!(text.contains(r#"db_query_duration_seconds_count{query="get_item"}"#));
+    assert!(text.contains(r#"bot_notifications_total{kind="outbid",result="failed"}"#));
+    assert!(text.contains(r#"telegram_request_errors_total{error="api",method="sendMessage"}"#));
+    assert!(text.contains("auction_open_items 4"));
+  }
+}
@@ -12,13 +12,38 @@ pub struct UserRow {
   pub first_name: Option<String>,
   pub last_name: Option<String>,
   pub notifications_disabled: bool,
+  /// Language reported by Telegram on the user's last interaction.
+  pub language_code: Option<String>,
+  /// Language picked in settings, overriding `language_code`.
+  pub locale: Option<String>,
   pub created_at: DateTime<Utc>,
 }
 
+impl UserRow {
+  /// The language to talk to this user in, as an unvalidated code.
+  pub fn preferred_language(&self) -> Option<&str> {
+    self.locale.as_deref().or(self.language_code.as_deref())
+  }
+
+  pub fn display_name(&self) -> String {
+    if let Some(username) = &self.username {
+      return format!("@{username}");
+    }
+    match (&self.first_name, &self.last_name) {
+      (Some(first), Some(last)) => format!("{first} {last}"),
+      (Some(first), None) => first.clone(),
+      (None, Some(last)) => last.clone(),
+      (None, None) => format!("user {}", self.id),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CategoryRow {
   pub id: i64,
   pub name: String,
+  /// Currency code new items in this category are priced in, overriding the deployment currency.
+  pub currency: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +58,83 @@ pub struct ItemRow {
   pub is_open: bool,
   pub is_new: bool,
   pub created_at: DateTime<Utc>,
+  pub relisted_from_id: Option<i64>,
+  /// Currency code the item is priced in. `None` means the deployment currency.
+  pub currency: Option<String>,
+  pub deleted_at: Option<DateTime<Utc>>,
+  /// While set, the item is hidden from everyone but admins until the scheduler publishes it.
+  pub publish_at: Option<DateTime<Utc>>,
+  /// When the item went live. `None` until a scheduled item is published.
+  pub published_at: Option<DateTime<Utc>>,
+  /// When the scheduler closes the item on its own.
+  pub closes_at: Option<DateTime<Utc>>,
+  pub event_id: Option<i64>,
+}
+
+impl ItemRow {
+  /// Archived items are hidden from the catalogue and cannot be bid on, but keep their history.
+  pub fn is_archived(&self) -> bool {
+    self.deleted_at.is_some()
+  }
+
+  /// Scheduled items that have not gone live yet are hidden like archived ones.
+  pub fn is_published(&self) -> bool {
+    self.publish_at.is_none()
+  }
+
+  /// An open, published lot in category 1, for tests to adjust with struct update syntax.
+  #[cfg(test)]
+  pub fn fixture(id: i64, title: &str, start_price: i64) -> Self {
+    Self {
+      id,
+      seller_tg_id: 1,
+      category_id: 1,
+      title: title.to_string(),
+      description: None,
+      start_price,
+      image_file_id: None,
+      is_open: true,
+      is_new: false,
+      created_at: Utc::now(),
+      relisted_from_id: None,
+      currency: None,
+      deleted_at: None,
+      publish_at: None,
+      published_at: Some(Utc::now()),
+      closes_at: None,
+      event_id: None,
+    }
+  }
+}
+
+/// Lots sharing a start and an end. Joining an event schedules a lot for both.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuctionEventRow {
+  pub id: i64,
+  pub name: String,
+  pub starts_at: DateTime<Utc>,
+  pub ends_at: DateTime<Utc>,
+  pub created_by: i64,
+  pub created_at: DateTime<Utc>,
+}
+
+impl AuctionEventRow {
+  /// The schedule of a lot joining the event at `now`. Lots joining a running event go live right away.
+  pub fn schedule(&self, now: DateTime<Utc>) -> ItemSchedule {
+    ItemSchedule {
+      publish_at: (self.starts_at > now).then_some(self.starts_at),
+      closes_at: Some(self.ends_at),
+      event_id: Some(self.id),
+    }
+  }
+}
+
+/// When a new item goes live and closes. The default publishes right away and leaves closing to the admins.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ItemSchedule {
+  pub publish_at: Option<DateTime<Utc>>,
+  pub closes_at: Option<DateTime<Utc>>,
+  pub event_id: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,4 +145,352 @@ pub struct BidRow {
   pub bidder_tg_id: i64,
   pub amount: i64,
   pub created_at: DateTime<Utc>,
+  pub retracted_at: Option<DateTime<Utc>>,
+  pub retracted_by: Option<i64>,
+  pub retraction_reason: Option<String>,
+}
+
+/// Aggregates over all bids on an item. Retracted bids only count towards `retracted`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BidTotals {
+  pub active: i64,
+  pub retracted: i64,
+  pub unique_bidders: i64,
+  pub first_bid_at: Option<DateTime<Utc>>,
+  pub best: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemCardRow {
+  pub chat_id: i64,
+  pub message_id: i32,
+  pub viewer_tg_id: Option<i64>,
+}
+
+/// Checkout progress of a won item. Orders move forward one step at a time and can be cancelled until shipped.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+  AwaitingPayment,
+  Paid,
+  Shipped,
+  Completed,
+  Cancelled,
+}
+
+impl OrderStatus {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::AwaitingPayment => "awaiting_payment",
+      Self::Paid => "paid",
+      Self::Shipped => "shipped",
+      Self::Completed => "completed",
+      Self::Cancelled => "cancelled",
+    }
+  }
+
+  pub fn parse(value: &str) -> Option<Self> {
+    match value {
+      "awaiting_payment" => Some(Self::AwaitingPayment),
+      "paid" => Some(Self::Paid),
+      "shipped" => Some(Self::Shipped),
+      "completed" => Some(Self::Completed),
+      "cancelled" => Some(Self::Cancelled),
+      _ => None,
+    }
+  }
+
+  pub fn label(self) -> &'static str {
+    match self {
+      Self::AwaitingPayment => "⏳ Awaiting payment",
+      Self::Paid => "💳 Paid",
+      Self::Shipped => "🚚 Shipped",
+      Self::Completed => "✅ Completed",
+      Self::Cancelled => "❌ Cancelled",
+    }
+  }
+
+  /// Statuses an admin may move an order to from this one.
+  pub fn next_steps(self) -> &'static [OrderStatus] {
+    match self {
+      Self::AwaitingPayment => &[Self::Paid, Self::Cancelled],
+      Self::Paid => &[Self::Shipped, Self::Cancelled],
+      Self::Shipped => &[Self::Completed],
+      Self::Completed | Self::Cancelled => &[],
+    }
+  }
+
+  pub fn can_become(self, next: OrderStatus) -> bool {
+    self.next_steps().contains(&next)
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderRow {
+  pub id: i64,
+  pub item_id: i64,
+  pub winner_tg_id: i64,
+  pub amount: i64,
+  pub status: OrderStatus,
+  pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecondChanceOfferRow {
+  pub id: i64,
+  pub item_id: i64,
+  pub bidder_tg_id: i64,
+  pub amount: i64,
+  pub offered_by: i64,
+  pub expires_at: DateTime<Utc>,
+}
+
+/// Privileged admin actions recorded in the audit log.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+  ItemCreated,
+  ItemClosed,
+  ItemArchived,
+  ItemRestored,
+  ItemRelisted,
+  CategoryCreated,
+  CategoryArchived,
+  CategoryRestored,
+  Broadcast,
+  NewLotsNotified,
+  BidRetracted,
+  OrderStatusChanged,
+  SecondChanceOffered,
+  AdminGranted,
+  EventCreated,
+}
+
+impl AuditAction {
+  pub const ALL: [AuditAction; 15] = [
+    Self::ItemCreated,
+    Self::ItemClosed,
+    Self::ItemArchived,
+    Self::ItemRestored,
+    Self::ItemRelisted,
+    Self::CategoryCreated,
+    Self::CategoryArchived,
+    Self::CategoryRestored,
+    Self::Broadcast,
+    Self::NewLotsNotified,
+    Self::BidRetracted,
+    Self::OrderStatusChanged,
+    Self::SecondChanceOffered,
+    Self::AdminGranted,
+    Self::EventCreated,
+  ];
+
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::ItemCreated => "item_created",
+      Self::ItemClosed => "item_closed",
+      Self::ItemArchived => "item_archived",
+      Self::ItemRestored => "item_restored",
+      Self::ItemRelisted => "item_relisted",
+      Self::CategoryCreated => "category_created",
+      Self::CategoryArchived => "category_archived",
+      Self::CategoryRestored => "category_restored",
+      Self::Broadcast => "broadcast",
+      Self::NewLotsNotified => "new_lots_notified",
+      Self::BidRetracted => "bid_retracted",
+      Self::OrderStatusChanged => "order_status_changed",
+      Self::SecondChanceOffered => "second_chance_offered",
+      Self::AdminGranted => "admin_granted",
+      Self::EventCreated => "event_created",
+    }
+  }
+
+  pub fn parse(value: &str) -> Option<Self> {
+    Self::ALL.into_iter().find(|action| action.as_str() == value)
+  }
+
+  pub fn label(self) -> &'static str {
+    match self {
+      Self::ItemCreated => "📦 Item created",
+      Self::ItemClosed => "🛑 Item closed",
+      Self::ItemArchived => "🗃 Item archived",
+      Self::ItemRestored => "♻️ Item restored",
+      Self::ItemRelisted => "♻️ Item relisted",
+      Self::CategoryCreated => "🆕 Category created",
+      Self::CategoryArchived => "🗃 Category archived",
+      Self::CategoryRestored => "♻️ Category restored",
+      Self::Broadcast => "📢 Broadcast",
+      Self::NewLotsNotified => "🔔 New lots notified",
+      Self::BidRetracted => "↩️ Bid retracted",
+      Self::OrderStatusChanged => "🧾 Order status changed",
+      Self::SecondChanceOffered => "🥈 Second chance offered",
+      Self::AdminGranted => "🛡️ Admin granted",
+      Self::EventCreated => "🗓 Event created",
+    }
+  }
+}
+
+/// The record an audit entry is about, stored as a `target_type`/`target_id` pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditTarget {
+  Item(i64),
+  Category(i64),
+  Bid(i64),
+  Order(i64),
+  User(i64),
+  Event(i64),
+  /// Actions addressed to users at large, such as broadcasts.
+  Users,
+}
+
+impl AuditTarget {
+  pub fn kind(self) -> &'static str {
+    match self {
+      Self::Item(_) => "item",
+      Self::Category(_) => "category",
+      Self::Bid(_) => "bid",
+      Self::Order(_) => "order",
+      Self::User(_) => "user",
+      Self::Event(_) => "event",
+      Self::Users => "users",
+    }
+  }
+
+  pub fn id(self) -> Option<i64> {
+    match self {
+      Self::Item(id) | Self::Category(id) | Self::Bid(id) | Self::Order(id) | Self::User(id) | Self::Event(id) => {
+        Some(id)
+      },
+      Self::Users => None,
+    }
+  }
+
+  pub fn from_parts(kind: &str, id: Option<i64>) -> Option<Self> {
+    match (kind, id) {
+      ("item", Some(id)) => Some(Self::Item(id)),
+      ("category", Some(id)) => Some(Self::Category(id)),
+      ("bid", Some(id)) => Some(Self::Bid(id)),
+      ("order", Some(id)) => Some(Self::Order(id)),
+      ("user", Some(id)) => Some(Self::User(id)),
+      ("event", Some(id)) => Some(Self::Event(id)),
+      ("users", None) => Some(Self::Users),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditEntryRow {
+  pub id: i64,
+  pub actor_tg_id: i64,
+  pub action: AuditAction,
+  pub target: Option<AuditTarget>,
+  pub payload: serde_json::Value,
+  pub created_at: DateTime<Utc>,
+}
+
+/// A validated lot waiting to be created, by a bulk import or the add item flow.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NewItem {
+  pub category: NewItemCategory,
+  pub title: String,
+  pub description: Option<String>,
+  pub start_price: i64,
+  pub image_file_ids: Vec<FileId>,
+  #[serde(default)]
+  pub schedule: ItemSchedule,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NewItemCategory {
+  Existing(i64),
+  /// Created along with the items. Rows naming the same new category share it, so they must spell it the same.
+  New {
+    name: String,
+    currency: Option<String>,
+  },
+}
+
+/// Narrows an export to records created in `[since, until)` and to the items of one category.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportFilter {
+  pub since: Option<DateTime<Utc>>,
+  pub until: Option<DateTime<Utc>>,
+  pub category_id: Option<i64>,
+}
+
+/// An item with how its auction went, for accounting exports.
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemResultRow {
+  #[serde(flatten)]
+  pub item: ItemRow,
+  pub category_name: String,
+  pub best_bid: Option<i64>,
+  /// What the winner owes. Only closed items with an order have one.
+  pub final_price: Option<i64>,
+  pub winner_tg_id: Option<i64>,
+  pub order_status: Option<OrderStatus>,
+}
+
+/// A bid with the title of the item it was placed on, for the bid ledger export.
+#[derive(Debug, Clone, Serialize)]
+pub struct BidLedgerRow {
+  #[serde(flatten)]
+  pub bid: BidRow,
+  pub item_title: String,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::AuditAction;
+  use super::AuditTarget;
+  use super::OrderStatus;
+
+  #[test]
+  fn order_status_round_trips_through_text() {
+    for status in [
+      OrderStatus::AwaitingPayment,
+      OrderStatus::Paid,
+      OrderStatus::Shipped,
+      OrderStatus::Completed,
+      OrderStatus::Cancelled,
+    ] {
+      assert_eq!(OrderStatus::parse(status.as_str()), Some(status));
+    }
+    assert_eq!(OrderStatus::parse("refunded"), None);
+  }
+
+  #[test]
+  fn orders_advance_one_step_and_cancel_until_shipped() {
+    assert!(OrderStatus::AwaitingPayment.can_become(OrderStatus::Paid));
+    assert!(!OrderStatus::AwaitingPayment.can_become(OrderStatus::Shipped));
+    assert!(OrderStatus::Paid.can_become(OrderStatus::Cancelled));
+    assert!(!OrderStatus::Shipped.can_become(OrderStatus::Cancelled));
+    assert!(OrderStatus::Shipped.can_become(OrderStatus::Completed));
+    assert!(OrderStatus::Completed.next_steps().is_empty());
+    assert!(OrderStatus::Cancelled.next_steps().is_empty());
+  }
+
+  #[test]
+  fn audit_action_round_trips_through_text() {
+    for action in AuditAction::ALL {
+      assert_eq!(AuditAction::parse(action.as_str()), Some(action));
+    }
+    assert_eq!(AuditAction::parse("item_deleted"), None);
+  }
+
+  #[test]
+  fn audit_target_round_trips_through_columns() {
+    for target in [
+      AuditTarget::Item(3),
+      AuditTarget::Category(4),
+      AuditTarget::Bid(5),
+      AuditTarget::Order(6),
+      AuditTarget::Event(7),
+      AuditTarget::Users,
+    ] {
+      assert_eq!(AuditTarget::from_parts(target.kind(), target.id()), Some(target));
+    }
+    assert_eq!(AuditTarget::from_parts("item", None), None);
+  }
 }
@@ -0,0 +1,73 @@
+use std::net::SocketAddr;
+
+use anyhow::Context;
+use anyhow::Result;
+use axum::Router;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use tokio::net::TcpListener;
+use tracing::info;
+use tracing::warn;
+
+use crate::db::Db;
+use crate::metrics;
+
+/// Routes for the platform's health checks and the Prometheus scraper. `/healthz` only proves the process answers
+/// HTTP, `/readyz` also needs a working database connection, so a deploy only switches traffic once the new machine
+/// can handle updates.
+pub fn routes(db: Db) -> Router {
+  Router::new()
+    .route("/healthz", get(|| async { "ok" }))
+    .route("/readyz", get(readiness))
+    .route("/metrics", get(export_metrics))
+    .with_state(db)
+}
+
+async fn readiness(State(db): State<Db>) -> (StatusCode, &'static str) {
+  match db.ping().await {
+    Ok(()) => (StatusCode::OK, "ready"),
+    Err(err) => {
+      warn!(error = %err, "readiness check failed");
+      (StatusCode::SERVICE_UNAVAILABLE, "database unavailable")
+    },
+  }
+}
+
+async fn export_metrics(State(db): State<Db>) -> impl IntoResponse {
+  let open_items = match db.count_open_items().await {
+    Ok(count) => Some(count),
+    Err(err) => {
+      warn!(error = %err, "failed to count open items for metrics");
+      None
+    },
+  };
+  match metrics::render(open_items) {
+    Ok(body) => Ok(([(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)], body)),
+    Err(err) => {
+      warn!(error = %err, "failed to render metrics");
+      Err(StatusCode::INTERNAL_SERVER_ERROR)
+    },
+  }
+}
+
+/// Binds the HTTP server on every interface, failing fast when the port is taken.
+pub async fn bind(port: u16) -> Result<TcpListener> {
+  let address = SocketAddr::from(([0, 0, 0, 0], port));
+  let listener = TcpListener::bind(address)
+    .await
+    .with_context(|| format!("failed to bind {address}"))?;
+  info!(%address, "http server listening");
+  Ok(listener)
+}
+
+/// Serves `router` until `shutdown` resolves. Runs in the background next to the dispatcher, so errors are logged.
+pub fn spawn(listener: TcpListener, router: Router, shutdown: impl Future<Output = ()> + Send + 'static) {
+  tokio::spawn(async move {
+    if let Err(err) = axum::serve(listener, router).with_graceful_shutdown(shutdown).await {
+      warn!(error = %err, "http server stopped");
+    }
+  });
+}
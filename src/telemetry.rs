@@ -1,9 +1,113 @@
+use std::time::Instant;
+
+use anyhow::Context as _;
 use anyhow::Result;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::SpanExporter;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use tracing::Subscriber;
+use tracing::span;
+use tracing::warn;
 use tracing_subscriber::EnvFilter;
+use tracing_subscriber::Layer;
+use tracing_subscriber::filter::filter_fn;
 use tracing_subscriber::fmt;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::config::LogFormat;
+use crate::config::TelemetryConfig;
+use crate::metrics;
+
+/// Target of the spans `#[instrument]` opens around every `Db` method.
+const DB_TARGET: &str = "tg_auction_bot::db";
+
+/// Keeps the trace exporter alive. Call [`Telemetry::shutdown`] before exiting so buffered spans reach the collector.
+pub struct Telemetry {
+  tracer_provider: Option<SdkTracerProvider>,
+}
+
+impl Telemetry {
+  pub fn shutdown(self) {
+    if let Some(provider) = self.tracer_provider
+      && let Err(err) = provider.shutdown()
+    {
+      warn!(error = %err, "failed to flush trace exporter");
+    }
+  }
+}
+
+pub fn init(config: &TelemetryConfig) -> Result<Telemetry> {
+  let logs = match config.log_format {
+    // Logs go to stderr so the output of the console subcommands stays clean.
+    LogFormat::Text => fmt::layer().with_writer(std::io::stderr).with_target(true).boxed(),
+    LogFormat::Json => fmt::layer()
+      .json()
+      .with_writer(std::io::stderr)
+      .with_target(true)
+      .boxed(),
+  };
+  let tracer_provider = config
+    .otlp_endpoint
+    .as_ref()
+    .map(|endpoint| tracer_provider(endpoint.as_str(), &config.service_name))
+    .transpose()?;
+  let traces = tracer_provider.as_ref().map(|provider| {
+    tracing_opentelemetry::layer()
+      .with_tracer(provider.tracer(env!("CARGO_PKG_NAME")))
+      .with_filter(env_filter())
+  });
+  // Every layer carries its own filter, so a quieter RUST_LOG does not switch off the query timings.
+  tracing_subscriber::registry()
+    .with(logs.with_filter(env_filter()))
+    .with(traces)
+    .with(DbQueryTimer.with_filter(filter_fn(|metadata| metadata.target() == DB_TARGET)))
+    .try_init()?;
+  Ok(Telemetry { tracer_provider })
+}
+
+fn env_filter() -> EnvFilter {
+  EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"))
+}
+
+/// Batches spans to the collector's OTLP/HTTP traces endpoint under `endpoint`.
+fn tracer_provider(endpoint: &str, service_name: &str) -> Result<SdkTracerProvider> {
+  let exporter = SpanExporter::builder()
+    .with_http()
+    .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+    .build()
+    .context("failed to build OTLP span exporter")?;
+  Ok(
+    SdkTracerProvider::builder()
+      .with_batch_exporter(exporter)
+      .with_resource(Resource::builder().with_service_name(service_name.to_string()).build())
+      .build(),
+  )
+}
+
+/// Feeds the lifetime of every `Db` method span into the query latency histogram, labelled with the method name.
+struct DbQueryTimer;
+
+struct QueryStarted(Instant);
+
+impl<S> Layer<S> for DbQueryTimer
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+{
+  fn on_new_span(&self, _attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+    if let Some(span) = ctx.span(id) {
+      span.extensions_mut().insert(QueryStarted(Instant::now()));
+    }
+  }
 
-pub fn init() -> Result<()> {
-  let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-  fmt().with_env_filter(env_filter).with_target(true).init();
-  Ok(())
+  fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+    if let Some(span) = ctx.span(&id)
+      && let Some(QueryStarted(started)) = span.extensions().get::<QueryStarted>()
+    {
+      metrics::record_db_query(span.name(), started.elapsed());
+    }
+  }
 }
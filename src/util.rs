@@ -1,72 +1,304 @@
+use std::fmt;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
 use thiserror::Error;
 
-static PRICE_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d+(?:\.\d{1,2})?$").expect("valid regex"));
+/// An amount with optional thousands separators (`1,250`), fraction and `k`/`m` multiplier.
+static AMOUNT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r"^(?P<major>\d{1,3}(?:,\d{3})+|\d+)(?:\.(?P<minor>\d+))?(?P<mult>[kKmM])?$").expect("valid regex")
+});
 
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum MoneyError {
-  #[error("amount must match 0.00 format")]
+  #[error("use an amount like 50, 1,250.50 or 1.2k")]
   InvalidFormat,
+  #[error("'{0}' could be read more than one way, use a dot for decimals and commas between thousands")]
+  Ambiguous(String),
+  #[error("amount has more decimals than {0} allows")]
+  TooPrecise(&'static str),
+  #[error("prices here are in {0}")]
+  WrongCurrency(&'static str),
   #[error("amount exceeds supported range")]
   OutOfRange,
 }
 
-pub fn parse_money_to_cents(input: &str) -> Result<i64, MoneyError> {
-  if !PRICE_PATTERN.is_match(input.trim()) {
-    return Err(MoneyError::InvalidFormat);
+/// Where a currency's symbol goes relative to the number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolPlacement {
+  /// `AED 12.50`
+  PrefixSpaced,
+  /// `$12.50`
+  Prefix,
+  /// `12.50 ₽`
+  SuffixSpaced,
+}
+
+/// A currency amounts are stored and shown in. Amounts are always kept in minor units (cents, fils, kopecks),
+/// `exponent` tells how many of those make up one major unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Currency {
+  pub code: &'static str,
+  pub symbol: &'static str,
+  pub exponent: u32,
+  pub placement: SymbolPlacement,
+}
+
+impl Currency {
+  pub const AED: Currency = Currency {
+    code: "AED",
+    symbol: "AED",
+    exponent: 2,
+    placement: SymbolPlacement::PrefixSpaced,
+  };
+  pub const EUR: Currency = Currency {
+    code: "EUR",
+    symbol: "€",
+    exponent: 2,
+    placement: SymbolPlacement::Prefix,
+  };
+  pub const GBP: Currency = Currency {
+    code: "GBP",
+    symbol: "£",
+    exponent: 2,
+    placement: SymbolPlacement::Prefix,
+  };
+  pub const KWD: Currency = Currency {
+    code: "KWD",
+    symbol: "KWD",
+    exponent: 3,
+    placement: SymbolPlacement::PrefixSpaced,
+  };
+  pub const RUB: Currency = Currency {
+    code: "RUB",
+    symbol: "₽",
+    exponent: 2,
+    placement: SymbolPlacement::SuffixSpaced,
+  };
+  /// Every currency a deployment or category can be priced in.
+  pub const SUPPORTED: [Currency; 6] = [Self::AED, Self::EUR, Self::GBP, Self::KWD, Self::RUB, Self::USD];
+  pub const USD: Currency = Currency {
+    code: "USD",
+    symbol: "$",
+    exponent: 2,
+    placement: SymbolPlacement::Prefix,
+  };
+
+  /// Looks a currency up by its ISO 4217 code, ignoring case.
+  pub fn from_code(code: &str) -> Option<Currency> {
+    let code = code.trim();
+    Self::SUPPORTED
+      .into_iter()
+      .find(|currency| currency.code.eq_ignore_ascii_case(code))
+  }
+
+  /// How many minor units make up one major unit, e.g. 100 cents in a dollar.
+  pub fn minor_per_major(self) -> i64 {
+    10_i64.pow(self.exponent)
+  }
+
+  fn decorate(self, number: &str) -> String {
+    match self.placement {
+      SymbolPlacement::PrefixSpaced => format!("{} {number}", self.symbol),
+      SymbolPlacement::Prefix => format!("{}{number}", self.symbol),
+      SymbolPlacement::SuffixSpaced => format!("{number} {}", self.symbol),
+    }
   }
 
-  let mut parts = input.trim().split('.');
-  let major = parts
-    .next()
-    .and_then(|p| p.parse::<i64>().ok())
-    .ok_or(MoneyError::InvalidFormat)?;
-
-  let minor = match parts.next() {
-    None => 0,
-    Some(minor) => {
-      if minor.len() == 1 {
-        (minor.to_owned() + "0")
-          .parse::<i64>()
-          .map_err(|_| MoneyError::OutOfRange)?
+  /// Formats an amount of minor units with all of its decimals, e.g. `AED 12.50`.
+  pub fn format(self, amount: i64) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let major = (amount / self.minor_per_major()).unsigned_abs();
+    let minor = (amount % self.minor_per_major()).unsigned_abs();
+    let number = if self.exponent == 0 {
+      format!("{sign}{major}")
+    } else {
+      format!("{sign}{major}.{minor:0width$}", width = self.exponent as usize)
+    };
+    self.decorate(&number)
+  }
+
+  /// Like [`Currency::format`], but drops the minor units of whole amounts. Meant for compact labels such as buttons.
+  pub fn format_short(self, amount: i64) -> String {
+    if amount % self.minor_per_major() == 0 {
+      self.decorate(&(amount / self.minor_per_major()).to_string())
+    } else {
+      self.format(amount)
+    }
+  }
+
+  /// Parses user input into minor units. Accepts plain amounts (`50`, `50.5`), thousands separators (`1,250`),
+  /// `k`/`m` multipliers (`1.2k`) and this currency's code or symbol on either side (`AED 50`, `$50`). A lone comma
+  /// that could be a decimal separator (`1,25`) is rejected rather than guessed.
+  pub fn parse(self, input: &str) -> Result<i64, MoneyError> {
+    let raw = self.strip_currency(input.trim())?;
+    // Spaces between digit groups (`1 250`) must follow the same rules as commas.
+    let number = raw.split_whitespace().collect::<Vec<_>>().join(",");
+    let Some(captures) = AMOUNT_PATTERN.captures(&number) else {
+      return Err(if number.contains(',') {
+        MoneyError::Ambiguous(raw)
       } else {
-        minor[.. 2].parse::<i64>().map_err(|_| MoneyError::OutOfRange)?
+        MoneyError::InvalidFormat
+      });
+    };
+
+    let major: String = captures["major"].chars().filter(|c| *c != ',').collect();
+    let minor = captures.name("minor").map_or("", |m| m.as_str());
+    let multiplier_digits = match captures.name("mult").map(|m| m.as_str()) {
+      Some("k" | "K") => 3,
+      Some("m" | "M") => 6,
+      _ => 0,
+    };
+    let scale = self.exponent + multiplier_digits;
+    let minor_len = minor.len() as u32;
+    if minor_len > scale {
+      return Err(MoneyError::TooPrecise(self.code));
+    }
+
+    let digits = format!("{major}{minor}");
+    digits
+      .parse::<i64>()
+      .ok()
+      .and_then(|value| value.checked_mul(10_i64.checked_pow(scale - minor_len)?))
+      .ok_or(MoneyError::OutOfRange)
+  }
+
+  /// Removes this currency's code or symbol from either end of the input. Another currency's code is an error, so a
+  /// USD amount is never silently read as AED.
+  fn strip_currency(self, input: &str) -> Result<String, MoneyError> {
+    for marker in [self.code, self.symbol] {
+      if let Some(rest) = strip_prefix_ignore_case(input, marker) {
+        return Ok(rest.trim().to_string());
       }
-    },
-  };
+      if let Some(rest) = strip_suffix_ignore_case(input, marker) {
+        return Ok(rest.trim().to_string());
+      }
+    }
+    let mentions_other = Self::SUPPORTED
+      .iter()
+      .filter(|other| other.code != self.code)
+      .flat_map(|other| [other.code, other.symbol])
+      .any(|marker| {
+        strip_prefix_ignore_case(input, marker).is_some() || strip_suffix_ignore_case(input, marker).is_some()
+      });
+    if mentions_other {
+      return Err(MoneyError::WrongCurrency(self.code));
+    }
+    Ok(input.to_string())
+  }
+}
+
+impl Default for Currency {
+  fn default() -> Self {
+    Self::AED
+  }
+}
 
-  major
-    .checked_mul(100)
-    .and_then(|value| value.checked_add(minor))
-    .ok_or(MoneyError::OutOfRange)
+impl fmt::Display for Currency {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.code)
+  }
+}
+
+fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+  let head = input.get(.. prefix.len())?;
+  head.eq_ignore_ascii_case(prefix).then(|| &input[prefix.len() ..])
 }
 
-pub fn format_cents(amount: i64) -> String {
-  format!("AED {:.2}", (amount as f64) / 100.0)
+fn strip_suffix_ignore_case<'a>(input: &'a str, suffix: &str) -> Option<&'a str> {
+  let split = input.len().checked_sub(suffix.len())?;
+  let tail = input.get(split ..)?;
+  tail.eq_ignore_ascii_case(suffix).then(|| &input[.. split])
 }
 
 #[cfg(test)]
 mod tests {
+  use super::Currency;
   use super::MoneyError;
-  use super::format_cents;
-  use super::parse_money_to_cents;
 
   #[test]
-  fn parses_valid_amounts() {
-    assert_eq!(parse_money_to_cents("10"), Ok(1000));
-    assert_eq!(parse_money_to_cents("10.5"), Ok(1050));
-    assert_eq!(parse_money_to_cents("10.55"), Ok(1055));
+  fn parses_amounts() {
+    let cases: &[(Currency, &str, i64)] = &[
+      (Currency::AED, "10", 1000),
+      (Currency::AED, "10.5", 1050),
+      (Currency::AED, "10.55", 1055),
+      (Currency::AED, " 10 ", 1000),
+      (Currency::AED, "1,250", 125_000),
+      (Currency::AED, "1,250.50", 125_050),
+      (Currency::AED, "12,345,678", 1_234_567_800),
+      (Currency::AED, "1.2k", 120_000),
+      (Currency::AED, "2K", 200_000),
+      (Currency::AED, "1.25m", 125_000_000),
+      (Currency::AED, "AED 50", 5000),
+      (Currency::AED, "aed50", 5000),
+      (Currency::AED, "50 AED", 5000),
+      (Currency::USD, "$50", 5000),
+      (Currency::USD, "USD 1,250", 125_000),
+      (Currency::USD, "$1.2k", 120_000),
+      (Currency::RUB, "1 250 ₽", 125_000),
+      (Currency::KWD, "1.125", 1125),
+    ];
+    for (currency, input, expected) in cases {
+      assert_eq!(currency.parse(input), Ok(*expected), "{currency} {input:?}");
+    }
+  }
+
+  #[test]
+  fn rejects_invalid_and_ambiguous_amounts() {
+    let cases: &[(Currency, &str, MoneyError)] = &[
+      (Currency::AED, "abc", MoneyError::InvalidFormat),
+      (Currency::AED, "", MoneyError::InvalidFormat),
+      (Currency::AED, "-5", MoneyError::InvalidFormat),
+      (Currency::AED, "1.2.3", MoneyError::InvalidFormat),
+      (Currency::AED, "10.", MoneyError::InvalidFormat),
+      (Currency::AED, "5kk", MoneyError::InvalidFormat),
+      (Currency::AED, "10.555", MoneyError::TooPrecise("AED")),
+      (Currency::AED, "1.234567k", MoneyError::TooPrecise("AED")),
+      (Currency::AED, "1,25", MoneyError::Ambiguous("1,25".to_string())),
+      (Currency::AED, "1,2500", MoneyError::Ambiguous("1,2500".to_string())),
+      (Currency::AED, "12,50", MoneyError::Ambiguous("12,50".to_string())),
+      (Currency::RUB, "1 25 ₽", MoneyError::Ambiguous("1 25".to_string())),
+      (Currency::AED, "$50", MoneyError::WrongCurrency("AED")),
+      (Currency::USD, "AED 50", MoneyError::WrongCurrency("USD")),
+      (Currency::AED, "99999999999999999999", MoneyError::OutOfRange),
+      (Currency::AED, "99999999999999m", MoneyError::OutOfRange),
+    ];
+    for (currency, input, expected) in cases {
+      assert_eq!(currency.parse(input).as_ref(), Err(expected), "{currency} {input:?}");
+    }
   }
 
   #[test]
-  fn rejects_invalid_formats() {
-    assert_eq!(parse_money_to_cents("abc"), Err(MoneyError::InvalidFormat));
-    assert_eq!(parse_money_to_cents("10.555"), Err(MoneyError::InvalidFormat));
+  fn formats_amounts() {
+    let cases: &[(Currency, i64, &str, &str)] = &[
+      (Currency::AED, 1234, "AED 12.34", "AED 12.34"),
+      (Currency::AED, 1000, "AED 10.00", "AED 10"),
+      (Currency::AED, 1050, "AED 10.50", "AED 10.50"),
+      (Currency::AED, 5, "AED 0.05", "AED 0.05"),
+      (Currency::AED, -1050, "AED -10.50", "AED -10.50"),
+      (Currency::USD, 125_000, "$1250.00", "$1250"),
+      (Currency::RUB, 99, "0.99 ₽", "0.99 ₽"),
+      (Currency::KWD, 1125, "KWD 1.125", "KWD 1.125"),
+    ];
+    for (currency, amount, full, short) in cases {
+      assert_eq!(currency.format(*amount), *full);
+      assert_eq!(currency.format_short(*amount), *short);
+    }
+  }
+
+  #[test]
+  fn formatted_amounts_parse_back() {
+    for currency in Currency::SUPPORTED {
+      for amount in [0, 5, 1234, 125_000] {
+        assert_eq!(currency.parse(&currency.format(amount)), Ok(amount), "{currency}");
+      }
+    }
   }
 
   #[test]
-  fn formats_currency() {
-    assert_eq!(format_cents(1234), "AED 12.34");
+  fn looks_up_codes() {
+    assert_eq!(Currency::from_code("usd"), Some(Currency::USD));
+    assert_eq!(Currency::from_code(" AED "), Some(Currency::AED));
+    assert_eq!(Currency::from_code("XYZ"), None);
   }
 }